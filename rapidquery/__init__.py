@@ -10,6 +10,7 @@ from ._lib import AlterTableModifyColumnOption as AlterTableModifyColumnOption
 from ._lib import AlterTableOptionMeta as AlterTableOptionMeta
 from ._lib import AlterTableRenameColumnOption as AlterTableRenameColumnOption
 from ._lib import ArrayType as ArrayType
+from ._lib import Backend as Backend
 from ._lib import BigIntegerType as BigIntegerType
 from ._lib import BigUnsignedType as BigUnsignedType
 from ._lib import BinaryType as BinaryType
@@ -67,6 +68,7 @@ from ._lib import SelectCol as SelectCol
 from ._lib import SmallIntegerType as SmallIntegerType
 from ._lib import SmallUnsignedType as SmallUnsignedType
 from ._lib import StringType as StringType
+from ._lib import Subquery as Subquery
 from ._lib import Table as Table
 from ._lib import TableName as TableName
 from ._lib import TextType as TextType
@@ -85,6 +87,10 @@ from ._lib import VectorType as VectorType
 from ._lib import Window as Window
 from ._lib import WindowFrame as WindowFrame
 from ._lib import YearType as YearType
+from ._lib import adapt_many as adapt_many
 from ._lib import all as all
 from ._lib import any as any
+from ._lib import build_schema_ordered as build_schema_ordered
+from ._lib import column_type_from_string as column_type_from_string
+from ._lib import drop_all as drop_all
 from ._lib import not_ as not_
@@ -4,7 +4,47 @@ import decimal
 import typing
 import uuid
 
-_Backends = typing.Literal["sqlite", "mysql", "postgresql", "postgres"]
+_Backends = typing.Union[typing.Literal["sqlite", "mysql", "postgresql", "postgres"], "Backend"]
+
+class Backend:
+    """
+    A database backend, validated when it's created.
+
+    Accepted everywhere a backend name is. A misspelled name fails where the backend is defined,
+    rather than when a statement is built.
+
+    Example:
+        >>> backend = Backend("postgres")
+        >>> backend == Backend.POSTGRES
+        True
+        >>> Select(Expr.col("id")).from_table("users").to_sql(backend)
+        'SELECT "id" FROM "users"'
+    """
+
+    POSTGRES: typing.ClassVar[Backend]
+    MYSQL: typing.ClassVar[Backend]
+    SQLITE: typing.ClassVar[Backend]
+
+    def __new__(cls, name: _Backends) -> Self:
+        """
+        Create a backend from its name.
+
+        Args:
+            name: One of "postgresql" (or "postgres"), "mysql" and "sqlite", or a Backend
+
+        Raises:
+            ValueError: If `name` isn't a known backend
+            TypeError: If `name` isn't a `str` or a `Backend`
+        """
+        ...
+
+    @property
+    def name(self) -> typing.Literal["sqlite", "mysql", "postgresql"]:
+        """The canonical name of the backend; also what `str()` returns."""
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
 
 class _AsteriskType:
     """
@@ -16,12 +56,13 @@ class _AsteriskType:
 ASTERISK: typing.Final[_AsteriskType]
 
 class SchemaStatement:
-    def to_sql(self, backend: _Backends) -> str:
+    def to_sql(self, backend: _Backends, pretty: bool = ...) -> str:
         """
         Build a SQL string representation.
 
         Args:
             backend: The database backend that determines SQL dialect and formatting
+            pretty: Put each top-level clause, and each definition of `CREATE TABLE`, on its own line
 
         Returns:
             A SQL string representation of the expression
@@ -41,7 +82,7 @@ class QueryStatement:
         """
         ...
 
-    def to_sql(self, backend: _Backends) -> str:
+    def to_sql(self, backend: _Backends, pretty: bool = ..., quote_identifiers: bool = ...) -> str:
         """
         Build a SQL string representation.
 
@@ -49,9 +90,23 @@ class QueryStatement:
 
         Args:
             backend: The database backend that determines SQL dialect and formatting
+            pretty: Put each top-level clause (`SELECT`, `FROM`, `WHERE`, `GROUP BY`, ...) on its own line;
+                subqueries stay on one line
+            quote_identifiers: Quote every identifier (the default). When `False`, identifiers are
+                only quoted if they need to be: names which aren't all lowercase letters, digits and
+                underscores, and the reserved keywords of the backend, such as `order`, `user` on
+                PostgreSQL or `rank` on MySQL
 
         Returns:
             A SQL string representation of the expression
+
+        Example:
+            >>> print(Select(Expr.col("id")).from_table("users").where(Expr.col("active")).to_sql("postgresql", pretty=True))
+            SELECT "id"
+            FROM "users"
+            WHERE "active"
+            >>> Select(Expr.col("id"), Expr.col("order")).from_table("users").to_sql("postgresql", quote_identifiers=False)
+            'SELECT id, "order" FROM users'
         """
         ...
 
@@ -102,6 +157,9 @@ class StringType(_LengthColumnType[str]):
     Represents a variable-length character string with a maximum length limit.
     This is the most common string type for storing text data of varying lengths
     like names, descriptions, or user input.
+
+    Without a length (`None` or `0`) it renders as plain `varchar` on PostgreSQL and SQLite,
+    and as `varchar(255)` on MySQL, which requires a length.
     """
 
     ...
@@ -368,6 +426,10 @@ class MoneyType(_PrecisionScaleColumnType[decimal.Decimal]):
     Specialized numeric type for storing monetary values with fixed precision.
     Optimized for currency calculations and formatting, though DECIMAL is
     often preferred for financial applications.
+
+    Values may be given as `decimal.Decimal`, `float`, `int` or `str`; they're converted
+    to `decimal.Decimal` (floats via their shortest representation, so `0.1` stays `0.1`)
+    and rendered as a plain numeric literal on every backend.
     """
 
     ...
@@ -527,6 +589,28 @@ class ArrayType(ColumnTypeMeta[list]):
     """The type of elements stored in the array."""
 
     def __new__(cls, element: ColumnTypeMeta) -> Self: ...
+    @classmethod
+    def of(cls, element: ColumnTypeMeta, dimensions: int = 1) -> Self:
+        """
+        Create an array type of `element`, nested `dimensions` times for multi-dimensional arrays.
+
+        Args:
+            element: The type of the innermost elements
+            dimensions: Number of array dimensions
+
+        Returns:
+            A new ArrayType
+
+        Raises:
+            ValueError: If `dimensions` is less than 1
+
+        Example:
+            >>> ArrayType.of(IntegerType(), dimensions=2) == ArrayType(ArrayType(IntegerType()))
+            True
+            >>> Table("grids", [Column("cells", ArrayType.of(IntegerType(), 2))]).to_sql("postgresql")
+            'CREATE TABLE "grids" ( "cells" integer[][] );\\n'
+        """
+        ...
 
 class AdaptedValue(typing.Generic[T]):
     """
@@ -538,7 +622,9 @@ class AdaptedValue(typing.Generic[T]):
     NOTE: this class is immutable and frozen.
     """
 
-    def __new__(cls, val: typing.Any, type: typing.Optional[ColumnTypeMeta] = None) -> Self:
+    def __new__(
+        cls, val: typing.Any, type: typing.Optional[ColumnTypeMeta] = None, strict: bool = False
+    ) -> Self:
         """
         Validates and adapts your value for Rust and SQL, then creates a new `AdaptedValue` instance.
 
@@ -564,6 +650,12 @@ class AdaptedValue(typing.Generic[T]):
             AdaptedValue("127.0.0.1", Inet())    # -> INET SQL type (network address)
             AdaptedValue([4.3, 5.6], Vector())   # -> VECTOR SQL type (for AI embeddings)
 
+        Integers of every integer type are only checked to fit in 64 bits. Pass `strict=True` to
+        also check them against the range of the exact type, e.g. -128 to 127 for `TinyIntegerType`
+        or 0 to 65535 for `SmallUnsignedType`, instead of having the database reject them::
+
+            AdaptedValue(300, TinyIntegerType(), strict=True)  # -> ValueError
+
         Possible exceptions are `TypeError`, `ValueError`, and `OverflowError`.
 
         NOTE: this class is immutable and frozen.
@@ -634,11 +726,54 @@ class AdaptedValue(typing.Generic[T]):
         """
         ...
 
+    def __copy__(self) -> Self:
+        """
+        Create a shallow copy of this value; the wrapped Python object is shared.
+        """
+        ...
+
+    def copy(self) -> Self:
+        """
+        Create a shallow copy of this value.
+        """
+        ...
+
+    def __deepcopy__(self, memo: typing.Dict[int, typing.Any]) -> Self:
+        """
+        Create a deep copy of this value.
+
+        Mutable payloads (JSON values and vectors, also inside arrays) are copied with
+        `copy.deepcopy`; immutable ones such as strings and UUIDs are shared.
+        """
+        ...
+
     # `AdaptedValue` is not a child of SchemaStatement, but we used
     # `to_sql` name for this method to make compatible with others
     def to_sql(self, backend: _Backends) -> str:
         """
         Converts the adapted value to SQL.
+
+        **This method is unsafe and can cause SQL injection.** use `.to_param_sql()` method instead.
+        """
+        ...
+
+    def to_param_sql(self, backend: _Backends) -> typing.Tuple[str, AdaptedValue[T]]:
+        """
+        Converts the adapted value to a placeholder and its bound value.
+
+        Useful when assembling raw SQL by hand, to keep the value parameterized.
+
+        Args:
+            backend: The database backend that determines the placeholder style
+
+        Returns:
+            A tuple of the placeholder string and the value to bind to it
+
+        Example:
+            >>> AdaptedValue("O'Reilly").to_param_sql("postgresql")
+            # ('$1', <AdaptedValue[adapted] String(...)>)
+            >>> AdaptedValue("O'Reilly").to_param_sql("mysql")
+            # ('?', <AdaptedValue[adapted] String(...)>)
         """
         ...
 
@@ -811,7 +946,28 @@ class Expr:
         ...
 
     @classmethod
-    def exists(cls, stmt: Select) -> Self: ...
+    def exists(cls, stmt: Select) -> Self:
+        """
+        Create an `EXISTS(subquery)` expression.
+
+        The subquery may be correlated: columns of the outer query's tables, such as
+        `users.c.id` or `Expr.col("u.id")`, keep their table qualifier (or alias) inside it.
+
+        Args:
+            stmt: The subquery
+
+        Returns:
+            An Expr representing the EXISTS check
+
+        Example:
+            >>> orders = AliasedTable(orders_table, "o")
+            >>> inner = Select(Expr(1)).from_table(orders).where(Expr(orders.c.user_id) == users.c.id.to_expr())
+            >>> Select(users.c.id).from_table(users).where(Expr.exists(inner))
+            # SELECT "users"."id" FROM "users"
+            # WHERE EXISTS(SELECT 1 FROM "orders" AS "o" WHERE "o"."user_id" = "users"."id")
+        """
+        ...
+
     @classmethod
     def any(cls, stmt: Select) -> Self: ...
     @classmethod
@@ -819,22 +975,161 @@ class Expr:
     @classmethod
     def all(cls, stmt: Select) -> Self: ...
     @classmethod
+    def any_array(cls, values: typing.Sequence[typing.Any]) -> Self:
+        """
+        Create an `ANY(ARRAY[...])` expression, to compare against any of the values.
+
+        Items that are not `Expr` are converted like `Expr(value)`.
+
+        **Notes** \\
+        Only PostgreSQL has arrays; building it for MySQL or SQLite fails. Use `in_` there.
+
+        Args:
+            values: A non-empty sequence of values
+
+        Returns:
+            An Expr to use as the right side of a comparison
+
+        Raises:
+            ValueError: If `values` is empty
+
+        Example:
+            >>> (Expr.col("id") == Expr.any_array([1, 2, 3])).to_sql("postgresql")
+            '"id" = ANY(ARRAY[1, 2, 3])'
+        """
+        ...
+    @classmethod
+    def all_array(cls, values: typing.Sequence[typing.Any]) -> Self:
+        """
+        Create an `ALL(ARRAY[...])` expression, to compare against all of the values.
+
+        Same as `any_array`, but the comparison has to hold for every value.
+
+        Example:
+            >>> (Expr.col("score") > Expr.all_array([10, 20])).to_sql("postgresql")
+            '"score" > ALL(ARRAY[10, 20])'
+        """
+        ...
+    @classmethod
+    def any_of(
+        cls,
+        array: typing.Union[Column, Self],
+        element_type: typing.Optional[ColumnTypeMeta] = ...,
+    ) -> Self:
+        """
+        Create an `ANY(array)` expression, to compare against any element of an array-typed expression.
+
+        A plain column reference doesn't tell whether it's an array, so `array` must be a `Column`
+        of `ArrayType`, or an expression made by `cast_array`. Otherwise pass `element_type`,
+        and `array` is cast to an array of it.
+
+        **Notes** \\
+        Only PostgreSQL has arrays; building it for MySQL or SQLite fails.
+
+        Args:
+            array: An array-typed column or expression
+            element_type: The element type, to cast `array` to an array of it
+
+        Returns:
+            An Expr to use as the right side of a comparison
+
+        Raises:
+            ValueError: If `array` isn't known to be array-typed and `element_type` is not given
+            TypeError: If `element_type` is not a column type
+
+        Example:
+            >>> tags = Column("tags", ArrayType(TextType()))
+            >>> (Expr.col("x") == Expr.any_of(tags)).to_sql("postgresql")
+            '"x" = ANY("tags")'
+            >>> (Expr.col("x") == Expr.any_of(Expr.col("tags"), TextType())).to_sql("postgresql")
+            '"x" = ANY(CAST("tags" AS text[]))'
+        """
+        ...
+    @classmethod
+    def all_of(
+        cls,
+        array: typing.Union[Column, Self],
+        element_type: typing.Optional[ColumnTypeMeta] = ...,
+    ) -> Self:
+        """
+        Create an `ALL(array)` expression, to compare against every element of an array-typed expression.
+
+        Same as `any_of`, but the comparison has to hold for every element.
+        """
+        ...
+    @classmethod
+    def json_build_object(cls, **pairs: _ExprValue) -> Self:
+        """
+        Create a JSON object from key/value pairs.
+
+        Keys are written inline as string literals; values are converted like `Expr(value)`.
+
+        **Notes** \\
+        Rendered as `json_build_object` on PostgreSQL, `JSON_OBJECT` on MySQL and `json_object`
+        on SQLite. Use `**{"key": value}` for keys which aren't Python identifiers.
+
+        Args:
+            **pairs: The keys and values of the object
+
+        Returns:
+            A new Expr representing the JSON object
+
+        Example:
+            >>> Expr.json_build_object(id=Expr.col("id"), name=Expr.col("name")).to_sql("postgresql")
+            'json_build_object(\\'id\\', "id", \\'name\\', "name")'
+        """
+        ...
+    @classmethod
+    def json_agg(cls, expr: _ExprValue) -> Self:
+        """
+        Create an aggregate which collects the values of `expr` into a JSON array.
+
+        **Notes** \\
+        Rendered as `json_agg` on PostgreSQL, `JSON_ARRAYAGG` on MySQL and `json_group_array`
+        on SQLite. Pass `expr.distinct()` to collect distinct values only.
+
+        Args:
+            expr: The expression to aggregate
+
+        Returns:
+            A new Expr representing the aggregate
+
+        Example:
+            >>> obj = Expr.json_build_object(id=Expr.col("id"), name=Expr.col("name"))
+            >>> Select(Expr.json_agg(obj)).from_table("users").to_sql("mysql")
+            "SELECT JSON_ARRAYAGG(JSON_OBJECT('id', `id`, 'name', `name`)) FROM `users`"
+        """
+        ...
+    @classmethod
     def tuple(
         cls,
-        values: typing.Union[typing.Set[Self], typing.List[Self], typing.Tuple[Self]],
+        values: typing.Union[typing.Set[typing.Any], typing.List[typing.Any], typing.Tuple[typing.Any, ...]],
     ) -> Self:
         """
         Create a tuple expression for tuple comparisons.
 
+        Items that are not `Expr` are converted like `Expr(value)`. Nested tuples and lists
+        are kept as nested rows rather than flattened, so `((a, b), (c, d))` renders as a row of rows.
+
+        **Notes** \\
+        Sets have no defined iteration order, so their items are sorted to render the same SQL
+        every time. Use a tuple or list when the items can't be compared, or to keep your own order.
+
         Args:
-            values: A collection of expressions to include in the tuple
+            values: A non-empty collection of expressions to include in the tuple
 
         Returns:
             An Expr representing a SQL tuple
 
+        Raises:
+            ValueError: If `values` is empty
+            TypeError: If `values` is a set whose items can't be compared to each other
+
         Example:
-            >>> Expr.tuple(["id", "name"])
+            >>> Expr.tuple([Expr.col("id"), Expr.col("name")])
             # Can be used in: WHERE (id, name) IN ((1, 'a'), (2, 'b'))
+            >>> Expr.tuple([(1, "a"), (2, "b")])
+            # ((1, 'a'), (2, 'b'))
         """
         ...
 
@@ -864,6 +1159,59 @@ class Expr:
         """
         ...
 
+    @classmethod
+    def func_call(cls, name: str, *args: _ExprValue) -> Self:
+        """
+        Create a call to a database function by name.
+
+        Args:
+            name: The function name, rendered as is; an identifier, optionally qualified by a schema
+                (e.g. `"pg_catalog.now"`)
+            *args: The function arguments
+
+        Returns:
+            An Expr representing the function call
+
+        Raises:
+            ValueError: If `name` isn't an identifier or a schema-qualified one
+
+        Example:
+            >>> Expr.func_call("similarity", Expr.col("name"), "alice")
+            # similarity("name", 'alice')
+        """
+        ...
+
+    @classmethod
+    def time_bucket(
+        cls,
+        width: str,
+        expr: _ExprValue,
+        origin: typing.Optional[_ExprValue] = None,
+    ) -> Self:
+        """
+        Create a TimescaleDB `time_bucket()` call.
+
+        Args:
+            width: The bucket width as `<count> <unit>` pairs, e.g. `"15 minutes"` or `"1 day 12 hours"`
+            expr: The timestamp expression to bucket
+            origin: Optional origin to align the buckets to
+
+        Returns:
+            An Expr representing the function call
+
+        Raises:
+            ValueError: If the width is not a valid interval
+
+        Example:
+            >>> bucket = Expr.time_bucket("1 hour", Expr.col("ts"))
+            >>> Select(bucket, FunctionCall.avg(Expr.col("value"))).from_table("metrics").group_by(bucket)
+            # SELECT time_bucket('1 hour', "ts"), AVG("value") FROM "metrics" GROUP BY time_bucket('1 hour', "ts")
+
+        **Notes** \\
+        `time_bucket()` is provided by the TimescaleDB extension on PostgreSQL.
+        """
+        ...
+
     @classmethod
     def current_date(cls) -> Self:
         """
@@ -904,15 +1252,105 @@ class Expr:
         """
         ...
 
-    def cast_as(self, value: str) -> Self:
+    def cast_as(self, value: typing.Union[str, ColumnTypeMeta]) -> Self:
         """
         Create a CAST expression to convert to a specific SQL type.
 
+        **Notes** \\
+        A column type is rendered for each backend as in table definitions, so interval fields,
+        array element types and enum names are kept (e.g. `interval HOUR TO MINUTE`, `text[]`).
+        Building fails if the backend doesn't have the type. MySQL's CAST only accepts a few
+        target types, so a column type is mapped to the closest one there (e.g. `SIGNED` for
+        integers, `CHAR` for strings and enums); types without one, such as booleans and UUIDs,
+        fail. SQLite converts by type affinity, so the type is mapped to `integer`, `real`,
+        `numeric`, `text` or `blob`.
+
+        A type name is written into the SQL as-is, so it's limited to the characters of type names
+        (letters, digits, `_`, spaces, `,`, `.`, `[]` and balanced parentheses).
+
+        Args:
+            value: The target SQL type name (e.g., 'INTEGER', 'VARCHAR(255)'), or a column type
+
+        Returns:
+            A new Expr representing the cast operation
+
+        Raises:
+            TypeError: If `value` is neither a string nor a column type
+            ValueError: If the type name is empty or malformed
+
+        Example:
+            >>> Expr.col("elapsed").cast_as(IntervalType(INTERVAL_HOUR_TO_MINUTE)).to_sql("postgresql")
+            'CAST("elapsed" AS interval HOUR TO MINUTE)'
+        """
+        ...
+
+    def pg_cast(self, value: typing.Union[str, ColumnTypeMeta]) -> Self:
+        """
+        Create a PostgreSQL `::` cast, e.g. `"price"::text`.
+
+        Same as `cast_as`, in the shorthand syntax which is idiomatic in PostgreSQL.
+
+        **Notes** \\
+        Only PostgreSQL has this syntax; building it for MySQL or SQLite fails. Use `cast_as` there.
+
+        Args:
+            value: The target SQL type name (e.g., 'text', 'int[]'), or a column type
+
+        Returns:
+            A new Expr representing the cast operation
+
+        Raises:
+            TypeError: If `value` is neither a string nor a column type
+            ValueError: If the type name is empty or malformed, or the column type isn't available
+                in PostgreSQL
+
+        Example:
+            >>> Expr.col("id").pg_cast(TextType()).to_sql("postgresql")
+            '"id"::text'
+        """
+        ...
+
+    def to_bool(self) -> Self:
+        """
+        Coerce this expression to a boolean, e.g. to filter on an integer flag.
+
+        **Notes** \\
+        PostgreSQL renders `CAST(expr AS boolean)`. MySQL and SQLite have no real boolean type,
+        so they render `expr <> 0` instead; both are true for any non-zero number.
+
+        Returns:
+            A new Expr representing the boolean
+
+        Example:
+            >>> Expr.col("active").to_bool().to_sql("postgresql")
+            'CAST("active" AS boolean)'
+            >>> Expr.col("active").to_bool().to_sql("mysql")
+            '`active` <> 0'
+        """
+        ...
+
+    def cast_array(self, element_type: ColumnTypeMeta) -> Self:
+        """
+        Create a CAST expression to an array of `element_type`.
+
+        Same as `cast_as(ArrayType(element_type))`. The result is known to be an array,
+        so it can be passed to `any_of` and `all_of`.
+
+        **Notes** \\
+        Only PostgreSQL has arrays; building it for MySQL or SQLite fails.
+
         Args:
-            value: The target SQL type name (e.g., 'INTEGER', 'VARCHAR(255)')
+            element_type: The column type of the array elements
 
         Returns:
             A new Expr representing the cast operation
+
+        Raises:
+            TypeError: If `element_type` is not a column type
+
+        Example:
+            >>> Expr.col("ids").cast_array(IntegerType()).to_sql("postgresql")
+            'CAST("ids" AS integer[])'
         """
         ...
 
@@ -926,6 +1364,9 @@ class Expr:
 
         Returns:
             A new Expr representing the LIKE operation
+
+        Raises:
+            ValueError: If `escape` isn't a single character
         """
         ...
 
@@ -939,20 +1380,62 @@ class Expr:
 
         Returns:
             A new Expr representing the NOT LIKE operation
+
+        Raises:
+            ValueError: If `escape` isn't a single character
         """
         ...
 
-    def __eq__(self, other: _ExprValue) -> Self:
+    def similar_to(self, pattern: str, escape: typing.Optional[str] = ...) -> Self:
         """
-        Create an equality comparison expression.
+        Create a PostgreSQL SIMILAR TO pattern matching expression.
+
+        Args:
+            pattern: The SQL regular expression to match against
+            escape: Optional escape character for special characters in the pattern
+
+        Returns:
+            A new Expr representing the SIMILAR TO operation
+
+        Raises:
+            ValueError: If `escape` isn't a single character
+
+        Example:
+            >>> Expr.col("code").similar_to("%(b|d)%")
+            # "code" SIMILAR TO '%(b|d)%'
+
+        **Notes** \\
+        Only PostgreSQL supports SIMILAR TO; building it for another backend fails.
         """
         ...
 
-    def __ne__(self, other: _ExprValue) -> Self:
-        """
-        Create an inequality comparison expression.
+    def not_similar_to(self, pattern: str, escape: typing.Optional[str] = ...) -> Self:
         """
-        ...
+        Create a PostgreSQL NOT SIMILAR TO pattern matching expression.
+
+        Args:
+            pattern: The SQL regular expression that should not match
+            escape: Optional escape character for special characters in the pattern
+
+        Returns:
+            A new Expr representing the NOT SIMILAR TO operation
+
+        Raises:
+            ValueError: If `escape` isn't a single character
+        """
+        ...
+
+    def __eq__(self, other: _ExprValue) -> Self:
+        """
+        Create an equality comparison expression.
+        """
+        ...
+
+    def __ne__(self, other: _ExprValue) -> Self:
+        """
+        Create an inequality comparison expression.
+        """
+        ...
 
     def __gt__(self, other: _ExprValue) -> Self:
         """
@@ -1005,12 +1488,41 @@ class Expr:
 
     def bit_and(self, other: _ExprValue) -> Self: ...
     def bit_or(self, other: _ExprValue) -> Self: ...
+    def bit_not(self) -> Self:
+        """
+        Create a bitwise complement expression, rendered as `~(expr)`.
+
+        This flips the bits of an integer value; it's not a logical NOT.
+
+        Example:
+            >>> Expr.col("flags").bit_not().bit_and(0xFF)
+            # ~("flags") & 255
+        """
+        ...
+
     def __truediv__(self, other: _ExprValue) -> Self:
         """
         Create a division expression.
         """
         ...
 
+    def __floordiv__(self, other: _ExprValue) -> Self:
+        """
+        Create a floor division expression.
+
+        Rendered as `FLOOR(a / b)` on MySQL, and as `FLOOR(CAST(a AS numeric) / b)` on PostgreSQL
+        (`real` on SQLite), so that integers aren't divided by truncating first.
+
+        **Notes** \\
+        Like Python's `//`, a negative quotient is rounded down: `-7 // 2` is `-4`. SQLite only has
+        `FLOOR()` when it's built with math functions (the default since 3.35).
+
+        Example:
+            >>> (Expr.col("minutes") // 60).to_sql("mysql")
+            'FLOOR(`minutes` / 60)'
+        """
+        ...
+
     def is_(self, other: _ExprValue) -> Self:
         """
         Create an IS comparison expression (for NULL comparisons).
@@ -1153,27 +1665,35 @@ class Expr:
         """
         ...
 
-    def pg_ilike(self, other: _ExprValue) -> Self:
+    def pg_ilike(self, pattern: str, escape: typing.Optional[str] = ...) -> Self:
         """
         Create a PostgreSQL case-insensitive LIKE expression.
 
         Args:
-            other: The pattern to match against
+            pattern: The pattern to match against
+            escape: Optional escape character for wildcards in the pattern
 
         Returns:
             A new Expr representing the ILIKE operation
+
+        Raises:
+            ValueError: If `escape` isn't a single character
         """
         ...
 
-    def pg_not_ilike(self, other: _ExprValue) -> Self:
+    def pg_not_ilike(self, pattern: str, escape: typing.Optional[str] = ...) -> Self:
         """
         Create a PostgreSQL case-insensitive NOT LIKE expression.
 
         Args:
-            other: The pattern that should not match
+            pattern: The pattern that should not match
+            escape: Optional escape character for wildcards in the pattern
 
         Returns:
             A new Expr representing the NOT ILIKE operation
+
+        Raises:
+            ValueError: If `escape` isn't a single character
         """
         ...
 
@@ -1189,6 +1709,60 @@ class Expr:
         """
         ...
 
+    def is_distinct_from(self, other: _ExprValue) -> Self:
+        """
+        Create a null-safe inequality expression (`IS DISTINCT FROM`).
+
+        Unlike `!=`, two NULLs are considered equal and a NULL is considered
+        different from any other value, so the result is never NULL.
+
+        **Notes** \\
+        MySQL has no `IS DISTINCT FROM`; it's rendered as `NOT (a <=> b)` there.
+
+        Args:
+            other: The expression to compare with
+
+        Returns:
+            A new Expr representing the IS DISTINCT FROM comparison
+        """
+        ...
+
+    def is_not_distinct_from(self, other: _ExprValue) -> Self:
+        """
+        Create a null-safe equality expression (`IS NOT DISTINCT FROM`).
+
+        Unlike `==`, two NULLs are considered equal, so the result is never NULL.
+
+        **Notes** \\
+        MySQL has no `IS NOT DISTINCT FROM`; it's rendered as `a <=> b` there.
+
+        Args:
+            other: The expression to compare with
+
+        Returns:
+            A new Expr representing the IS NOT DISTINCT FROM comparison
+        """
+        ...
+
+    def eq_null_safe(self, other: _ExprValue) -> Self:
+        """
+        Create a null-safe equality expression, where `NULL` equals `NULL`.
+
+        Same as `is_not_distinct_from`: rendered as `a <=> b` on MySQL, and as
+        `a IS NOT DISTINCT FROM b` on PostgreSQL and SQLite.
+
+        Args:
+            other: The expression to compare with
+
+        Returns:
+            A new Expr representing the null-safe comparison
+
+        Example:
+            >>> Expr.col("deleted_at").eq_null_safe(None).to_sql("mysql")
+            '`deleted_at` <=> NULL'
+        """
+        ...
+
     def is_null(self) -> Self:
         """
         Create an IS NULL expression.
@@ -1209,13 +1783,52 @@ class Expr:
 
     def __lshift__(self, other: _ExprValue) -> Self:
         """
-        Create a bitwise left shift expression.
+        Create a bitwise left shift expression. Same as `lshift` without `strict`.
         """
         ...
 
     def __rshift__(self, other: _ExprValue) -> Self:
         """
-        Create a bitwise right shift expression.
+        Create a bitwise right shift expression. Same as `rshift` without `strict`.
+        """
+        ...
+
+    def lshift(self, other: _ExprValue, strict: bool = False) -> Self:
+        """
+        Create a bitwise left shift expression, `self << other`.
+
+        **Notes** \\
+        PostgreSQL only shifts integers, while MySQL and SQLite convert the operands. With `strict`,
+        building for PostgreSQL fails if either operand is a literal which isn't an integer (such
+        as a string or a float); columns aren't checked, since their types aren't known.
+
+        Args:
+            other: The number of bits to shift by
+            strict: Whether to check the operands when building for PostgreSQL
+
+        Returns:
+            A new Expr representing the shift
+
+        Example:
+            >>> Expr.col("flags").lshift("2", strict=True).to_sql("postgresql")
+            Traceback (most recent call last):
+            ValueError: bit shift operands must be integers in PostgreSQL
+        """
+        ...
+
+    def rshift(self, other: _ExprValue, strict: bool = False) -> Self:
+        """
+        Create a bitwise right shift expression, `self >> other`.
+
+        **Notes** \\
+        Same as `lshift`, `strict` checks the literal operands when building for PostgreSQL.
+
+        Args:
+            other: The number of bits to shift by
+            strict: Whether to check the operands when building for PostgreSQL
+
+        Returns:
+            A new Expr representing the shift
         """
         ...
 
@@ -1225,31 +1838,110 @@ class Expr:
         """
         ...
 
+    def power(self, other: _ExprValue) -> Self:
+        """
+        Create an exponentiation expression, rendered as `POWER(self, other)`.
+
+        `POWER()` is used on every backend because `^` means XOR on MySQL.
+
+        **Notes** \\
+        SQLite only has `POWER()` when it's built with math functions (the default since 3.35).
+
+        Args:
+            other: The exponent
+
+        Returns:
+            A new Expr representing the exponentiation
+        """
+        ...
+
+    def __pow__(self, other: _ExprValue) -> Self:
+        """
+        Create an exponentiation expression. Same as `power`.
+        """
+        ...
+
     def __mul__(self, other: _ExprValue) -> Self:
         """
         Create a multiplication expression.
         """
         ...
 
-    def between(self, a: _ExprValue, b: _ExprValue) -> Self:
+    @typing.overload
+    def between(self, a: _ExprValue, b: _ExprValue, *, open_bounds: bool = ...) -> Self: ...
+    @typing.overload
+    def between(self, a: typing.Tuple[_ExprValue, _ExprValue], *, open_bounds: bool = ...) -> Self: ...
+    def between(self, a, b=..., *, open_bounds=...) -> Self:
         """
         Create a BETWEEN range comparison expression.
 
+        The bounds can be passed either as two arguments or as a single `(low, high)` tuple.
+
+        **Notes** \\
+        A `None` bound is a NULL, which makes the whole comparison NULL, so no row matches.
+        With `open_bounds=True`, a `None` bound leaves that side of the range unlimited instead:
+        `between(low, None)` becomes `>= low` and `between(None, high)` becomes `<= high`,
+        which suits range filters with optional minimum and maximum.
+
         Args:
-            a: The lower bound of the range
+            a: The lower bound of the range, or a `(low, high)` tuple
             b: The upper bound of the range
+            open_bounds: Treat `None` bounds as unlimited
 
         Returns:
             A new Expr representing the BETWEEN operation
+
+        Raises:
+            ValueError: If `open_bounds` is set and both bounds are `None`
+
+        Example:
+            >>> Expr.col("price").between(min_price, max_price, open_bounds=True)
+        """
+        ...
+
+    @typing.overload
+    def overlaps(self, start: _ExprValue, end: _ExprValue) -> Self: ...
+    @typing.overload
+    def overlaps(self, start: typing.Tuple[_ExprValue, _ExprValue]) -> Self: ...
+    def overlaps(self, start, end=...) -> Self:
+        """
+        Create an OVERLAPS predicate between two date/time periods.
+
+        Must be called on a `(start, end)` row built with `Expr.tuple`. The other period can be
+        passed either as two arguments or as a single `(start, end)` tuple.
+
+        **Notes** \\
+        Only PostgreSQL has OVERLAPS; building it for MySQL or SQLite fails.
+
+        Args:
+            start: The start of the other period, or a `(start, end)` tuple
+            end: The end of the other period
+
+        Returns:
+            A new Expr representing the OVERLAPS predicate
+
+        Raises:
+            ValueError: If this expression is not a row of two items
+
+        Example:
+            >>> period = Expr.tuple([Expr.col("starts_at"), Expr.col("ends_at")])
+            >>> period.overlaps(Expr.col("r.starts_at"), Expr.col("r.ends_at")).to_sql("postgresql")
+            '("starts_at", "ends_at") OVERLAPS ("r"."starts_at", "r"."ends_at")'
         """
         ...
 
-    def not_between(self, a: _ExprValue, b: _ExprValue) -> Self:
+    @typing.overload
+    def not_between(self, a: _ExprValue, b: _ExprValue) -> Self: ...
+    @typing.overload
+    def not_between(self, a: typing.Tuple[_ExprValue, _ExprValue]) -> Self: ...
+    def not_between(self, a, b=...) -> Self:
         """
         Create a NOT BETWEEN range comparison expression.
 
+        The bounds can be passed either as two arguments or as a single `(low, high)` tuple.
+
         Args:
-            a: The lower bound of the range
+            a: The lower bound of the range, or a `(low, high)` tuple
             b: The upper bound of the range
 
         Returns:
@@ -1259,30 +1951,93 @@ class Expr:
 
     def in_subquery(self, stmt: Select) -> Self: ...
     def not_in_subquery(self, stmt: Select) -> Self: ...
-    def in_(self, other: typing.Sequence[_ExprValue]) -> Self:
+    def in_(
+        self,
+        other: typing.Iterable[_ExprValue],
+        type: typing.Optional[ColumnTypeMeta] = None,
+        optimize_single: bool = False,
+    ) -> Self:
         """
         Create an IN membership expression.
 
+        `Expr` and `AdaptedValue` items are used as they are, without inferring their types again.
+
         Args:
-            other: A sequence of expressions to check membership against
+            other: An iterable of expressions to check membership against; generators are consumed
+                item by item, without building a list first
+            type: Optional column type to adapt all other items to, instead of inferring each one
+            optimize_single: Render `expr = value` instead of `expr IN (value)` when there is
+                exactly one item
 
         Returns:
             A new Expr representing the IN operation
         """
         ...
 
-    def not_in(self, other: typing.Sequence[_ExprValue]) -> Self:
+    def not_in(
+        self,
+        other: typing.Iterable[_ExprValue],
+        type: typing.Optional[ColumnTypeMeta] = None,
+        optimize_single: bool = False,
+    ) -> Self:
         """
         Create a NOT IN membership expression.
 
+        `Expr` and `AdaptedValue` items are used as they are, without inferring their types again.
+
         Args:
-            other: A sequence of expressions to check non-membership against
+            other: An iterable of expressions to check non-membership against; generators are consumed
+                item by item, without building a list first
+            type: Optional column type to adapt all other items to, instead of inferring each one
+            optimize_single: Render `expr <> value` instead of `expr NOT IN (value)` when there is
+                exactly one item
 
         Returns:
             A new Expr representing the NOT IN operation
         """
         ...
 
+    def distinct(self) -> Self:
+        """
+        Mark this expression as DISTINCT, to be passed to an aggregate function.
+
+        **Notes** \\
+        The result may only be the first argument of an aggregate function, such as `COUNT` or a
+        function called with `func_call`; building it anywhere else raises `ValueError`.
+
+        Returns:
+            A new Expr representing `DISTINCT expr`
+
+        Example:
+            >>> FunctionCall.count(Expr.col("user_id").distinct())
+            # COUNT(DISTINCT "user_id")
+        """
+        ...
+
+    def case_when(
+        self,
+        cases: typing.Sequence[typing.Tuple[_ExprValue, _ExprValue]],
+        else_: typing.Optional[_ExprValue] = None,
+    ) -> Self:
+        """
+        Create a simple CASE expression using this expression as the operand.
+
+        Unlike `Case`, which builds a searched CASE (`CASE WHEN cond THEN ...`), this compares
+        the operand against each value (`CASE operand WHEN value THEN ...`).
+
+        Args:
+            cases: A sequence of `(value, result)` pairs
+            else_: The result if no value matches
+
+        Returns:
+            A new Expr representing the CASE expression
+
+        Example:
+            >>> Expr.col("status").case_when([(1, "active"), (2, "banned")], else_="unknown")
+            # CASE "status" WHEN 1 THEN 'active' WHEN 2 THEN 'banned' ELSE 'unknown' END
+        """
+        ...
+
     # `Expr` is not a child of SchemaStatement, but we used
     # `to_sql` name for this method to make compatible with others
     def to_sql(self, backend: _Backends) -> str:
@@ -1681,6 +2436,123 @@ def not_(arg1: Expr) -> Expr:
     """
     ...
 
+def build_schema_ordered(tables: typing.Sequence[Table], backend: _Backends, pretty: bool = ...) -> str:
+    """
+    Build the `CREATE TABLE` statements of many tables, ordered by their foreign keys.
+
+    A table is created after the tables its foreign keys reference; otherwise the given order
+    is kept. Index creates of all tables come after the tables. References to the table itself
+    or to tables which aren't given don't affect the order.
+
+    Args:
+        tables: The tables to create
+        backend: The database backend that determines SQL dialect
+        pretty: Format the statements like `SchemaStatement.to_sql(..., pretty=True)`
+
+    Returns:
+        The statements, each ending with `;` and a newline
+
+    Raises:
+        ValueError: If the foreign keys of the tables form a cycle
+
+    Example:
+        >>> users = Table("users", [Column("id", IntegerType(), primary_key=True)])
+        >>> posts = Table(
+        ...     "posts",
+        ...     [Column("id", IntegerType(), primary_key=True), Column("user_id", IntegerType())],
+        ...     foreign_keys=[ForeignKey(from_columns=["user_id"], to_columns=["id"], to_table="users")],
+        ... )
+        >>> build_schema_ordered([posts, users], "postgresql")  # users first
+    """
+    ...
+
+def drop_all(tables: typing.Sequence[Table], backend: _Backends, cascade: bool = ...) -> str:
+    """
+    Build the `DROP TABLE` statements of many tables, ordered by their foreign keys.
+
+    The reverse of `build_schema_ordered`: a table is dropped before the tables its foreign
+    keys reference, so that no constraint is violated. Useful to tear down test fixtures.
+
+    Args:
+        tables: The tables to drop
+        backend: The database backend that determines SQL dialect
+        cascade: Add `CASCADE` to each statement. Tables whose foreign keys form a cycle are then
+            dropped in the given order.
+
+    Returns:
+        The statements, each ending with `;` and a newline
+
+    Raises:
+        ValueError: If the foreign keys of the tables form a cycle, and `cascade` is not set
+
+    Example:
+        >>> drop_all([users, posts], "postgresql")
+        'DROP TABLE "posts";\\nDROP TABLE "users";\\n'
+    """
+    ...
+
+def column_type_from_string(s: str, backend: _Backends) -> ColumnTypeMeta:
+    """
+    Parse a column type as declared by the backend, e.g. from `information_schema`.
+
+    The inverse of rendering a column type: the result renders back to an equivalent type.
+    Lengths (`varchar(255)`), precision and scale (`numeric(10,2)`) and arrays (`int[]`) are
+    supported. Names are case-insensitive.
+
+    Interval fields (`interval day to second(3)`) are supported on PostgreSQL.
+
+    **Notes** \\
+    Arrays are only supported on PostgreSQL. MySQL display widths, as in `int(11)`, and the
+    fractional seconds precision of time types, as in `timestamp(3)`, are ignored, since the
+    column types don't keep them.
+
+    Args:
+        s: The type string
+        backend: The database backend that declared the type
+
+    Returns:
+        The column type instance
+
+    Raises:
+        ValueError: If the type is unknown to the backend, or its arguments are invalid
+
+    Example:
+        >>> column_type_from_string("varchar(255)", "postgresql")
+        <StringType length=255>
+        >>> column_type_from_string("int[]", "postgresql")
+        <ArrayColumnType element=<IntegerType >>
+    """
+    ...
+
+def adapt_many(
+    values: typing.Iterable[typing.Any],
+    type: typing.Optional[ColumnTypeMeta] = None,
+    strict: bool = False,
+) -> typing.List[AdaptedValue]:
+    """
+    Adapt many values at once, e.g. the rows of a bulk insert.
+
+    Same as `[AdaptedValue(x, type, strict) for x in values]`, in a single call. The result is
+    always made of new objects: `AdaptedValue` items are copied, and adapted again from their
+    value if `type` is given.
+
+    Args:
+        values: The values to adapt
+        type: The column type of every value, or `None` to detect it per value
+        strict: Check integers against the range of `type` (see `AdaptedValue`)
+
+    Returns:
+        A list of `AdaptedValue`s, in the order of `values`
+
+    Raises:
+        TypeError, ValueError, OverflowError: If a value can't be adapted, as `AdaptedValue` does
+
+    Example:
+        >>> adapt_many([1, 2, None], IntegerType())
+        [<AdaptedValue[inferred] BigInt(1)>, <AdaptedValue[inferred] BigInt(2)>, <AdaptedValue[adapted] Null>]
+    """
+    ...
+
 class Column(typing.Generic[T]):
     """
     Defines a table column with its properties and constraints.
@@ -1719,13 +2591,13 @@ class Column(typing.Generic[T]):
     """Whether this column must contain unique values."""
 
     auto_increment: bool
-    """Whether this column should auto-increment."""
+    """Whether this column should auto-increment. Only valid on integer column types."""
 
     extra: typing.Optional[str]
     """Extra SQL specifications for this column."""
 
     default: typing.Optional[Expr]
-    """Default value for this column."""
+    """Default value for this column. Assigned literals are checked against the column type."""
 
     generated: typing.Optional[Expr]
     """Expression for generated column values."""
@@ -1733,6 +2605,9 @@ class Column(typing.Generic[T]):
     stored_generated: bool
     """Whether the generated column is STORED (vs VIRTUAL)."""
 
+    check: typing.Optional[Expr]
+    """Column-level CHECK constraint expression."""
+
     comment: typing.Optional[str]
     """Comment describing this column."""
 
@@ -1749,6 +2624,7 @@ class Column(typing.Generic[T]):
         default: _ExprValue = ...,
         generated: _ExprValue = ...,
         stored_generated: bool = ...,
+        check: _ExprValue = ...,
     ) -> Self:
         """
         Create a new Column definition.
@@ -1761,13 +2637,23 @@ class Column(typing.Generic[T]):
             nullable: Whether NULL values are allowed
             auto_increment: Whether the column auto-increments
             extra: Additional column specifications
-            comment: Column description comment
-            default: Default value expression
+            comment: Column description comment; rendered inline on MySQL and as a separate
+                `COMMENT ON COLUMN` statement by `Table.to_sql` on PostgreSQL
+            default: Default value expression; a literal is checked against `type`, including the
+                range of sized integer types. Other expressions are parenthesized on MySQL and SQLite,
+                which only accept literals and some keywords bare
             generated: Generation expression for computed columns
             stored_generated: Whether computed column is stored physically
+            check: Column-level CHECK constraint expression
 
         Returns:
             A new Column instance
+
+        Raises:
+            ValueError: If `auto_increment` is set on a non-integer column type, or a literal
+                `default` is out of range for `type`
+            TypeError: If a literal `default` doesn't match `type`, such as a string default
+                of an integer column
         """
         ...
 
@@ -1791,37 +2677,96 @@ class Column(typing.Generic[T]):
         """
         ...
 
-    def adapt(self, value: T) -> AdaptedValue[T]:
-        """
-        Shorthand for `AdaptedValue(value, type=self.type)`
+    def uuid_default(self) -> Self:
         """
-        ...
+        Set the default of this UUID column to the backend's UUID generation function.
 
-    def __copy__(self) -> Self:
-        """
-        Create a shallow copy of this Column.
+        The function is chosen when the table is built for a backend.
+
+        Returns:
+            Self for method chaining
+
+        Raises:
+            ValueError: If the column is not a `UuidType` column
+
+        Example:
+            >>> table = Table("t", [Column("id", UuidType(), primary_key=True).uuid_default()])
+            >>> table.to_sql("postgresql")
+            # "id" uuid PRIMARY KEY DEFAULT gen_random_uuid()
+            >>> table.to_sql("mysql")
+            # `id` binary(16) PRIMARY KEY DEFAULT (UUID_TO_BIN(UUID()))
+
+        **Notes** \\
+        Any other function can be used as a default directly, e.g. `default=FunctionCall("gen_random_uuid")`.
+        SQLite has no UUID generation function, so building the table for SQLite raises `ValueError`.
         """
         ...
 
-    def copy(self) -> Self:
+    @staticmethod
+    def default_now() -> Expr:
         """
-        Create a copy of this Column.
+        Create a `CURRENT_TIMESTAMP` expression, to use as a column default.
+
+        Same as `Expr.current_timestamp()`; a `"CURRENT_TIMESTAMP"` string would be
+        a quoted literal instead.
 
         Returns:
-            A new Column instance with the same values
+            The keyword expression
+
+        Example:
+            >>> Column("created_at", TimestampType(), default=Column.default_now())
+            # "created_at" timestamp DEFAULT CURRENT_TIMESTAMP
         """
         ...
 
-    def __repr__(self) -> str:
+    @staticmethod
+    def default_today() -> Expr:
         """
-        Return a developer-friendly string representation.
+        Create a `CURRENT_DATE` expression, to use as a column default.
+
+        Same as `Expr.current_date()`. MySQL only accepts it parenthesized, so it renders
+        `DEFAULT (CURRENT_DATE)` there.
 
         Returns:
-            A string showing the column definition
+            The keyword expression
+
+        Example:
+            >>> Column("day", DateType(), default=Column.default_today())
+            # "day" date DEFAULT CURRENT_DATE
         """
         ...
 
-class TableName:
+    def adapt(self, value: T, strict: bool = False) -> AdaptedValue[T]:
+        """
+        Shorthand for `AdaptedValue(value, type=self.type, strict=strict)`
+        """
+        ...
+
+    def __copy__(self) -> Self:
+        """
+        Create a shallow copy of this Column.
+        """
+        ...
+
+    def copy(self) -> Self:
+        """
+        Create a copy of this Column.
+
+        Returns:
+            A new Column instance with the same values
+        """
+        ...
+
+    def __repr__(self) -> str:
+        """
+        Return a developer-friendly string representation.
+
+        Returns:
+            A string showing the column definition
+        """
+        ...
+
+class TableName:
     """
     Represents a table name reference with optional schema, database, and alias.
 
@@ -1884,6 +2829,9 @@ class TableName:
     def __eq__(self, other: Self) -> bool:
         """
         Check equality with another TableName.
+
+        Compares name, schema and database; the alias is ignored, so `users AS a` equals
+        `users AS b`. Use `equals(other, compare_alias=True)` to tell them apart.
         """
         ...
 
@@ -1893,6 +2841,31 @@ class TableName:
         """
         ...
 
+    def __hash__(self) -> int:
+        """
+        Hash of name, schema and database, consistent with `__eq__`.
+        """
+        ...
+
+    def equals(self, other: Self, compare_alias: bool = False) -> bool:
+        """
+        Check equality with another TableName, optionally comparing aliases too.
+
+        Args:
+            other: The TableName to compare with
+            compare_alias: Whether names with different aliases are unequal
+
+        Returns:
+            True if the names are equal
+
+        Example:
+            >>> TableName("users", alias="a") == TableName("users", alias="b")
+            True
+            >>> TableName("users", alias="a").equals(TableName("users", alias="b"), compare_alias=True)
+            False
+        """
+        ...
+
     def copy_with(
         self,
         *,
@@ -1923,6 +2896,7 @@ class TableName:
         ...
 
 _ForeignKeyActions = typing.Literal["CASCADE", "NO ACTION", "RESTRICT", "SET DEFAULT", "SET NULL"]
+_ForeignKeyMatch = typing.Literal["FULL", "PARTIAL", "SIMPLE"]
 
 class ForeignKey:
     """
@@ -1982,19 +2956,32 @@ class ForeignKey:
     on_update: typing.Optional[_ForeignKeyActions]
     """Action to take when referenced row is updated."""
 
+    match: typing.Optional[_ForeignKeyMatch]
+    """
+    How NULLs in a composite foreign key are matched (`MATCH FULL/PARTIAL/SIMPLE`).
+
+    Only PostgreSQL supports it; building the foreign key for another backend raises `ValueError`.
+    """
+
     def __new__(
         cls,
-        from_columns: typing.Sequence[str],
-        to_columns: typing.Sequence[str],
+        from_columns: typing.Sequence[typing.Union[Column, ColumnRef, str]],
+        to_columns: typing.Sequence[typing.Union[Column, ColumnRef, str]],
         to_table: typing.Union[TableName, str],
         from_table: typing.Union[TableName, str, None] = ...,
         name: typing.Optional[str] = ...,
         on_delete: typing.Optional[_ForeignKeyActions] = ...,
         on_update: typing.Optional[_ForeignKeyActions] = ...,
+        match: typing.Optional[_ForeignKeyMatch] = ...,
     ) -> None:
         """
         Create a new ForeignKey.
 
+        Columns may be given as `Column` or `ColumnRef` objects, of which only the name is kept,
+        or as names; the same goes for assigning `from_columns`, `to_columns` and `set_columns`.
+        A table-qualified `ColumnRef` must be qualified by the table of its columns (or its alias),
+        `to_table` for `to_columns` and `from_table`, if it's given, for `from_columns`.
+
         Args:
             from_columns: Columns in the child/referencing table
             to_columns: Columns in the parent/referenced table
@@ -2003,9 +2990,41 @@ class ForeignKey:
             name: Constraint name (optional)
             on_delete: Action on parent row deletion
             on_update: Action on parent row update
+            match: MATCH type, case-insensitive (PostgreSQL only)
 
         Returns:
             A new ForeignKey instance
+
+        Raises:
+            ValueError: If an action or `match` is unknown, or a `ColumnRef` is qualified by another table
+            TypeError: If a column isn't a `Column`, `ColumnRef` or `str`
+        """
+        ...
+
+    def set_columns(
+        self,
+        from_columns: typing.Sequence[typing.Union[Column, ColumnRef, str]],
+        to_columns: typing.Sequence[typing.Union[Column, ColumnRef, str]],
+    ) -> None:
+        """
+        Replace both column lists at once.
+
+        **Notes** \\
+        Setting `from_columns` and `to_columns` one after the other fails when a composite key
+        changes its number of columns, as each is checked against the other. The n-th column of
+        `from_columns` references the n-th column of `to_columns`.
+
+        Args:
+            from_columns: Columns in the child/referencing table
+            to_columns: Columns in the parent/referenced table
+
+        Raises:
+            ValueError: If either list is empty or their lengths differ; the foreign key is
+                left unchanged
+
+        Example:
+            >>> fk = ForeignKey(["user_id"], ["id"], "users")
+            >>> fk.set_columns(["tenant_id", "user_id"], ["tenant_id", "id"])
         """
         ...
 
@@ -2053,7 +3072,12 @@ class IndexColumn:
     """The name of the column to include in the index."""
 
     prefix: typing.Optional[int]
-    """Number of characters to index for string columns (prefix indexing)."""
+    """
+    Number of characters to index for string columns (prefix indexing).
+
+    Only MySQL supports it. SQLite ignores it, and building an index with a prefix for
+    PostgreSQL raises `ValueError`.
+    """
 
     order: typing.Optional[typing.Literal["asc", "desc"]]
     """Sort order for this column ("asc" or "desc")."""
@@ -2137,6 +3161,16 @@ class Index(SchemaStatement):
     nulls_not_distinct: bool
     """Whether NULL values should be considered equal for uniqueness."""
 
+    unique_constraint: bool
+    """
+    Whether this is a `UNIQUE` table constraint rather than a unique index.
+
+    A table renders it inside `CREATE TABLE`, e.g. `CONSTRAINT "name" UNIQUE ("email")`
+    (`UNIQUE KEY` on MySQL), instead of as a separate `CREATE UNIQUE INDEX`. PostgreSQL lists
+    constraints apart from indexes, and only accepts a unique constraint, not a partial unique
+    index, as a foreign key target.
+    """
+
     include: typing.Sequence[str]
     """Additional columns to include in the index for covering queries."""
 
@@ -2146,8 +3180,34 @@ class Index(SchemaStatement):
     index_type: typing.Optional[typing.Union[str, _IndexType]]
     """The type/algorithm for this index."""
 
-    where: typing.Optional[Expr]
-    """Condition for partial indexing."""
+    tablespace: typing.Optional[str]
+    """
+    The PostgreSQL tablespace to create the index in.
+
+    Rendered as `TABLESPACE "name"`, or `USING INDEX TABLESPACE "name"` for a primary key or
+    unique constraint inside `CREATE TABLE`. Other backends ignore it.
+    """
+
+    fillfactor: typing.Optional[int]
+    """
+    The PostgreSQL fillfactor of the index, a percentage from 10 to 100.
+
+    Rendered as `WITH (fillfactor=N)`. Other backends ignore it.
+    """
+
+    @property
+    def predicate(self) -> typing.Optional[Expr]:
+        """
+        Condition for partial indexing, given as `where` to the constructor.
+
+        It's rendered as a `WHERE` clause on PostgreSQL and SQLite; MySQL has no partial indexes
+        and ignores it.
+        """
+        ...
+
+    def is_partial(self) -> bool:
+        """Returns `True` if this is a partial index, i.e. it has a predicate."""
+        ...
 
     def __new__(
         cls,
@@ -2161,10 +3221,17 @@ class Index(SchemaStatement):
         include: typing.Sequence[str] = ...,
         index_type: typing.Union[str, _IndexType] = ...,
         where: typing.Optional[Expr] = ...,
+        unique_constraint: bool = ...,
+        tablespace: typing.Optional[str] = ...,
+        fillfactor: typing.Optional[int] = ...,
     ) -> Self:
         """
         Create a new Index specification.
 
+        **Notes** \\
+        `tablespace` and `fillfactor` are PostgreSQL specific; building the index for another backend
+        raises `ValueError`.
+
         Args:
             columns: The columns to include in the index
             name: The index name (optional)
@@ -2176,9 +3243,19 @@ class Index(SchemaStatement):
             include: Additional included columns
             index_type: The index algorithm type
             where: Condition for partial indexing
+            unique_constraint: Whether this is a `UNIQUE` table constraint instead of an index
+            tablespace: The tablespace to create the index in
+            fillfactor: How full index pages are packed, as a percentage from 10 to 100
 
         Returns:
             A new Index instance
+
+        Raises:
+            ValueError: If `fillfactor` is out of range
+
+        Example:
+            >>> Index(["email"], table="users", tablespace="fast", fillfactor=70).to_sql("postgresql")
+            'CREATE INDEX "ix_users_email" ON "users" ("email") WITH (fillfactor=70) TABLESPACE "fast"'
         """
         ...
 
@@ -2318,6 +3395,9 @@ class Table(SchemaStatement):
     temporary: bool
     """Whether this is a temporary table that exists only for the session."""
 
+    without_rowid: bool
+    """Whether this is a SQLite `WITHOUT ROWID` table; ignored on other backends."""
+
     comment: typing.Optional[str]
     """Comment describing the purpose of this table."""
 
@@ -2333,6 +3413,9 @@ class Table(SchemaStatement):
     extra: typing.Optional[str]
     """Additional table-specific options for the CREATE TABLE statement."""
 
+    storage_params: typing.Dict[str, typing.Union[bool, int, float, str]]
+    """PostgreSQL storage parameters, rendered as `WITH (...)` clause."""
+
     def __new__(
         cls,
         name: typing.Union[str, TableName],
@@ -2347,6 +3430,8 @@ class Table(SchemaStatement):
         collate: typing.Optional[str] = ...,
         character_set: typing.Optional[str] = ...,
         extra: typing.Optional[str] = ...,
+        storage_params: typing.Optional[typing.Dict[str, typing.Union[bool, int, float, str]]] = ...,
+        without_rowid: bool = ...,
     ) -> Self:
         """
         Create a new Table definition.
@@ -2359,14 +3444,24 @@ class Table(SchemaStatement):
             checks: List of check constraint expressions
             if_not_exists: Whether to use IF NOT EXISTS clause
             temporary: Whether to create a temporary table
-            comment: Table description comment
+            comment: Table description comment; rendered inline on MySQL and as a separate
+                `COMMENT ON TABLE` statement on PostgreSQL
             engine: Storage engine specification
             collate: Collation specification
             character_set: Character set specification
             extra: Additional SQL specifications
+            storage_params: PostgreSQL storage parameters (e.g. `{"fillfactor": 70}`), rendered as
+                `WITH (...)` before `extra`; string values are quoted. Ignored on other backends.
+            without_rowid: Create a SQLite `WITHOUT ROWID` table, rendered before `extra`. The
+                table must have a primary key, otherwise building it for SQLite raises
+                `ValueError`. Ignored on other backends.
 
         Returns:
             A new Table instance
+
+        Raises:
+            ValueError: If a storage parameter name isn't an (optionally namespaced) identifier,
+                or a float value isn't finite
         """
         ...
 
@@ -2385,11 +3480,43 @@ class Table(SchemaStatement):
         """Returns columns as `_TableColumnsSequence`. It is an alias for `self.columns`"""
         ...
 
+    def column_names(self) -> typing.List[str]:
+        """Returns the names of columns, in definition order."""
+        ...
+
+    def has_column(self, name: str) -> bool:
+        """Returns `True` if this table has a column named `name`."""
+        ...
+
+    def rename_column(self, from_name: str, to_name: str) -> AlterTable:
+        """
+        Create the `ALTER TABLE` statement which renames a column of this table.
+
+        Same as `AlterTable(table.name, [AlterTableRenameColumnOption(from_name, to_name)])`, without
+        the alias of the table name. This table itself is left unchanged.
+
+        Args:
+            from_name: The current name of the column
+            to_name: The new name of the column
+
+        Returns:
+            A new AlterTable
+
+        Raises:
+            KeyError: If this table has no column named `from_name`
+
+        Example:
+            >>> users.rename_column("name", "full_name").to_sql("postgresql")
+            'ALTER TABLE "users" RENAME COLUMN "name" TO "full_name"'
+        """
+        ...
+
     def __repr__(self) -> str: ...
 
 class _AliasedTableColumnsSequence:
     def __getattr__(self, name: str) -> ColumnRef: ...
     def get(self, name: str) -> ColumnRef: ...
+    def to_list(self) -> typing.Sequence[ColumnRef]: ...
     def __len__(self) -> int: ...
 
 class AliasedTable:
@@ -2662,11 +3789,21 @@ class AlterTableDropColumnOption(AlterTableOptionMeta):
 
     Example:
         >>> AlterTableDropColumnOption("deprecated_field")
+        >>> AlterTableDropColumnOption("deprecated_field", if_exists=True, cascade=True)
+        # DROP COLUMN IF EXISTS "deprecated_field" CASCADE
+
+    **Notes** \\
+    `if_exists` and `cascade` are only supported by PostgreSQL; building them for another
+    backend raises `ValueError`.
     """
 
-    def __new__(cls, name: str) -> Self: ...
+    def __new__(cls, name: str, if_exists: bool = ..., cascade: bool = ...) -> Self: ...
     @property
     def name(self) -> str: ...
+    @property
+    def if_exists(self) -> bool: ...
+    @property
+    def cascade(self) -> bool: ...
     def __repr__(self) -> str: ...
 
 class AlterTableDropForeignKeyOption(AlterTableOptionMeta):
@@ -2917,6 +4054,10 @@ class Insert(QueryStatement):
         REPLACE will delete existing rows that conflict with the new row
         before inserting.
 
+        **Notes** \\
+        Only MySQL and SQLite support `REPLACE INTO`; building for PostgreSQL raises `ValueError`.
+        Use `on_conflict` for upserts there.
+
         Returns:
             Self for method chaining
         """
@@ -2951,11 +4092,17 @@ class Insert(QueryStatement):
         """
         Specify values to insert using keyword arguments.
 
+        The first call without `columns` sets the columns from the keys. Once the columns are
+        set, the keys are matched to them, in any order.
+
         Args:
             **kwds: Column names and their values
 
         Returns:
             Self for method chaining
+
+        Raises:
+            ValueError: If the keys don't match the established columns
         """
         ...
 
@@ -2969,6 +4116,36 @@ class Insert(QueryStatement):
 
         Returns:
             Self for method chaining
+
+        Raises:
+            ValueError: If the number of values differs from the number of columns
+        """
+        ...
+
+    def values_many(
+        self,
+        rows: typing.Sequence[typing.Union[typing.Tuple[_ExprValue, ...], typing.Dict[str, _ExprValue]]],
+    ) -> Self:
+        """
+        Specify many rows to insert at once.
+
+        This is the fast path for bulk inserts; it's equivalent to calling `values` for each row.
+        Tuple rows must follow the order of `columns`. Dictionary rows are matched to columns by key;
+        if no columns were specified yet, the keys of the first dictionary row become the columns.
+
+        Example:
+            >>> Insert().into("users").columns("id", "name").values_many([(1, "a"), (2, "b")])
+            >>> Insert().into("users").values_many([{"id": 1, "name": "a"}, {"id": 2, "name": "b"}])
+
+        Args:
+            rows: A sequence of tuples or dictionaries, one for each row
+
+        Returns:
+            Self for method chaining
+
+        Raises:
+            ValueError: If a row doesn't match the columns
+            TypeError: If a row is neither a tuple nor a dictionary
         """
         ...
 
@@ -2976,11 +4153,19 @@ class Insert(QueryStatement):
         """
         Use DEFAULT VALUES if no values were specified.
 
+        **Notes** \\
+        A single row renders as `DEFAULT VALUES` on PostgreSQL and SQLite, and as `VALUES ()` on
+        MySQL. More rows render as `VALUES (DEFAULT), ...` on PostgreSQL and `VALUES (), ...` on
+        MySQL; SQLite can't insert more than one row of defaults, so building fails there.
+
         Args:
             rows: Number of rows to insert with default values
 
         Returns:
             Self for method chaining
+
+        Raises:
+            ValueError: If `rows` is less than 1
         """
         ...
 
@@ -2996,15 +4181,21 @@ class Insert(QueryStatement):
         """
         ...
 
-    def returning(self, *args: typing.Union[Column, str]) -> Self:
+    def returning(self, *args: typing.Union[Column, ColumnRef, str]) -> Self:
         """
         Specify columns to return from the inserted rows.
 
+        A table-qualified `ColumnRef` must be qualified by the table being inserted into, so
+        call `into` first.
+
         Args:
-            *args: Column names or Column objects to return
+            *args: Column names, Column or ColumnRef objects to return
 
         Returns:
             Self for method chaining
+
+        Raises:
+            ValueError: If a `ColumnRef` is an asterisk or qualified by another table
         """
         ...
 
@@ -3051,6 +4242,30 @@ class Delete(QueryStatement):
         """
         ...
 
+    def using(self, table: typing.Union[str, Table, TableName]) -> Self:
+        """
+        Add a table to the USING clause (`DELETE FROM .. USING ..`).
+
+        Use `where` to correlate the rows of both tables. Calling it multiple times
+        adds more tables to the clause.
+
+        **Notes** \\
+        Only PostgreSQL supports this syntax; building for other backends raises `ValueError`.
+
+        Example:
+            >>> Delete().from_table("orders").using("customers").where(
+            ...     (Expr.col("orders.customer_id") == Expr.col("customers.id"))
+            ...     & (Expr.col("customers.banned") == True)
+            ... )
+
+        Args:
+            table: The table name, Table object, or TableName
+
+        Returns:
+            Self for method chaining
+        """
+        ...
+
     def limit(self, n: int) -> Self:
         """
         Limit the number of rows to delete.
@@ -3171,11 +4386,22 @@ class Update(QueryStatement):
         """
         ...
 
-    def values(self, **kwds: _ExprValue) -> Self:
+    def values(
+        self, mapping: typing.Optional[typing.Dict[str, _ExprValue]] = None, /, **kwds: _ExprValue
+    ) -> Self:
         """
         Specify columns and their new values.
 
+        Columns can be given as a dictionary, as keyword arguments, or both
+        (keyword arguments win on duplicate keys). The SET clause keeps the order
+        the columns were given in. Replaces the assignments of previous `values` calls,
+        but not those of `set_expr`, except for the columns given here.
+
+        Example:
+            >>> Update().table("users").values({"name": "ali", "age": 20}, status="active")
+
         Args:
+            mapping: A dictionary of column names and their new values
             **kwds: Column names and their new values as keyword arguments
 
         Returns:
@@ -3183,6 +4409,27 @@ class Update(QueryStatement):
         """
         ...
 
+    def set_expr(self, column: typing.Union[Column, str], expr: _ExprValue) -> Self:
+        """
+        Assign an expression to a single column.
+
+        Unlike `values`, this keeps the previous assignments and only replaces
+        the one for `column` (if any); later `values` calls keep it too, unless
+        they assign `column`. The expression may reference the column itself.
+
+        Example:
+            >>> Update().table("t").set_expr("n", Expr.col("n") + 1)
+            >>> # UPDATE "t" SET "n" = "n" + 1
+
+        Args:
+            column: The column name or Column object to assign
+            expr: The new value expression
+
+        Returns:
+            Self for method chaining
+        """
+        ...
+
     def where(self, condition: _ExprValue) -> Self:
         """
         Add a WHERE condition to filter rows to update.
@@ -3319,6 +4566,47 @@ class SelectCol:
     def window(self) -> typing.Union[str, Window, None]: ...
     def __repr__(self) -> str: ...
 
+class _SubqueryColumnsSequence:
+    def __getattr__(self, name: str) -> ColumnRef: ...
+    def get(self, name: str) -> ColumnRef: ...
+    def to_list(self) -> typing.Sequence[ColumnRef]: ...
+    def __len__(self) -> int: ...
+
+class Subquery:
+    """
+    A `Select` used as a derived table under an alias. Created by `Select.subquery`.
+
+    It refers to the query, not a copy of it; later changes to the query are visible.
+    Only named projections have columns: aliased `SelectCol`s and plain column references.
+    """
+
+    @property
+    def select(self) -> Select:
+        """The wrapped query."""
+        ...
+
+    @property
+    def alias(self) -> str:
+        """The alias of the derived table."""
+        ...
+
+    @property
+    def columns(self) -> _SubqueryColumnsSequence:
+        """Returns projected columns, qualified by the alias, as `_SubqueryColumnsSequence`"""
+        ...
+
+    @property
+    def c(self) -> _SubqueryColumnsSequence:
+        """Returns projected columns as `_SubqueryColumnsSequence`. It is an alias for `self.columns`"""
+        ...
+
+    def __repr__(self) -> str: ...
+
+_JoinOn = typing.Union[
+    _ExprValue,
+    typing.List[typing.Tuple[typing.Union[str, ColumnRef], typing.Union[str, ColumnRef]]],
+]
+
 class Select(QueryStatement):
     """
     Builds SELECT SQL statements with a fluent interface.
@@ -3343,6 +4631,8 @@ class Select(QueryStatement):
         >>> Select().columns("id", "title").from_table("posts") \\
         ...     .join("users", Expr.col("posts.user_id") == Expr.col("users.id")) \\
         ...     .where(Expr.col("published") == True)
+        >>> Select(SelectCol(Expr(1) + 1, "x")).to_sql("postgresql")  # no FROM
+        'SELECT 1 + 1 AS "x"'
     """
 
     def __new__(cls, *cols: typing.Union[SelectCol, _ExprValue]) -> Self:
@@ -3357,15 +4647,31 @@ class Select(QueryStatement):
         """
         ...
 
-    def distinct(self, *on: typing.Union[Column, ColumnRef, str]) -> Self:
+    def distinct(self, *on: typing.Union[Column, ColumnRef, Expr, FunctionCall, str]) -> Self:
         """
         Make this a DISTINCT query to eliminate duplicate rows.
 
         Args:
-            *on: Optional columns for DISTINCT ON (PostgreSQL-specific)
+            *on: Optional columns or expressions for DISTINCT ON (PostgreSQL-specific)
 
         Returns:
             Self for method chaining
+
+        Raises:
+            TypeError: If an `on` target has an unsupported type
+
+        Example:
+            >>> Select(Expr.col("name"), Expr.col("id")).from_table("users").distinct(
+            ...     FunctionCall.lower(Expr.col("name"))
+            ... )
+            # SELECT DISTINCT ON (LOWER("name")) "name", "id" FROM "users"
+
+        **Notes** \\
+        DISTINCT ON is only rendered by PostgreSQL; other backends drop it.
+
+        PostgreSQL requires the ORDER BY expressions of a plain DISTINCT query to be selected, so
+        building for it raises `ValueError` otherwise. An expression counts as selected if it names
+        an output column, or is selected as is ignoring table qualifiers; selecting `*` allows anything.
         """
         ...
 
@@ -3381,12 +4687,80 @@ class Select(QueryStatement):
         """
         ...
 
-    def from_table(self, table: typing.Union[Table, TableName, str]) -> Self:
+    def add_column(self, expr: typing.Union[SelectCol, _ExprValue], alias: typing.Optional[str] = ...) -> Self:
+        """
+        Append one column to the selected columns, keeping the ones already selected.
+
+        Unlike `columns`, it can be called conditionally to build the projection step by step.
+
+        Args:
+            expr: Column name, expression, or SelectCol object to select
+            alias: Optional alias of the column; overrides the alias of a SelectCol
+
+        Returns:
+            Self for method chaining
+
+        Example:
+            >>> query = Select(Expr.col("id")).from_table("orders")
+            >>> if with_total:
+            ...     query.add_column(Expr.col("price") * Expr.col("quantity"), "total")
+            # SELECT "id", "price" * "quantity" AS "total" FROM "orders"
+        """
+        ...
+
+    @property
+    def selected_columns(self) -> typing.List[typing.Tuple[Expr, typing.Optional[str]]]:
+        """
+        The selected columns, in order, as `(expr, alias)` pairs.
+
+        `expr` is the selected `Expr` itself, and `alias` is `None` for a column without one.
+        Meant for tooling which shows what a query returns without parsing its SQL; use
+        `Expr.to_sql` to render an expression.
+
+        Example:
+            >>> [(expr.to_sql("postgresql"), alias) for expr, alias in query.selected_columns]
+            [('"id"', None), ('1', 'one')]
+        """
+        ...
+
+    def count_distinct(self, expr: _ExprValue, alias: typing.Optional[str] = ...) -> Self:
+        """
+        Select only the number of distinct values of `expr`, replacing the selected columns.
+
+        Same as `columns(SelectCol(FunctionCall.count_distinct(expr), alias))`.
+
+        Args:
+            expr: The expression whose distinct values are counted
+            alias: Optional alias of the count column
+
+        Returns:
+            Self for method chaining
+
+        Example:
+            >>> Select().from_table("orders").count_distinct(Expr.col("user_id")).to_sql("postgresql")
+            'SELECT COUNT(DISTINCT "user_id") FROM "orders"'
+        """
+        ...
+
+    def from_table(
+        self, table: typing.Union[Table, TableName, AliasedTable, Subquery, str], only: bool = ...
+    ) -> Self:
         """
         Specify the source table for the query.
 
+        With `only=True` the table is read as `FROM ONLY table`, which leaves out the rows of
+        the tables inheriting from it.
+
+        **Notes** \\
+        Only PostgreSQL has table inheritance; building for other backends raises `ValueError`.
+
+        Example:
+            >>> Select(Expr.asterisk()).from_table("parent", only=True).to_sql("postgresql")
+            'SELECT * FROM ONLY "parent"'
+
         Args:
-            table: The table name, Table object, or TableName to select from
+            table: The table name, Table object, TableName or Subquery to select from
+            only: Exclude the rows of inheriting tables; not allowed for a Subquery
 
         Returns:
             Self for method chaining
@@ -3406,6 +4780,61 @@ class Select(QueryStatement):
         """
         ...
 
+    def subquery(self, alias: str) -> Subquery:
+        """
+        Wrap this query as a derived table under `alias`.
+
+        The result can be passed to `from_table` and `join` of another query, and its
+        columns can be referenced through `Subquery.c`.
+
+        Example:
+            >>> totals = (
+            ...     Select(Expr.col("user_id"), SelectCol(FunctionCall.sum(Expr.col("amount")), "total"))
+            ...     .from_table("orders")
+            ...     .group_by(Expr.col("user_id"))
+            ...     .subquery("t")
+            ... )
+            >>> Select(totals.c.user_id).from_table(totals).where(Expr(totals.c.total) > 100)
+            # SELECT "t"."user_id" FROM (SELECT ...) AS "t" WHERE "t"."total" > 100
+
+        Args:
+            alias: Alias name for the derived table
+
+        Returns:
+            A Subquery which refers to this query
+        """
+        ...
+
+    def from_lateral(self, subquery: Select, alias: str, on: typing.Optional[_ExprValue] = None) -> Self:
+        """
+        Add a lateral subquery to the FROM list, without a JOIN keyword.
+
+        Complements `join_lateral()`; the subquery can reference the preceding FROM items.
+
+        Args:
+            subquery: The SELECT query to add laterally
+            alias: Alias name for the lateral subquery
+            on: Optional condition, added to the WHERE clause
+
+        Returns:
+            Self for method chaining
+
+        Raises:
+            TypeError: If `subquery` is not a Select
+            ValueError: If `subquery` is this statement itself
+
+        Example:
+            >>> latest = Select(Expr.col("total")).from_table("orders").where(
+            ...     Expr.col("orders.user_id") == Expr.col("users.id")
+            ... ).limit(1)
+            >>> Select(Expr.col("users.id"), Expr.col("s.total")).from_table("users").from_lateral(latest, "s")
+            # SELECT "users"."id", "s"."total" FROM "users", LATERAL (SELECT "total" FROM "orders" ...) AS "s"
+
+        **Notes** \\
+        LATERAL is supported by PostgreSQL and MySQL 8.0.14+, but not by SQLite.
+        """
+        ...
+
     def from_function(self, function: FunctionCall, alias: str) -> Self:
         """
         Use a table-returning function as the data source.
@@ -3437,6 +4866,11 @@ class Select(QueryStatement):
 
         Typically used with LIMIT for pagination.
 
+        **Notes** \\
+        MySQL and SQLite don't accept OFFSET without LIMIT; if no limit is set, the largest
+        limit the backend accepts is used (`18446744073709551615` for MySQL and
+        `9223372036854775807` for SQLite).
+
         Args:
             n: Number of rows to skip
 
@@ -3449,6 +4883,8 @@ class Select(QueryStatement):
         """
         Add a WHERE condition to filter rows.
 
+        Conditions added by repeated calls are combined with AND.
+
         Args:
             condition: The filter condition expression
 
@@ -3457,11 +4893,67 @@ class Select(QueryStatement):
         """
         ...
 
+    def clear_where(self) -> Self:
+        """
+        Remove all WHERE conditions.
+
+        **Notes** \\
+        The condition given to `from_lateral` is kept in WHERE too, so it's removed as well.
+
+        Returns:
+            Self for method chaining
+        """
+        ...
+
+    def has_where(self) -> bool:
+        """
+        Whether any WHERE condition has been added.
+
+        Example:
+            >>> query = Select(Expr.asterisk()).from_table("users")
+            >>> if name is not None:
+            ...     query.where(Expr.col("name") == name)
+            >>> query.has_where()
+        """
+        ...
+
     def having(self, condition: _ExprValue) -> Self:
         """
         Add a HAVING condition to filter grouped results.
 
-        Used with GROUP BY to filter aggregated data.
+        Used with GROUP BY to filter aggregated data. Replaces the current HAVING condition;
+        use `and_having` or `or_having` to extend it instead.
+
+        Args:
+            condition: The filter condition expression
+
+        Returns:
+            Self for method chaining
+        """
+        ...
+
+    def and_having(self, condition: _ExprValue) -> Self:
+        """
+        Combine the current HAVING condition with `condition` using AND.
+
+        Same as `having` when there's no HAVING condition yet.
+
+        Args:
+            condition: The filter condition expression
+
+        Returns:
+            Self for method chaining
+
+        Example:
+            >>> query.having(FunctionCall.count(Expr.col("id")) > 5).and_having(Expr.col("region") != "eu")
+        """
+        ...
+
+    def or_having(self, condition: _ExprValue) -> Self:
+        """
+        Combine the current HAVING condition with `condition` using OR.
+
+        Same as `having` when there's no HAVING condition yet.
 
         Args:
             condition: The filter condition expression
@@ -3471,6 +4963,11 @@ class Select(QueryStatement):
         """
         ...
 
+    @property
+    def current_having(self) -> typing.Optional[Expr]:
+        """The current HAVING condition, or `None` if there's none."""
+        ...
+
     def order_by(
         self,
         target: _ExprValue,
@@ -3480,8 +4977,99 @@ class Select(QueryStatement):
         """
         Specify the order of results.
 
+        Args:
+            target: The expression to sort by
+            order: Sort direction
+            null_order: Optional placement of NULL values ("first" or "last")
+
+        Returns:
+            Self for method chaining
+
+        **Notes** \\
+        MySQL has no `NULLS FIRST/LAST`, so `null_order` is emulated there with an extra
+        `target IS NULL` sort key, e.g. `ORDER BY "a" IS NULL ASC, "a" DESC` for `("a", "desc", "last")`.
+        """
+        ...
+
+    def order_by_columns(
+        self,
+        *specs: typing.Union[
+            typing.Tuple[_ExprValue, typing.Literal["asc", "desc"]],
+            typing.Tuple[_ExprValue, typing.Literal["asc", "desc"], typing.Optional[typing.Literal["first", "last"]]],
+        ],
+    ) -> Self:
+        """
+        Add several ORDER BY clauses at once, in the given order.
+
+        Args:
+            *specs: `(target, order)` or `(target, order, null_order)` tuples, as accepted by `order_by`
+
+        Returns:
+            Self for method chaining
+
+        Raises:
+            TypeError: If a spec is not a tuple
+            ValueError: If a spec has the wrong length or an invalid order
+
+        Example:
+            >>> Select(Expr.asterisk()).from_table("users").order_by_columns(
+            ...     (Expr.col("last_name"), "asc"),
+            ...     (Expr.col("first_name"), "asc"),
+            ...     (Expr.col("created_at"), "desc", "last"),
+            ... )
+            # SELECT * FROM "users" ORDER BY "last_name" ASC, "first_name" ASC, "created_at" DESC NULLS LAST
+        """
+        ...
+
+    def top_n_per_group(
+        self,
+        partition_by: typing.Sequence[_ExprValue],
+        order_by: typing.Sequence[
+            typing.Union[
+                typing.Tuple[_ExprValue, typing.Literal["asc", "desc"]],
+                typing.Tuple[
+                    _ExprValue, typing.Literal["asc", "desc"], typing.Optional[typing.Literal["first", "last"]]
+                ],
+            ]
+        ],
+        n: int,
+        rank_alias: typing.Optional[str] = None,
+        alias: typing.Optional[str] = None,
+    ) -> Self:
+        """
+        Keep only the first `n` rows of each group.
+
+        The current query gets a `ROW_NUMBER()` column partitioned by `partition_by` and ordered
+        by `order_by`, and is wrapped in a subquery which is filtered on it.
+
+        **Notes** \\
+        This rewrites the query in place: methods called afterwards apply to the outer query.
+        The outer query selects the original columns by name, so the rank column isn't part of
+        the result; every selected column must be a column or an aliased expression, and the
+        names must be unique. ORDER BY, LIMIT, OFFSET and the lock stay on the outer query, so
+        they apply to the kept rows and may only refer to the selected columns.
+
+        Args:
+            partition_by: Expressions defining the groups
+            order_by: `(target, order)` or `(target, order, null_order)` tuples, as accepted by `order_by`
+            n: Maximum number of rows kept per group
+            rank_alias: Name of the rank column; by default the first of "rn", "rn_1", "rn_2", ...
+                which isn't a selected column name
+            alias: Alias of the subquery; by default the first of "ranked", "ranked_1", ... which
+                isn't the name of a table, subquery or CTE of the query
+
         Returns:
             Self for method chaining
+
+        Raises:
+            ValueError: If `n` is zero, an order spec is invalid, a selected column has no name or
+                the same name as another, `rank_alias` is a selected column name, or `alias` is empty
+
+        Example:
+            >>> Select(Expr.col("name"), Expr.col("dept")).from_table("employees").top_n_per_group(
+            ...     [Expr.col("dept")], [(Expr.col("salary"), "desc")], 3
+            ... )
+            # SELECT "name", "dept" FROM (SELECT "name", "dept", ROW_NUMBER() OVER ( PARTITION BY "dept" ORDER BY "salary" DESC ) AS "rn" FROM "employees") AS "ranked" WHERE "rn" <= 3
         """
         ...
 
@@ -3494,6 +5082,11 @@ class Select(QueryStatement):
         """
         Add row locking for transactional queries (FOR UPDATE/FOR SHARE).
 
+        **Notes** \\
+        Any `behavior` may be combined with either lock type; `FOR SHARE ... NOWAIT` and
+        `FOR SHARE ... SKIP LOCKED` are valid on both PostgreSQL and MySQL 8, so they're passed through as is.
+        SQLite has no row locking, so the whole clause is omitted there.
+
         Args:
             type: Lock type - "exclusive" (FOR UPDATE) or "shared" (FOR SHARE)
             behavior: Optional lock behavior - "nowait" or "skip" (SKIP LOCKED)
@@ -3504,6 +5097,36 @@ class Select(QueryStatement):
         """
         ...
 
+    def of(self, *tables: typing.Union[str, TableName, Table]) -> Self:
+        """
+        Restrict the lock to rows of specific tables (`FOR UPDATE OF a, b`).
+
+        Same as the `tables` argument of `lock`, which must be called first. Replaces the tables
+        given before.
+
+        **Notes** \\
+        A lock target is a table of FROM or of a join, or one of their aliases. It's rendered the way
+        the query refers to that table: by its alias if it's aliased, or else by its bare name, so
+        `of("accounts")` locks `FROM "accounts" AS "a"` as `FOR UPDATE OF "a"`.
+
+        Example:
+            >>> Select(Expr.asterisk()).from_table(TableName("accounts", alias="a")).join(
+            ...     TableName("orders", alias="o"), Expr.col("a.id") == Expr.col("o.account_id")
+            ... ).lock().of("a")
+            # SELECT * FROM "accounts" AS "a" JOIN ... FOR UPDATE OF "a"
+
+        Args:
+            *tables: The tables whose rows are locked
+
+        Returns:
+            Self for method chaining
+
+        Raises:
+            ValueError: If `lock` wasn't called. Building fails if a table isn't in FROM or a join,
+                or is joined under several aliases.
+        """
+        ...
+
     def group_by(
         self,
         *cols: _ExprValue,
@@ -3511,6 +5134,31 @@ class Select(QueryStatement):
         """
         Group results by specified columns for aggregation.
 
+        Replaces the groups given before; use `add_group_by` to append.
+
+        Args:
+            *cols: Column names or expressions to group by
+
+        Returns:
+            Self for method chaining
+        """
+        ...
+
+    def add_group_by(
+        self,
+        *cols: _ExprValue,
+    ) -> Self:
+        """
+        Append columns to the GROUP BY clause, keeping the groups given before.
+
+        Useful when the grouping keys are assembled conditionally.
+
+        Example:
+            >>> query = Select(Expr.col("country"), FunctionCall.count(Expr.asterisk())).from_table("users")
+            >>> query.add_group_by(Expr.col("country"))
+            >>> if by_city:
+            ...     query.add_group_by(Expr.col("city"))
+
         Args:
             *cols: Column names or expressions to group by
 
@@ -3527,6 +5175,15 @@ class Select(QueryStatement):
         """
         Combine this query with another using set operations.
 
+        Set operations apply from left to right, so `a.union(b).union(c, "all")` is
+        `(a UNION b) UNION ALL c`. To group the other way, combine with a statement which is
+        compound itself: `a.union(b.union(c), "all")` is `a UNION ALL (b UNION c)`.
+
+        **Notes** \\
+        SQLite has no parentheses around the combined statements, so there a statement which is
+        compound itself, or has its own ORDER BY, LIMIT or OFFSET, is combined as
+        `SELECT * FROM (...) AS "compound"`.
+
         Args:
             statement: The SELECT query to combine with
             type: Set operation type:
@@ -3537,21 +5194,119 @@ class Select(QueryStatement):
 
         Returns:
             Self for method chaining
+
+        Raises:
+            ValueError: If `statement` is this statement, or already combines it at any depth
+        """
+        ...
+
+    def with_recursive(
+        self,
+        name: str,
+        base_select: Self,
+        recursive_select: Self,
+        union_all: bool = True,
+        columns: typing.Sequence[str] = ...,
+    ) -> Self:
+        """
+        Prefix this query with a recursive common table expression (`WITH RECURSIVE`).
+
+        The CTE is `base_select` combined with `recursive_select` using UNION ALL (or UNION
+        if `union_all` is False). Use `from_table(name)` to read from it.
+
+        **Notes** \\
+        The WITH clause is only rendered when this query is built directly (`build`/`to_sql`),
+        not when it's used as a subquery of another statement.
+
+        Args:
+            name: The CTE name
+            base_select: The anchor (non-recursive) member
+            recursive_select: The recursive member, which should read from `name`
+            union_all: Whether to combine members with UNION ALL instead of UNION
+            columns: Optional column names of the CTE
+
+        Returns:
+            Self for method chaining
+
+        Example:
+            >>> base = Select(SelectCol(1, "n"))
+            >>> step = Select(Expr.col("n") + 1).from_table("t").where(Expr.col("n") < 10)
+            >>> Select(Expr.col("n")).with_recursive("t", base, step).from_table("t")
+            # WITH RECURSIVE "t" AS (SELECT 1 AS "n" UNION ALL SELECT "n" + 1 FROM "t" WHERE "n" < 10)
+            # SELECT "n" FROM "t"
+        """
+        ...
+
+    def add_cte_from(self, name: str) -> Self:
+        """
+        Add the CTE called `name`, defined by `with_recursive`, to the FROM clause.
+
+        Same as `from_table(name)`, but checks that this query defines the CTE.
+
+        Args:
+            name: The CTE name
+
+        Returns:
+            Self for method chaining
+
+        Raises:
+            ValueError: If this query defines no CTE called `name`
+
+        Example:
+            >>> Select(Expr.col("n")).with_recursive("t", base, step).add_cte_from("t")
+            # WITH RECURSIVE "t" AS (...) SELECT "n" FROM "t"
+        """
+        ...
+
+    def join_cte(
+        self,
+        name: str,
+        on: typing.Optional[_JoinOn] = ...,
+        type: typing.Literal["", "cross", "full", "inner", "right", "left"] = ...,
+    ) -> Self:
+        """
+        Join the CTE called `name`, defined by `with_recursive`.
+
+        Same as `join(name, on, type)`, but checks that this query defines the CTE.
+
+        Args:
+            name: The CTE name
+            on: The join condition (see join() for the accepted forms); omitted for "cross"
+            type: Join type (see join() for options)
+
+        Returns:
+            Self for method chaining
+
+        Raises:
+            ValueError: If this query defines no CTE called `name`, `type` is invalid, or `on` is
+                missing for a join other than "cross", or given for "cross"
+
+        Example:
+            >>> (
+            ...     Select(Expr.col("p.name"))
+            ...     .with_recursive("tree", base, step)
+            ...     .from_table(TableName("products", alias="p"))
+            ...     .join_cte("tree", Expr.col("tree.id") == Expr.col("p.category_id"))
+            ... )
+            # WITH RECURSIVE "tree" AS (...) SELECT "p"."name" FROM "products" AS "p"
+            # JOIN "tree" ON "tree"."id" = "p"."category_id"
         """
         ...
 
     def join(
         self,
-        table: typing.Union[str, TableName, Table, AliasedTable],
-        on: _ExprValue,
+        table: typing.Union[str, TableName, Table, AliasedTable, Subquery],
+        on: typing.Optional[_JoinOn] = ...,
         type: typing.Literal["", "cross", "full", "inner", "right", "left"] = ...,
     ) -> Self:
         """
         Join another table to the query.
 
         Args:
-            table: The table name, Table object, or TableName to join
-            on: The join condition expression
+            table: The table name, Table object, TableName or Subquery to join
+            on: The join condition expression, or a list of `(left, right)` column pairs which are
+                compared with `=` and combined with `AND`. Required by every join type except
+                "cross", which takes no condition
             type: Join type:
                 - "": Default join (typically INNER)
                 - "inner": INNER JOIN
@@ -3562,6 +5317,17 @@ class Select(QueryStatement):
 
         Returns:
             Self for method chaining
+
+        Raises:
+            ValueError: If `on` is missing for a join other than "cross", or given for "cross",
+                or is an empty list
+            TypeError: If `on` is a list with items other than `(left, right)` tuples
+
+        Example:
+            >>> Select(Expr.asterisk()).from_table("a").join("b", [("a.id", "b.a_id"), ("a.kind", "b.kind")])
+            # SELECT * FROM "a" JOIN "b" ON "a"."id" = "b"."a_id" AND "a"."kind" = "b"."kind"
+            >>> Select(Expr.asterisk()).from_table("sizes").join("colors", type="cross")
+            # SELECT * FROM "sizes" CROSS JOIN "colors"
         """
         ...
 
@@ -3569,7 +5335,7 @@ class Select(QueryStatement):
         self,
         query: Self,
         alias: str,
-        on: _ExprValue,
+        on: typing.Optional[_JoinOn] = ...,
         type: typing.Literal["", "cross", "full", "inner", "right", "left"] = ...,
     ) -> Self:
         """
@@ -3581,15 +5347,97 @@ class Select(QueryStatement):
         Args:
             query: The SELECT query to join laterally
             alias: Alias name for the lateral subquery
-            on: The join condition expression
+            on: The join condition (see join() for the accepted forms); omitted for "cross"
+            type: Join type (see join() for options)
+
+        Returns:
+            Self for method chaining
+
+        Raises:
+            TypeError: If `query` is not a Select
+            ValueError: If `on` is missing for a join other than "cross", or given for "cross"
+        """
+        ...
+
+    def join_subquery(
+        self,
+        query: Self,
+        alias: str,
+        on: typing.Optional[_JoinOn] = ...,
+        type: typing.Literal["", "cross", "full", "inner", "right", "left"] = ...,
+    ) -> Self:
+        """
+        Join an aliased subquery, without LATERAL.
+
+        Same as joining `query.subquery(alias)`. Use `join_lateral` when the subquery
+        references columns of preceding FROM items.
+
+        Args:
+            query: The SELECT query to join
+            alias: Alias name for the subquery
+            on: The join condition (see join() for the accepted forms); omitted for "cross"
             type: Join type (see join() for options)
 
         Returns:
             Self for method chaining
+
+        Raises:
+            TypeError: If `query` is not a Select
+            ValueError: If `query` is this Select itself, or `on` is missing for a join other than
+                "cross", or given for "cross"
+
+        Example:
+            >>> totals = (
+            ...     Select(Expr.col("user_id"), SelectCol(FunctionCall.sum(Expr.col("amount")), "total"))
+            ...     .from_table("orders")
+            ...     .group_by(Expr.col("user_id"))
+            ... )
+            >>> query = (
+            ...     Select(Expr.col("u.name"), Expr.col("t.total"))
+            ...     .from_table(TableName("users", alias="u"))
+            ...     .join_subquery(totals, "t", Expr.col("t.user_id") == Expr.col("u.id"))
+            ... )
+            # SELECT ... FROM "users" AS "u" JOIN (SELECT ...) AS "t" ON "t"."user_id" = "u"."id"
         """
         ...
 
     def window(self, name: str, statement: Window) -> Self: ...
+    def explain(
+        self,
+        backend: _Backends,
+        analyze: bool = ...,
+        format: typing.Optional[str] = ...,
+    ) -> str:
+        """
+        Build this query as a SQL string prefixed with `EXPLAIN`, to inspect its plan.
+
+        **This method is unsafe and can cause SQL injection.** It's meant for debugging only.
+
+        **Notes** \\
+        Each backend has its own options:
+        - PostgreSQL: `EXPLAIN [ANALYZE]`, or `EXPLAIN ([ANALYZE, ]FORMAT x)` where `x` is one of
+          `TEXT`, `JSON`, `XML` and `YAML`
+        - MySQL: `EXPLAIN [FORMAT=x]` where `x` is one of `TRADITIONAL`, `JSON` and `TREE`, or
+          `EXPLAIN ANALYZE`, which only has the `TREE` format
+        - SQLite: `EXPLAIN QUERY PLAN`, without `ANALYZE` or formats
+
+        Args:
+            backend: The database backend that determines SQL dialect
+            analyze: Run the query and report actual timings
+            format: Output format of the plan, case-insensitive
+
+        Returns:
+            The `EXPLAIN` statement as a string
+
+        Raises:
+            ValueError: If `backend` doesn't support `analyze` or `format`
+
+        Example:
+            >>> Select(Expr.col("id")).from_table("users").explain("postgresql", format="json")
+            'EXPLAIN (FORMAT JSON) SELECT "id" FROM "users"'
+        """
+        ...
+
     def __repr__(self) -> str: ...
 
 class Case:
@@ -99,14 +99,46 @@ impl Drop for PythonValue {
 }
 
 impl PythonValue {
+    /// Same as [`Clone::clone`], except that the mutable objects (JSON values
+    /// and vectors) are copied with `copy.deepcopy` instead of shared.
+    pub fn deep_clone(&self, memo: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<Self> {
+        use pyo3::types::PyAnyMethods;
+
+        let deepcopy = |x: &NonNull<pyo3::ffi::PyObject>| unsafe {
+            let object = pyo3::Bound::from_borrowed_ptr(memo.py(), x.as_ptr());
+            let copied = memo
+                .py()
+                .import("copy")?
+                .getattr("deepcopy")?
+                .call1((object, memo))?;
+
+            Ok::<_, pyo3::PyErr>(NonNull::new_unchecked(copied.into_ptr()))
+        };
+
+        match self {
+            Self::Json(x) => Ok(Self::Json(deepcopy(x)?)),
+            Self::Vector(x) => Ok(Self::Vector(deepcopy(x)?)),
+            Self::Array(x) => {
+                let mut values = Vec::with_capacity(x.len());
+                for value in x {
+                    values.push(value.deep_clone(memo)?);
+                }
+                Ok(Self::Array(values))
+            }
+            _ => Ok(self.clone()),
+        }
+    }
+
+    /// Returns a new reference to the Python object, or null with an exception
+    /// set on failure.
     pub unsafe fn as_pyobject(&self) -> *mut pyo3::ffi::PyObject {
         match self {
-            Self::Null => pyo3::ffi::Py_None(),
+            Self::Null => pyo3::ffi::Py_NewRef(pyo3::ffi::Py_None()),
             Self::Bool(x) => {
                 if *x {
-                    pyo3::ffi::Py_True()
+                    pyo3::ffi::Py_NewRef(pyo3::ffi::Py_True())
                 } else {
-                    pyo3::ffi::Py_False()
+                    pyo3::ffi::Py_NewRef(pyo3::ffi::Py_False())
                 }
             }
             Self::BigInt(x) => pyo3::ffi::PyLong_FromLongLong(*x),
@@ -154,11 +186,19 @@ impl PythonValue {
             }
             Self::Array(x) => {
                 let arr = pyo3::ffi::PyList_New(x.len() as isize);
+                if arr.is_null() {
+                    return arr;
+                }
 
                 for (index, item) in x.iter().enumerate() {
                     let item = item.as_pyobject();
-                    if pyo3::ffi::PyList_SetItem(arr, index as isize, item) == 0 {
-                        pyo3::ffi::Py_DECREF(item);
+                    if item.is_null() {
+                        pyo3::ffi::Py_DECREF(arr);
+                        return item;
+                    }
+
+                    // `PyList_SetItem` steals the reference to `item`, even on failure
+                    if pyo3::ffi::PyList_SetItem(arr, index as isize, item) != 0 {
                         pyo3::ffi::Py_DECREF(arr);
                         return std::ptr::null_mut();
                     }
@@ -38,14 +38,95 @@ impl From<RustValue> for ReturnableValue {
 }
 
 impl ReturnableValue {
+    /// Converts a `Money` value into `decimal.Decimal`.
+    ///
+    /// Accepts `decimal.Decimal`, `float`, `int` and `str`. Floats go through
+    /// their shortest representation, so `0.1` becomes `Decimal("0.1")`
+    /// rather than its exact binary value.
+    fn money_to_decimal(
+        object: pyo3::Bound<'_, pyo3::PyAny>,
+    ) -> pyo3::PyResult<pyo3::Bound<'_, pyo3::PyAny>> {
+        unsafe {
+            if pyo3::ffi::Py_IS_TYPE(object.as_ptr(), crate::typeref::STD_DECIMAL_TYPE) == 0 {
+                let text = if pyo3::ffi::PyUnicode_CheckExact(object.as_ptr()) == 1 {
+                    object.clone()
+                } else if pyo3::ffi::PyFloat_CheckExact(object.as_ptr()) == 1
+                    || pyo3::ffi::PyLong_CheckExact(object.as_ptr()) == 1
+                {
+                    object.str()?.into_any()
+                } else {
+                    return Err(typeerror!(
+                        "expected decimal.Decimal, float, int or str, got {}",
+                        object.py(),
+                        object.as_ptr()
+                    ));
+                };
+
+                let decimal_type = pyo3::Bound::from_borrowed_ptr(
+                    object.py(),
+                    crate::typeref::STD_DECIMAL_TYPE as *mut pyo3::ffi::PyObject,
+                );
+
+                let decimal = decimal_type.call1((&text,)).map_err(|_| {
+                    pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "invalid money value: {text}"
+                    ))
+                })?;
+
+                return Self::finite_decimal(decimal);
+            }
+
+            Self::finite_decimal(object)
+        }
+    }
+
+    #[inline]
+    fn finite_decimal(object: pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<pyo3::Bound<'_, pyo3::PyAny>> {
+        if !object.call_method0("is_finite")?.is_truthy()? {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "money value must be finite, got {object}"
+            )));
+        }
+
+        Ok(object)
+    }
+
+    /// Checks that the integer `val` fits in the integer column type `type`.
+    fn check_integer_range<T>(val: T, r#type: &sea_query::ColumnType) -> pyo3::PyResult<()>
+    where
+        T: Copy + std::fmt::Display + Into<i128>,
+    {
+        let (min, max): (i128, i128) = match r#type {
+            sea_query::ColumnType::TinyInteger => (i8::MIN.into(), i8::MAX.into()),
+            sea_query::ColumnType::SmallInteger => (i16::MIN.into(), i16::MAX.into()),
+            sea_query::ColumnType::Integer => (i32::MIN.into(), i32::MAX.into()),
+            sea_query::ColumnType::TinyUnsigned => (0, u8::MAX.into()),
+            sea_query::ColumnType::SmallUnsigned => (0, u16::MAX.into()),
+            sea_query::ColumnType::Unsigned => (0, u32::MAX.into()),
+            _ => return Ok(()),
+        };
+
+        if (min..=max).contains(&val.into()) {
+            Ok(())
+        } else {
+            Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "{val} is out of range for {type:?}, expected {min} to {max}"
+            )))
+        }
+    }
+
+    /// Validates and adapts `object` for `type`.
+    ///
+    /// Integers are all kept as BigInt and BigUnsigned, so there's no
+    /// difference between tiny, small or normal integers here; unless
+    /// `strict` is set, which checks the value against the range of the
+    /// exact integer type.
     #[inline]
     pub fn with_specific_type(
         object: pyo3::Bound<'_, pyo3::PyAny>,
         r#type: std::sync::Arc<sea_query::ColumnType>,
+        strict: bool,
     ) -> pyo3::PyResult<Self> {
-        // Validate object depend on `type`
-        // Note that we're only using BigInt and BigUnsigned for integers,
-        // so there's no different between tiny, small, or normal integers for us.
         match &*r#type {
             sea_query::ColumnType::Boolean => unsafe {
                 if pyo3::ffi::PyBool_Check(object.as_ptr()) == 0 {
@@ -65,6 +146,9 @@ impl ReturnableValue {
                 if val == -1 && !pyo3::ffi::PyErr_Occurred().is_null() {
                     return Err(pyo3::PyErr::fetch(object.py()));
                 }
+                if strict {
+                    Self::check_integer_range(val, &r#type)?;
+                }
 
                 Ok(Self::from(PythonValue::BigInt(val)))
             },
@@ -76,6 +160,9 @@ impl ReturnableValue {
                 if val == u64::MAX && !pyo3::ffi::PyErr_Occurred().is_null() {
                     return Err(pyo3::PyErr::fetch(object.py()));
                 }
+                if strict {
+                    Self::check_integer_range(val, &r#type)?;
+                }
 
                 Ok(Self::from(PythonValue::BigUnsigned(val)))
             },
@@ -126,7 +213,14 @@ impl ReturnableValue {
 
                 Ok(Self::from(PythonValue::Double(val)))
             },
-            sea_query::ColumnType::Decimal(_) | sea_query::ColumnType::Money(_) => unsafe {
+            sea_query::ColumnType::Money(_) => unsafe {
+                let object = Self::money_to_decimal(object)?;
+
+                Ok(Self::from(PythonValue::Decimal(NonNull::new_unchecked(
+                    object.into_ptr(),
+                ))))
+            },
+            sea_query::ColumnType::Decimal(_) => unsafe {
                 // TODO: Support float
                 if pyo3::ffi::Py_IS_TYPE(object.as_ptr(), crate::typeref::STD_DECIMAL_TYPE) == 0 {
                     return Err(typeerror!(
@@ -234,7 +328,7 @@ impl ReturnableValue {
                 let mut values = Vec::with_capacity(list.len());
 
                 for item in list.iter() {
-                    let x = Self::with_specific_type(item, std::sync::Arc::clone(ty))?;
+                    let x = Self::with_specific_type(item, std::sync::Arc::clone(ty), strict)?;
                     values.push(x.deserialized.unwrap());
                 }
 
@@ -280,11 +374,16 @@ impl ReturnableValue {
                 return Self::with_specific_type(
                     object,
                     std::sync::Arc::new(sea_query::ColumnType::BigInteger),
+                    false,
                 );
             }
 
             if pyo3::ffi::PyFloat_CheckExact(object.as_ptr()) == 1 {
-                return Self::with_specific_type(object, std::sync::Arc::new(sea_query::ColumnType::Double));
+                return Self::with_specific_type(
+                    object,
+                    std::sync::Arc::new(sea_query::ColumnType::Double),
+                    false,
+                );
             }
 
             if pyo3::ffi::PyUnicode_CheckExact(object.as_ptr()) == 1 {
@@ -356,6 +455,7 @@ impl ReturnableValue {
     pub fn from_bound(
         object: pyo3::Bound<'_, pyo3::PyAny>,
         r#type: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+        strict: bool,
     ) -> pyo3::PyResult<Self> {
         unsafe {
             if pyo3::ffi::Py_IsNone(object.as_ptr()) == 1 {
@@ -378,7 +478,7 @@ impl ReturnableValue {
             })?
         };
 
-        Self::with_specific_type(object, std::sync::Arc::new(r#type))
+        Self::with_specific_type(object, std::sync::Arc::new(r#type), strict)
     }
 
     #[inline]
@@ -443,10 +543,11 @@ impl From<ReturnableValue> for PyAdaptedValue {
 #[pyo3::pymethods]
 impl PyAdaptedValue {
     #[new]
-    #[pyo3(signature=(value, r#type=None))]
+    #[pyo3(signature=(value, r#type=None, strict=false))]
     pub fn new(
         value: pyo3::Bound<'_, pyo3::PyAny>,
         r#type: Option<pyo3::Bound<'_, pyo3::PyAny>>,
+        strict: bool,
     ) -> pyo3::PyResult<pyo3::PyClassInitializer<Self>> {
         if value.is_instance_of::<Self>() {
             return Ok(pyo3::PyClassInitializer::from(unsafe {
@@ -454,7 +555,7 @@ impl PyAdaptedValue {
             }));
         }
 
-        let result = ReturnableValue::from_bound(value, r#type.as_ref())?;
+        let result = ReturnableValue::from_bound(value, r#type.as_ref(), strict)?;
         let slf = Self {
             inner: parking_lot::Mutex::new(result),
         };
@@ -587,7 +688,7 @@ impl PyAdaptedValue {
         let mut lock = self.inner.lock();
         let obj = lock.deserialize(py);
 
-        unsafe { pyo3::Py::from_borrowed_ptr_or_err(py, obj.as_pyobject()) }
+        unsafe { pyo3::Py::from_owned_ptr_or_err(py, obj.as_pyobject()) }
     }
 
     fn __hash__(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<isize> {
@@ -676,6 +777,24 @@ impl PyAdaptedValue {
         }
     }
 
+    fn __deepcopy__(&self, memo: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<Self> {
+        // Copied before `copy.deepcopy` runs, since it may call back into this value
+        let value = self.inner.lock().clone();
+
+        let deserialized = match &value.deserialized {
+            Some(x) => Some(x.deep_clone(memo)?),
+            None => None,
+        };
+        let inner = ReturnableValue {
+            deserialized,
+            serialized: value.serialized,
+        };
+
+        Ok(Self {
+            inner: parking_lot::Mutex::new(inner),
+        })
+    }
+
     fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<String> {
         let mut lock = self.inner.lock();
         let expr = lock.create_simple_expr(backend.py());
@@ -689,6 +808,36 @@ impl PyAdaptedValue {
         Ok(sql)
     }
 
+    fn to_param_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<(String, Self)> {
+        let expr = {
+            let mut lock = self.inner.lock();
+            lock.create_simple_expr(backend.py())
+        };
+
+        let builder = crate::backend::into_query_builder(backend)?;
+
+        let (placeholder, numbered) = builder.placeholder();
+        let mut sql = sea_query::SqlWriterValues::new(placeholder, numbered);
+
+        let assert_unwind = std::panic::AssertUnwindSafe(|| builder.prepare_simple_expr(&expr, &mut sql));
+
+        std::panic::catch_unwind(assert_unwind)
+            .map_err(|_| pyo3::PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("build failed"))?;
+
+        let (sql, values) = sql.into_parts();
+
+        // The expression is a single `SimpleExpr::Value`, so exactly one value is
+        // collected
+        let value = values
+            .into_iter()
+            .next()
+            .map(RustValue::from)
+            .map(ReturnableValue::from)
+            .ok_or_else(|| pyo3::PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("build failed"))?;
+
+        Ok((sql, value.into()))
+    }
+
     fn __repr__(&self) -> String {
         let lock = self.inner.lock();
 
@@ -701,3 +850,42 @@ impl PyAdaptedValue {
         }
     }
 }
+
+/// Adapts every item of `values` like `AdaptedValue(value, type, strict)`, in
+/// one call.
+///
+/// Unlike `AdaptedValue`, which returns an `AdaptedValue` as is, an
+/// `AdaptedValue` item is copied, and adapted again from its value if `type` is
+/// given.
+#[pyo3::pyfunction]
+#[pyo3(signature=(values, r#type=None, strict=false))]
+pub fn adapt_many(
+    values: &pyo3::Bound<'_, pyo3::PyAny>,
+    r#type: Option<pyo3::Bound<'_, pyo3::PyAny>>,
+    strict: bool,
+) -> pyo3::PyResult<Vec<pyo3::Py<PyAdaptedValue>>> {
+    let py = values.py();
+    let mut adapted = Vec::with_capacity(values.len().unwrap_or_default());
+
+    for value in values.try_iter()? {
+        let value = value?;
+
+        if let Ok(x) = value.cast::<PyAdaptedValue>() {
+            let result = match &r#type {
+                Some(r#type) => {
+                    let value = x.get().value(py)?.into_bound(py);
+                    ReturnableValue::from_bound(value, Some(r#type), strict)?
+                }
+                None => x.get().inner.lock().clone(),
+            };
+
+            adapted.push(pyo3::Py::new(py, PyAdaptedValue::from(result))?);
+            continue;
+        }
+
+        let result = ReturnableValue::from_bound(value, r#type.as_ref(), strict)?;
+        adapted.push(pyo3::Py::new(py, PyAdaptedValue::from(result))?);
+    }
+
+    Ok(adapted)
+}
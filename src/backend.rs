@@ -16,47 +16,1486 @@ pub struct PySchemaStatement;
 )]
 pub struct PyQueryStatement;
 
+thread_local! {
+    /// The first error found while building the current statement; see [`report_build_error`].
+    static BUILD_ERROR: std::cell::RefCell<Option<String>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Records why the statement being built can't be rendered for its backend.
+///
+/// Builders have no way to return errors, so they record the first one and go
+/// on; the build macros raise it as `ValueError` once the statement is built.
+pub(crate) fn report_build_error(message: String) {
+    BUILD_ERROR.with_borrow_mut(|error| {
+        error.get_or_insert(message);
+    });
+}
+
+/// Forgets the error of a previous build; called before building a statement.
+pub(crate) fn clear_build_error() {
+    BUILD_ERROR.with_borrow_mut(|error| *error = None);
+}
+
+/// Returns the error recorded while building the statement as `ValueError`.
+pub(crate) fn take_build_error() -> pyo3::PyResult<()> {
+    match BUILD_ERROR.with_borrow_mut(Option::take) {
+        Some(message) => Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(message)),
+        None => Ok(()),
+    }
+}
+
+/// Custom binary operator of `Expr.is_distinct_from`.
+///
+/// Rendered as-is on PostgreSQL and SQLite; [`MysqlBuilder`] rewrites it to
+/// `NOT (a <=> b)`.
+pub(crate) const IS_DISTINCT_FROM: &str = "IS DISTINCT FROM";
+
+/// Custom binary operator of `Expr.is_not_distinct_from`.
+///
+/// Rendered as-is on PostgreSQL and SQLite; [`MysqlBuilder`] rewrites it to
+/// `a <=> b`.
+pub(crate) const IS_NOT_DISTINCT_FROM: &str = "IS NOT DISTINCT FROM";
+
+/// Custom binary operators of `Expr.similar_to` and `Expr.not_similar_to`.
+///
+/// Rendered as-is on PostgreSQL; [`MysqlBuilder`] and [`SqliteBuilder`] have no
+/// `SIMILAR TO` and report a build error instead.
+pub(crate) const SIMILAR_TO: &str = "SIMILAR TO";
+pub(crate) const NOT_SIMILAR_TO: &str = "NOT SIMILAR TO";
+
+/// Custom binary operator of `Expr.overlaps`, between two `(start, end)` rows.
+///
+/// Rendered as-is on PostgreSQL; [`MysqlBuilder`] and [`SqliteBuilder`] have no
+/// `OVERLAPS` and report a build error instead.
+pub(crate) const OVERLAPS: &str = "OVERLAPS";
+
+/// Custom binary operator of `Expr.__floordiv__`.
+///
+/// Rendered by [`prepare_extended_expr`] as `FLOOR(a / b)` on MySQL, and as
+/// `FLOOR(CAST(a AS numeric) / b)` (`real` on SQLite) on the other backends, so
+/// a negative quotient is rounded down as in Python.
+pub(crate) const FLOOR_DIV: &str = "FLOOR_DIV";
+
+/// Custom binary operators of `Expr.lshift` and `Expr.rshift` with `strict`.
+///
+/// Rendered as-is, like the plain shift operators, but [`PostgresBuilder`]
+/// checks their operands first.
+pub(crate) const STRICT_LSHIFT: &str = "<<";
+pub(crate) const STRICT_RSHIFT: &str = ">>";
+
+/// Reports a build error on the custom operators which only PostgreSQL has.
 #[inline]
-#[optimize(speed)]
-pub(crate) fn into_query_builder(
-    object: &pyo3::Bound<'_, pyo3::PyAny>,
-) -> pyo3::PyResult<Box<dyn sea_query::QueryBuilder>> {
-    let val = unsafe {
-        if pyo3::ffi::PyUnicode_CheckExact(object.as_ptr()) == 0 {
-            return Err(typeerror!("expected str, got {:?}", object.py(), object.as_ptr()));
+fn check_postgres_only_oper(bin_oper: &sea_query::BinOper) {
+    if let sea_query::BinOper::Custom(op) = bin_oper {
+        if *op == SIMILAR_TO || *op == NOT_SIMILAR_TO || *op == OVERLAPS {
+            report_build_error(format!("{op} is only supported by PostgreSQL"));
         }
+    }
+}
 
-        let mut size: pyo3::ffi::Py_ssize_t = 0;
-        let c_str = pyo3::ffi::PyUnicode_AsUTF8AndSize(object.as_ptr(), &mut size);
+/// Reports a build error on a strict bit shift with a literal operand which
+/// isn't an integer.
+///
+/// PostgreSQL only shifts integers (and bit strings), while MySQL and SQLite
+/// convert the operands themselves; only literals are checked, since the types
+/// of columns aren't known.
+#[inline]
+fn check_shift_operands(simple_expr: &sea_query::SimpleExpr) {
+    use sea_query::Value;
 
-        if c_str.is_null() || size < 0 {
-            return Err(pyo3::PyErr::fetch(object.py()));
-        } else {
-            std::ffi::CStr::from_ptr(c_str).to_string_lossy()
+    if let sea_query::SimpleExpr::Binary(left, sea_query::BinOper::Custom(op), right) = simple_expr {
+        if *op != STRICT_LSHIFT && *op != STRICT_RSHIFT {
+            return;
+        }
+
+        for operand in [left, right] {
+            if let sea_query::SimpleExpr::Value(
+                Value::String(Some(_))
+                | Value::Char(Some(_))
+                | Value::Bool(Some(_))
+                | Value::Float(Some(_))
+                | Value::Double(Some(_))
+                | Value::Decimal(Some(_))
+                | Value::Bytes(Some(_)),
+            ) = &**operand
+            {
+                report_build_error("bit shift operands must be integers in PostgreSQL".to_owned());
+            }
+        }
+    }
+}
+
+/// Custom binary operator of `Expr.case_when`.
+///
+/// The left side is the CASE operand, and the right side is a tuple of
+/// `WHEN`/`THEN` pairs, followed by the `ELSE` result if there's any. Rendered
+/// by [`prepare_extended_expr`].
+pub(crate) const SIMPLE_CASE: &str = "CASE";
+
+/// Name of the function call which `Expr.distinct` wraps its expression in.
+///
+/// Rendered as `DISTINCT expr` by [`prepare_extended_expr`], only as the first
+/// argument of an aggregate function; anywhere else it reports a build error.
+///
+/// Like the other function names below, it starts with `@` so that it can't be
+/// the name of a function called with `Expr.func_call`, which only takes
+/// identifiers.
+pub(crate) const DISTINCT: &str = "@DISTINCT";
+
+/// Name of the function call which `Expr.any_array` and `Expr.all_array` put
+/// their values in.
+///
+/// Rendered as `ARRAY[value, ...]` by [`prepare_extended_expr`] on PostgreSQL;
+/// MySQL and SQLite have no array constructor and report a build error instead.
+pub(crate) const ARRAY: &str = "@ARRAY";
+
+/// Names of the function calls of `Expr.json_build_object` and `Expr.json_agg`.
+///
+/// Renamed by [`prepare_extended_expr`] to the function of each backend:
+/// `json_build_object` and `json_agg` on PostgreSQL, `JSON_OBJECT` and
+/// `JSON_ARRAYAGG` on MySQL, `json_object` and `json_group_array` on SQLite.
+pub(crate) const JSON_BUILD_OBJECT: &str = "@JSON_BUILD_OBJECT";
+pub(crate) const JSON_AGG: &str = "@JSON_AGG";
+
+/// Name of the function call which `Column.uuid_default` sets as the default.
+///
+/// Rendered by [`prepare_extended_expr`] as `gen_random_uuid()` on PostgreSQL
+/// and as `UUID_TO_BIN(UUID())` on MySQL, where `UuidType` is `binary(16)`;
+/// SQLite has no UUID generation function and reports a build error instead.
+pub(crate) const UUID_GENERATE: &str = "@UUID_GENERATE";
+
+/// Custom binary operator which prefixes the first selected expression with
+/// `DISTINCT ON`.
+///
+/// `Select.distinct` builds `Binary(Tuple([on, ...]), Custom("DISTINCT ON"),
+/// first)` when one of its targets is an expression, because sea-query only
+/// takes column references there. Rendered as `DISTINCT ON (on, ...) first` on
+/// PostgreSQL; other backends drop `DISTINCT ON`, as sea-query does.
+pub(crate) const DISTINCT_ON: &str = "DISTINCT ON";
+
+/// Name of the function call which `Select.from_lateral` wraps its subquery in.
+///
+/// sea-query has no lateral FROM source, so the subquery goes into the FROM
+/// list as `TableRef::FunctionCall(LATERAL(subquery), alias)`; rendered as
+/// `LATERAL (subquery) AS alias` by [`prepare_table_ref`].
+pub(crate) const LATERAL: &str = "@LATERAL";
+
+/// Custom binary operator of `Expr.cast_as` when the target is a column type.
+///
+/// The right side is a tuple of the type rendered for PostgreSQL, MySQL and
+/// SQLite, in this order, where an empty string marks a type the backend
+/// doesn't have. Rendered as `CAST(expr AS type)` by [`prepare_extended_expr`].
+pub(crate) const CAST_AS_TYPE: &str = "CAST AS";
+
+/// Custom binary operator of `Expr.pg_cast`.
+///
+/// The right side is the target type name, as a `SimpleExpr::Custom`. Rendered
+/// as `expr::type` by [`prepare_extended_expr`] on PostgreSQL; other backends
+/// have no such syntax and report a build error.
+pub(crate) const PG_CAST: &str = "::";
+
+/// Custom binary operator of `Expr.to_bool`.
+///
+/// The right side is the zero which the expression is compared to. Rendered as
+/// `CAST(expr AS boolean)` by [`prepare_extended_expr`] on PostgreSQL, which
+/// has a real boolean type, and as `expr <> 0` on MySQL and SQLite, whose
+/// booleans are integers.
+pub(crate) const TO_BOOL: &str = "TO BOOL";
+
+/// Whether `inner` is an `Expr.to_bool` rendered as `expr <> 0`, which binds
+/// like a comparison.
+fn is_to_bool_comparison(inner: &sea_query::SimpleExpr) -> bool {
+    matches!(
+        inner,
+        sea_query::SimpleExpr::Binary(_, sea_query::BinOper::Custom(TO_BOOL), _)
+    )
+}
+
+/// Whether `oper` binds looser than comparisons, as `NOT`, `AND` and `OR` do.
+fn is_logical_oper(oper: &sea_query::Oper) -> bool {
+    matches!(
+        oper,
+        sea_query::Oper::UnOper(sea_query::UnOper::Not)
+            | sea_query::Oper::BinOper(sea_query::BinOper::And | sea_query::BinOper::Or)
+    )
+}
+
+/// Whether sea-query's table builder of `backend` can render `column_type`,
+/// instead of panicking.
+fn is_type_available(column_type: &sea_query::ColumnType, backend: BackendKind) -> bool {
+    use sea_query::ColumnType as T;
+
+    match (backend, column_type) {
+        (BackendKind::Postgres, T::Array(inner)) => is_type_available(inner, backend),
+        (BackendKind::Postgres, T::Year) => false,
+        (BackendKind::Mysql, T::Interval(..)) => false,
+        (BackendKind::Mysql, T::Array(_) | T::Vector(_) | T::Cidr | T::Inet | T::MacAddr | T::LTree) => false,
+        (BackendKind::Sqlite, T::Decimal(Some((precision, _)))) => *precision <= 16,
+        (
+            BackendKind::Sqlite,
+            T::Interval(..)
+            | T::Array(_)
+            | T::Vector(_)
+            | T::Cidr
+            | T::Inet
+            | T::MacAddr
+            | T::Year
+            | T::Bit(_)
+            | T::VarBit(_)
+            | T::LTree,
+        ) => false,
+        _ => true,
+    }
+}
+
+/// Renders `column_type` for PostgreSQL, MySQL and SQLite, as [`CAST_AS_TYPE`]
+/// expects.
+///
+/// PostgreSQL takes the type as in table definitions. MySQL's CAST only accepts
+/// a few target types, and SQLite converts by type affinity, so the type is
+/// mapped to the closest of those there.
+pub(crate) fn cast_type_names(column_type: &sea_query::ColumnType) -> [String; 3] {
+    use sea_query::TableBuilder;
+
+    let mut names: [String; 3] = Default::default();
+
+    if is_type_available(column_type, BackendKind::Postgres) {
+        sea_query::PostgresQueryBuilder.prepare_column_type(column_type, &mut names[0]);
+    }
+    if let Some(name) = mysql_cast_type_name(column_type) {
+        names[1] = name;
+    }
+    if let Some(name) = sqlite_cast_type_name(column_type) {
+        names[2] = name;
+    }
+
+    names
+}
+
+/// Returns the MySQL CAST target of `column_type`, if there's one which keeps
+/// the value.
+fn mysql_cast_type_name(column_type: &sea_query::ColumnType) -> Option<String> {
+    use sea_query::ColumnType as T;
+
+    Some(match column_type {
+        T::Char(Some(length)) | T::String(sea_query::StringLen::N(length)) => format!("CHAR({length})"),
+        T::Char(None) | T::String(_) | T::Text | T::Enum { .. } => "CHAR".into(),
+        T::TinyInteger | T::SmallInteger | T::Integer | T::BigInteger => "SIGNED".into(),
+        T::TinyUnsigned | T::SmallUnsigned | T::Unsigned | T::BigUnsigned => "UNSIGNED".into(),
+        T::Float => "FLOAT".into(),
+        T::Double => "DOUBLE".into(),
+        T::Decimal(Some((precision, scale))) | T::Money(Some((precision, scale))) => {
+            format!("DECIMAL({precision}, {scale})")
         }
+        T::Decimal(None) | T::Money(None) => "DECIMAL".into(),
+        T::DateTime | T::Timestamp | T::TimestampWithTimeZone => "DATETIME".into(),
+        T::Time => "TIME".into(),
+        T::Date => "DATE".into(),
+        T::Year => "YEAR".into(),
+        T::Binary(length) | T::VarBinary(sea_query::StringLen::N(length)) => format!("BINARY({length})"),
+        T::VarBinary(_) | T::Blob => "BINARY".into(),
+        T::Json | T::JsonBinary => "JSON".into(),
+        T::Custom(iden) => iden.to_string(),
+        _ => return None,
+    })
+}
+
+/// Returns the SQLite type name of the affinity `column_type` converts to, if
+/// SQLite has the type.
+fn sqlite_cast_type_name(column_type: &sea_query::ColumnType) -> Option<String> {
+    use sea_query::ColumnType as T;
+
+    Some(match column_type {
+        T::TinyInteger
+        | T::SmallInteger
+        | T::Integer
+        | T::BigInteger
+        | T::TinyUnsigned
+        | T::SmallUnsigned
+        | T::Unsigned
+        | T::BigUnsigned
+        | T::Boolean => "integer".into(),
+        T::Float | T::Double => "real".into(),
+        T::Decimal(_) | T::Money(_) => "numeric".into(),
+        T::Char(_)
+        | T::String(_)
+        | T::Text
+        | T::Enum { .. }
+        | T::Json
+        | T::JsonBinary
+        | T::Uuid
+        | T::DateTime
+        | T::Timestamp
+        | T::TimestampWithTimeZone
+        | T::Time
+        | T::Date => "text".into(),
+        T::Binary(_) | T::VarBinary(_) | T::Blob => "blob".into(),
+        T::Custom(iden) => iden.to_string(),
+        _ => return None,
+    })
+}
+
+#[inline]
+fn is_distinct_call(call: &sea_query::FunctionCall) -> bool {
+    call.get_args().len() == 1
+        && matches!(call.get_func(), sea_query::Function::Custom(name) if name.to_string() == DISTINCT)
+}
+
+/// Whether `call` is an aggregate function call, which may take a
+/// [`DISTINCT`] argument.
+///
+/// Custom functions may be user-defined aggregates, so they all count, except
+/// for the internal function calls other than [`JSON_AGG`].
+fn is_aggregate_call(call: &sea_query::FunctionCall) -> bool {
+    use sea_query::Function as F;
+
+    match call.get_func() {
+        F::Count | F::Sum | F::Avg | F::Max | F::Min | F::BitAnd | F::BitOr => true,
+        F::Custom(name) => {
+            let name = name.to_string();
+            name == JSON_AGG || !name.starts_with('@')
+        }
+        _ => false,
+    }
+}
+
+/// Writes the arguments of `call` in parentheses, with the [`DISTINCT`]
+/// argument of an aggregate as `DISTINCT expr`.
+fn prepare_call_arguments<B: sea_query::QueryBuilder>(
+    builder: &B,
+    call: &sea_query::FunctionCall,
+    sql: &mut dyn sea_query::SqlWriter,
+) {
+    write!(sql, "(").unwrap();
+    for (index, arg) in call.get_args().iter().enumerate() {
+        if index != 0 {
+            write!(sql, ", ").unwrap();
+        }
+        if call.get_mods()[index].distinct {
+            write!(sql, "DISTINCT ").unwrap();
+        }
+
+        match arg {
+            sea_query::SimpleExpr::FunctionCall(x) if is_distinct_call(x) => {
+                if index != 0 || !is_aggregate_call(call) {
+                    report_build_error(
+                        "DISTINCT is only allowed as the first argument of an aggregate function".to_owned(),
+                    );
+                }
+
+                write!(sql, "DISTINCT ").unwrap();
+                builder.prepare_simple_expr(&x.get_args()[0], sql);
+            }
+            _ => builder.prepare_simple_expr(arg, sql),
+        }
+    }
+    write!(sql, ")").unwrap();
+}
+
+#[inline]
+fn is_uuid_generate_call(call: &sea_query::FunctionCall) -> bool {
+    matches!(call.get_func(), sea_query::Function::Custom(name) if name.to_string() == UUID_GENERATE)
+}
+
+#[inline]
+fn is_array_call(call: &sea_query::FunctionCall) -> bool {
+    matches!(call.get_func(), sea_query::Function::Custom(name) if name.to_string() == ARRAY)
+}
+
+/// Returns the name of `call` for `backend`, if it's a call of
+/// [`JSON_BUILD_OBJECT`] or [`JSON_AGG`].
+fn json_function_name(call: &sea_query::FunctionCall, backend: BackendKind) -> Option<&'static str> {
+    let sea_query::Function::Custom(name) = call.get_func() else {
+        return None;
     };
+    let name = name.to_string();
 
-    if val == "sqlite" {
-        Ok(Box::new(sea_query::SqliteQueryBuilder))
-    } else if val == "mysql" {
-        Ok(Box::new(sea_query::MysqlQueryBuilder))
-    } else if val == "postgresql" || val == "postgres" {
-        Ok(Box::new(sea_query::PostgresQueryBuilder))
+    if name == JSON_BUILD_OBJECT {
+        Some(match backend {
+            BackendKind::Postgres => "json_build_object",
+            BackendKind::Mysql => "JSON_OBJECT",
+            BackendKind::Sqlite => "json_object",
+        })
+    } else if name == JSON_AGG {
+        Some(match backend {
+            BackendKind::Postgres => "json_agg",
+            BackendKind::Mysql => "JSON_ARRAYAGG",
+            BackendKind::Sqlite => "json_group_array",
+        })
     } else {
-        Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-            "invalid backend value, got {val}"
-        )))
+        None
+    }
+}
+
+/// Same as sea-query's `QueryBuilder::prepare_join_on`, but renders nothing for
+/// an empty condition, which is how `Select.join` stores the missing condition
+/// of a CROSS JOIN, and renders `USING (...)` for join columns, which sea-query
+/// leaves unimplemented.
+fn prepare_join_on<B: sea_query::QueryBuilder>(
+    builder: &B,
+    join_on: &sea_query::JoinOn,
+    sql: &mut dyn sea_query::SqlWriter,
+) {
+    match join_on {
+        sea_query::JoinOn::Condition(c) if c.is_empty() => (),
+        sea_query::JoinOn::Condition(c) => builder.prepare_condition(c, "ON", sql),
+        sea_query::JoinOn::Columns(columns) => {
+            write!(sql, " USING (").unwrap();
+            for (index, column) in columns.iter().enumerate() {
+                if index > 0 {
+                    write!(sql, ", ").unwrap();
+                }
+                builder.prepare_simple_expr(column, sql);
+            }
+            write!(sql, ")").unwrap();
+        }
+    }
+}
+
+/// Renders only the expression of an `OrderExpr` with the wrapped builder.
+///
+/// sea-query's default `prepare_order_expr` renders the expression with
+/// `prepare_simple_expr`, and then the order with `prepare_order`, which is
+/// left out here.
+struct SortKeyBuilder<'a, B>(&'a B);
+
+impl<B: sea_query::QueryBuilder> sea_query::QuotedBuilder for SortKeyBuilder<'_, B> {
+    fn quote(&self) -> sea_query::Quote {
+        self.0.quote()
+    }
+}
+
+impl<B: sea_query::QueryBuilder> sea_query::EscapeBuilder for SortKeyBuilder<'_, B> {
+    fn escape_string(&self, string: &str) -> String {
+        self.0.escape_string(string)
+    }
+
+    fn unescape_string(&self, string: &str) -> String {
+        self.0.unescape_string(string)
+    }
+}
+
+impl<B: sea_query::QueryBuilder> sea_query::TableRefBuilder for SortKeyBuilder<'_, B> {}
+
+impl<B: sea_query::QueryBuilder> sea_query::PrecedenceDecider for SortKeyBuilder<'_, B> {
+    fn inner_expr_well_known_greater_precedence(
+        &self,
+        inner: &sea_query::SimpleExpr,
+        outer_oper: &sea_query::Oper,
+    ) -> bool {
+        self.0.inner_expr_well_known_greater_precedence(inner, outer_oper)
+    }
+}
+
+impl<B: sea_query::QueryBuilder> sea_query::OperLeftAssocDecider for SortKeyBuilder<'_, B> {
+    fn well_known_left_associative(&self, op: &sea_query::BinOper) -> bool {
+        self.0.well_known_left_associative(op)
+    }
+}
+
+impl<B: sea_query::QueryBuilder> sea_query::QueryBuilder for SortKeyBuilder<'_, B> {
+    fn placeholder(&self) -> (&str, bool) {
+        self.0.placeholder()
+    }
+
+    fn prepare_simple_expr(&self, simple_expr: &sea_query::SimpleExpr, sql: &mut dyn sea_query::SqlWriter) {
+        self.0.prepare_simple_expr(simple_expr, sql)
+    }
+
+    fn prepare_query_statement(
+        &self,
+        query: &sea_query::SubQueryStatement,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        self.0.prepare_query_statement(query, sql)
+    }
+
+    fn prepare_order(&self, _: &sea_query::OrderExpr, _: &mut dyn sea_query::SqlWriter) {}
+
+    fn prepare_value(&self, value: &sea_query::Value, sql: &mut dyn sea_query::SqlWriter) {
+        self.0.prepare_value(value, sql)
+    }
+}
+
+/// Same as sea-query's `prepare_order_expr` of `backend`, but renders the
+/// expression with `builder`.
+///
+/// The fields of `OrderExpr` are private, so the expression is rendered by
+/// [`SortKeyBuilder`], and the order and NULLS ordering are read from the
+/// rendering of sea-query's PostgreSQL builder. Orders are always `ASC` or
+/// `DESC`, since `Order::Field` isn't exposed.
+fn prepare_order_expr<B: sea_query::QueryBuilder>(
+    builder: &B,
+    order_expr: &sea_query::OrderExpr,
+    sql: &mut dyn sea_query::SqlWriter,
+    backend: BackendKind,
+) {
+    use sea_query::QueryBuilder;
+
+    let mut plain = String::new();
+    sea_query::PostgresQueryBuilder.prepare_order_expr(order_expr, &mut plain);
+
+    let (plain, nulls) = if let Some(x) = plain.strip_suffix(" NULLS FIRST") {
+        (x, Some(sea_query::NullOrdering::First))
+    } else if let Some(x) = plain.strip_suffix(" NULLS LAST") {
+        (x, Some(sea_query::NullOrdering::Last))
+    } else {
+        (plain.as_str(), None)
+    };
+    let order = if plain.ends_with(" DESC") { "DESC" } else { "ASC" };
+
+    let mut key = String::new();
+    SortKeyBuilder(builder).prepare_order_expr(order_expr, &mut key);
+
+    // MySQL has no `NULLS FIRST/LAST`; like sea-query, it's emulated with an
+    // extra `expr IS NULL` sort key placed before the requested one. Compound
+    // keys are parenthesized, since `IS` binds tighter than e.g. `NOT`.
+    if backend == BackendKind::Mysql && nulls.is_some() {
+        let order = match nulls {
+            Some(sea_query::NullOrdering::First) => "DESC",
+            _ => "ASC",
+        };
+        if key.contains(' ') {
+            write!(sql, "({key}) IS NULL {order}, ").unwrap();
+        } else {
+            write!(sql, "{key} IS NULL {order}, ").unwrap();
+        }
+    }
+
+    write!(sql, "{key} {order}").unwrap();
+
+    if backend != BackendKind::Mysql {
+        match nulls {
+            Some(sea_query::NullOrdering::First) => write!(sql, " NULLS FIRST").unwrap(),
+            Some(sea_query::NullOrdering::Last) => write!(sql, " NULLS LAST").unwrap(),
+            None => (),
+        }
+    }
+}
+
+/// Same as sea-query's `prepare_select_limit_offset`, but puts `no_limit`
+/// before an `OFFSET` without `LIMIT`, which MySQL and SQLite don't accept.
+///
+/// The fields of `SelectStatement` are private, so `plain`, the wrapped
+/// sea-query builder, tells whether the statement has a limit.
+fn prepare_select_limit_offset<B: sea_query::QueryBuilder, P: sea_query::QueryBuilder>(
+    builder: &B,
+    plain: &P,
+    select: &sea_query::SelectStatement,
+    sql: &mut dyn sea_query::SqlWriter,
+    no_limit: u64,
+) {
+    let mut probe = String::new();
+    plain.prepare_select_limit_offset(select, &mut probe);
+
+    if probe.starts_with(" OFFSET ") {
+        write!(sql, " LIMIT ").unwrap();
+        builder.prepare_value(&no_limit.into(), sql);
+    }
+    plain.prepare_select_limit_offset(select, sql);
+}
+
+/// Same as sea-query's `QueryBuilder::prepare_table_ref`, plus the lateral FROM
+/// source.
+///
+/// The default method can't be called from an override, so it's repeated here
+/// to keep subqueries rendered by `builder` rather than by the wrapped
+/// sea-query builder.
+fn prepare_table_ref<B: sea_query::QueryBuilder>(
+    builder: &B,
+    table_ref: &sea_query::TableRef,
+    sql: &mut dyn sea_query::SqlWriter,
+) {
+    match table_ref {
+        sea_query::TableRef::SubQuery(query, alias) => {
+            write!(sql, "(").unwrap();
+            builder.prepare_select_statement(query, sql);
+            write!(sql, ") AS ").unwrap();
+            alias.prepare(sql.as_writer(), builder.quote());
+        }
+        sea_query::TableRef::ValuesList(values, alias) => {
+            write!(sql, "(").unwrap();
+            builder.prepare_values_list(values, sql);
+            write!(sql, ") AS ").unwrap();
+            alias.prepare(sql.as_writer(), builder.quote());
+        }
+        sea_query::TableRef::FunctionCall(call, alias) => {
+            let is_lateral =
+                matches!(call.get_func(), sea_query::Function::Custom(name) if name.to_string() == LATERAL);
+
+            let lateral = match call.get_args() {
+                [sea_query::SimpleExpr::SubQuery(None, query)] if is_lateral => match &**query {
+                    sea_query::SubQueryStatement::SelectStatement(query) => Some(query),
+                    _ => None,
+                },
+                _ => None,
+            };
+
+            if let Some(query) = lateral {
+                write!(sql, "LATERAL (").unwrap();
+                builder.prepare_select_statement(query, sql);
+                write!(sql, ")").unwrap();
+            } else {
+                builder.prepare_function_name(call.get_func(), sql);
+                builder.prepare_function_arguments(call, sql);
+            }
+
+            write!(sql, " AS ").unwrap();
+            alias.prepare(sql.as_writer(), builder.quote());
+        }
+        _ => builder.prepare_table_ref_iden(table_ref, sql),
+    }
+}
+
+/// Renders the expressions which sea-query can't build on its own.
+///
+/// Returns `false` if `simple_expr` is not one of them.
+fn prepare_extended_expr<B: sea_query::QueryBuilder>(
+    builder: &B,
+    simple_expr: &sea_query::SimpleExpr,
+    sql: &mut dyn sea_query::SqlWriter,
+    backend: BackendKind,
+) -> bool {
+    match simple_expr {
+        sea_query::SimpleExpr::Binary(on, sea_query::BinOper::Custom(op), first) if *op == DISTINCT_ON => {
+            let sea_query::SimpleExpr::Tuple(on) = &**on else {
+                return false;
+            };
+
+            if backend == BackendKind::Postgres {
+                write!(sql, "DISTINCT ON (").unwrap();
+                for (index, expr) in on.iter().enumerate() {
+                    if index > 0 {
+                        write!(sql, ", ").unwrap();
+                    }
+                    builder.prepare_simple_expr(expr, sql);
+                }
+                write!(sql, ") ").unwrap();
+            }
+
+            builder.prepare_simple_expr(first, sql);
+            true
+        }
+        sea_query::SimpleExpr::Binary(left, op @ sea_query::BinOper::Custom(name), right)
+            if *name == SIMILAR_TO || *name == NOT_SIMILAR_TO =>
+        {
+            // Same precedence as LIKE, and the pattern's `ESCAPE` mustn't be parenthesized
+            let like = sea_query::Oper::BinOper(sea_query::BinOper::Like);
+            let left_paren = !builder.inner_expr_well_known_greater_precedence(left, &like);
+
+            if left_paren {
+                write!(sql, "(").unwrap();
+            }
+            builder.prepare_simple_expr(left, sql);
+            if left_paren {
+                write!(sql, ")").unwrap();
+            }
+
+            write!(sql, " ").unwrap();
+            builder.prepare_bin_oper(op, sql);
+            write!(sql, " ").unwrap();
+            builder.prepare_simple_expr(right, sql);
+            true
+        }
+        sea_query::SimpleExpr::Binary(left, sea_query::BinOper::Custom(op), right) if *op == FLOOR_DIV => {
+            // MySQL's `/` never truncates, but PostgreSQL and SQLite divide integers
+            // toward zero, so the dividend is cast first
+            write!(sql, "FLOOR(").unwrap();
+            match backend {
+                BackendKind::Mysql => {
+                    builder.binary_expr(left, &sea_query::BinOper::Div, right, sql);
+                }
+                BackendKind::Postgres | BackendKind::Sqlite => {
+                    write!(sql, "CAST(").unwrap();
+                    builder.prepare_simple_expr(left, sql);
+                    if backend == BackendKind::Postgres {
+                        write!(sql, " AS numeric) / ").unwrap();
+                    } else {
+                        write!(sql, " AS real) / ").unwrap();
+                    }
+
+                    let div = sea_query::Oper::BinOper(sea_query::BinOper::Div);
+                    let right_paren = !builder.inner_expr_well_known_greater_precedence(right, &div);
+                    if right_paren {
+                        write!(sql, "(").unwrap();
+                    }
+                    builder.prepare_simple_expr(right, sql);
+                    if right_paren {
+                        write!(sql, ")").unwrap();
+                    }
+                }
+            }
+            write!(sql, ")").unwrap();
+            true
+        }
+        sea_query::SimpleExpr::Binary(operand, sea_query::BinOper::Custom(op), arms)
+            if *op == SIMPLE_CASE =>
+        {
+            let sea_query::SimpleExpr::Tuple(arms) = &**arms else {
+                return false;
+            };
+
+            write!(sql, "CASE ").unwrap();
+            builder.prepare_simple_expr(operand, sql);
+
+            let mut chunks = arms.chunks_exact(2);
+            for arm in chunks.by_ref() {
+                write!(sql, " WHEN ").unwrap();
+                builder.prepare_simple_expr(&arm[0], sql);
+                write!(sql, " THEN ").unwrap();
+                builder.prepare_simple_expr(&arm[1], sql);
+            }
+            if let [r#else] = chunks.remainder() {
+                write!(sql, " ELSE ").unwrap();
+                builder.prepare_simple_expr(r#else, sql);
+            }
+
+            write!(sql, " END").unwrap();
+            true
+        }
+        sea_query::SimpleExpr::Binary(expr, sea_query::BinOper::Custom(op), names) if *op == CAST_AS_TYPE => {
+            let sea_query::SimpleExpr::Tuple(names) = &**names else {
+                return false;
+            };
+            let index = match backend {
+                BackendKind::Postgres => 0,
+                BackendKind::Mysql => 1,
+                BackendKind::Sqlite => 2,
+            };
+            let Some(sea_query::SimpleExpr::Custom(name)) = names.get(index) else {
+                return false;
+            };
+
+            if name.is_empty() {
+                report_build_error(format!("cast target type is not available in {backend}"));
+            }
+
+            write!(sql, "CAST(").unwrap();
+            builder.prepare_simple_expr(expr, sql);
+            write!(sql, " AS {name})").unwrap();
+            true
+        }
+        sea_query::SimpleExpr::Binary(expr, sea_query::BinOper::Custom(op), name) if *op == PG_CAST => {
+            let sea_query::SimpleExpr::Custom(name) = &**name else {
+                return false;
+            };
+
+            if backend != BackendKind::Postgres {
+                report_build_error(format!("{op} cast is only supported by PostgreSQL"));
+            }
+
+            // `::` binds tighter than any other operator
+            let paren = !matches!(
+                &**expr,
+                sea_query::SimpleExpr::Column(_)
+                    | sea_query::SimpleExpr::Value(_)
+                    | sea_query::SimpleExpr::FunctionCall(_)
+                    | sea_query::SimpleExpr::SubQuery(..)
+                    | sea_query::SimpleExpr::Tuple(_)
+                    | sea_query::SimpleExpr::Constant(_)
+                    | sea_query::SimpleExpr::Binary(_, sea_query::BinOper::Custom(PG_CAST), _)
+            );
+
+            if paren {
+                write!(sql, "(").unwrap();
+            }
+            builder.prepare_simple_expr(expr, sql);
+            if paren {
+                write!(sql, ")").unwrap();
+            }
+            write!(sql, "::{name}").unwrap();
+            true
+        }
+        sea_query::SimpleExpr::Binary(expr, sea_query::BinOper::Custom(op), zero) if *op == TO_BOOL => {
+            if backend == BackendKind::Postgres {
+                write!(sql, "CAST(").unwrap();
+                builder.prepare_simple_expr(expr, sql);
+                write!(sql, " AS boolean)").unwrap();
+            } else {
+                builder.binary_expr(expr, &sea_query::BinOper::NotEqual, zero, sql);
+            }
+            true
+        }
+        sea_query::SimpleExpr::FunctionCall(call) if is_array_call(call) => {
+            if backend != BackendKind::Postgres {
+                report_build_error("ARRAY is only supported by PostgreSQL".to_owned());
+            }
+
+            write!(sql, "ARRAY[").unwrap();
+            for (index, expr) in call.get_args().iter().enumerate() {
+                if index > 0 {
+                    write!(sql, ", ").unwrap();
+                }
+                builder.prepare_simple_expr(expr, sql);
+            }
+            write!(sql, "]").unwrap();
+            true
+        }
+        sea_query::SimpleExpr::FunctionCall(call) if is_uuid_generate_call(call) => {
+            match backend {
+                BackendKind::Postgres => write!(sql, "gen_random_uuid()").unwrap(),
+                BackendKind::Mysql => write!(sql, "UUID_TO_BIN(UUID())").unwrap(),
+                BackendKind::Sqlite => {
+                    report_build_error("SQLite has no UUID generation function".to_owned());
+                }
+            }
+            true
+        }
+        sea_query::SimpleExpr::FunctionCall(call) if json_function_name(call, backend).is_some() => {
+            write!(sql, "{}", json_function_name(call, backend).unwrap()).unwrap();
+            prepare_call_arguments(builder, call, sql);
+            true
+        }
+        sea_query::SimpleExpr::FunctionCall(call) if is_distinct_call(call) => {
+            report_build_error(
+                "DISTINCT is only allowed as the first argument of an aggregate function".to_owned(),
+            );
+            true
+        }
+        sea_query::SimpleExpr::FunctionCall(call)
+            if call
+                .get_args()
+                .iter()
+                .any(|x| matches!(x, sea_query::SimpleExpr::FunctionCall(x) if is_distinct_call(x))) =>
+        {
+            builder.prepare_function_name(call.get_func(), sql);
+            prepare_call_arguments(builder, call, sql);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Same as the default `prepare_query_statement`, but keeps rendering with
+/// `builder` instead of the sea-query builder.
+fn prepare_query_statement<B: sea_query::QueryBuilder>(
+    builder: &B,
+    query: &sea_query::SubQueryStatement,
+    sql: &mut dyn sea_query::SqlWriter,
+) {
+    match query {
+        sea_query::SubQueryStatement::SelectStatement(x) => builder.prepare_select_statement(x, sql),
+        sea_query::SubQueryStatement::InsertStatement(x) => builder.prepare_insert_statement(x, sql),
+        sea_query::SubQueryStatement::UpdateStatement(x) => builder.prepare_update_statement(x, sql),
+        sea_query::SubQueryStatement::DeleteStatement(x) => builder.prepare_delete_statement(x, sql),
+        sea_query::SubQueryStatement::WithStatement(x) => builder.prepare_with_query(x, sql),
+    }
+}
+
+/// A [`sea_query::PostgresQueryBuilder`] which knows how to render our extended
+/// expressions.
+///
+/// Every method overridden by [`sea_query::PostgresQueryBuilder`] must be
+/// forwarded (or copied, when it renders expressions itself) to keep the
+/// PostgreSQL syntax intact.
+pub(crate) struct PostgresBuilder;
+
+impl sea_query::QuotedBuilder for PostgresBuilder {
+    fn quote(&self) -> sea_query::Quote {
+        sea_query::PostgresQueryBuilder.quote()
+    }
+}
+
+impl sea_query::EscapeBuilder for PostgresBuilder {}
+
+impl sea_query::TableRefBuilder for PostgresBuilder {}
+
+impl sea_query::PrecedenceDecider for PostgresBuilder {
+    fn inner_expr_well_known_greater_precedence(
+        &self,
+        inner: &sea_query::SimpleExpr,
+        outer_oper: &sea_query::Oper,
+    ) -> bool {
+        // A `::` cast and `CAST(... AS boolean)` never need parentheses as an operand
+        matches!(
+            inner,
+            sea_query::SimpleExpr::Binary(_, sea_query::BinOper::Custom(PG_CAST | TO_BOOL), _)
+        ) || sea_query::PostgresQueryBuilder.inner_expr_well_known_greater_precedence(inner, outer_oper)
+    }
+}
+
+impl sea_query::OperLeftAssocDecider for PostgresBuilder {
+    fn well_known_left_associative(&self, op: &sea_query::BinOper) -> bool {
+        sea_query::PostgresQueryBuilder.well_known_left_associative(op)
+    }
+}
+
+impl sea_query::QueryBuilder for PostgresBuilder {
+    fn placeholder(&self) -> (&str, bool) {
+        sea_query::PostgresQueryBuilder.placeholder()
+    }
+
+    fn prepare_simple_expr(&self, simple_expr: &sea_query::SimpleExpr, sql: &mut dyn sea_query::SqlWriter) {
+        check_shift_operands(simple_expr);
+
+        if prepare_extended_expr(self, simple_expr, sql, BackendKind::Postgres) {
+            return;
+        }
+
+        match simple_expr {
+            sea_query::SimpleExpr::AsEnum(type_name, expr) => {
+                write!(sql, "CAST(").unwrap();
+                self.prepare_simple_expr_common(expr, sql);
+
+                let q = sea_query::QuotedBuilder::quote(self);
+                let type_name = type_name.to_string();
+                let (ty, sfx) = match type_name.strip_suffix("[]") {
+                    Some(ty) => (ty, "[]"),
+                    None => (type_name.as_str(), ""),
+                };
+                write!(sql, " AS {}{}{}{})", q.left(), ty, q.right(), sfx).unwrap();
+            }
+            _ => self.prepare_simple_expr_common(simple_expr, sql),
+        }
+    }
+
+    fn prepare_select_distinct(
+        &self,
+        select_distinct: &sea_query::SelectDistinct,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        sea_query::PostgresQueryBuilder.prepare_select_distinct(select_distinct, sql)
+    }
+
+    fn prepare_bin_oper(&self, bin_oper: &sea_query::BinOper, sql: &mut dyn sea_query::SqlWriter) {
+        sea_query::PostgresQueryBuilder.prepare_bin_oper(bin_oper, sql)
+    }
+
+    fn prepare_query_statement(
+        &self,
+        query: &sea_query::SubQueryStatement,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        prepare_query_statement(self, query, sql)
+    }
+
+    fn prepare_function_name(&self, function: &sea_query::Function, sql: &mut dyn sea_query::SqlWriter) {
+        sea_query::PostgresQueryBuilder.prepare_function_name(function, sql)
+    }
+
+    fn prepare_table_sample(&self, select: &sea_query::SelectStatement, sql: &mut dyn sea_query::SqlWriter) {
+        sea_query::PostgresQueryBuilder.prepare_table_sample(select, sql)
+    }
+
+    fn prepare_table_ref(&self, table_ref: &sea_query::TableRef, sql: &mut dyn sea_query::SqlWriter) {
+        prepare_table_ref(self, table_ref, sql)
+    }
+
+    fn prepare_join_on(&self, join_on: &sea_query::JoinOn, sql: &mut dyn sea_query::SqlWriter) {
+        prepare_join_on(self, join_on, sql)
+    }
+
+    fn prepare_order_expr(&self, order_expr: &sea_query::OrderExpr, sql: &mut dyn sea_query::SqlWriter) {
+        prepare_order_expr(self, order_expr, sql, BackendKind::Postgres)
+    }
+
+    fn prepare_value(&self, value: &sea_query::Value, sql: &mut dyn sea_query::SqlWriter) {
+        sql.push_param(value.clone(), self as _);
+    }
+
+    fn write_string_quoted(&self, string: &str, buffer: &mut String) {
+        sea_query::PostgresQueryBuilder.write_string_quoted(string, buffer)
+    }
+
+    fn write_bytes(&self, bytes: &[u8], buffer: &mut String) {
+        sea_query::PostgresQueryBuilder.write_bytes(bytes, buffer)
+    }
+
+    fn if_null_function(&self) -> &str {
+        sea_query::PostgresQueryBuilder.if_null_function()
+    }
+
+    fn insert_default_values(&self, num_rows: u32, sql: &mut dyn sea_query::SqlWriter) {
+        // `VALUES (DEFAULT)` fills only the first column explicitly; `DEFAULT VALUES`
+        // is the standard form of a single row
+        if num_rows == 1 {
+            write!(sql, "DEFAULT VALUES").unwrap();
+        } else {
+            sea_query::PostgresQueryBuilder.insert_default_values(num_rows, sql)
+        }
+    }
+}
+
+/// A [`sea_query::SqliteQueryBuilder`] which knows how to render our extended
+/// expressions.
+///
+/// Every method overridden by [`sea_query::SqliteQueryBuilder`] must be
+/// forwarded (or copied, when it renders expressions itself) to keep the SQLite
+/// syntax intact.
+pub(crate) struct SqliteBuilder;
+
+impl sea_query::QuotedBuilder for SqliteBuilder {
+    fn quote(&self) -> sea_query::Quote {
+        sea_query::SqliteQueryBuilder.quote()
+    }
+}
+
+impl sea_query::EscapeBuilder for SqliteBuilder {
+    fn escape_string(&self, string: &str) -> String {
+        sea_query::SqliteQueryBuilder.escape_string(string)
+    }
+
+    fn unescape_string(&self, string: &str) -> String {
+        sea_query::SqliteQueryBuilder.unescape_string(string)
+    }
+}
+
+impl sea_query::TableRefBuilder for SqliteBuilder {}
+
+impl sea_query::PrecedenceDecider for SqliteBuilder {
+    fn inner_expr_well_known_greater_precedence(
+        &self,
+        inner: &sea_query::SimpleExpr,
+        outer_oper: &sea_query::Oper,
+    ) -> bool {
+        if is_to_bool_comparison(inner) {
+            return is_logical_oper(outer_oper);
+        }
+
+        sea_query::SqliteQueryBuilder.inner_expr_well_known_greater_precedence(inner, outer_oper)
+    }
+}
+
+impl sea_query::OperLeftAssocDecider for SqliteBuilder {
+    fn well_known_left_associative(&self, op: &sea_query::BinOper) -> bool {
+        sea_query::SqliteQueryBuilder.well_known_left_associative(op)
+    }
+}
+
+impl sea_query::QueryBuilder for SqliteBuilder {
+    fn prepare_simple_expr(&self, simple_expr: &sea_query::SimpleExpr, sql: &mut dyn sea_query::SqlWriter) {
+        if !prepare_extended_expr(self, simple_expr, sql, BackendKind::Sqlite) {
+            self.prepare_simple_expr_common(simple_expr, sql);
+        }
+    }
+
+    fn prepare_select_lock(&self, select_lock: &sea_query::LockClause, sql: &mut dyn sea_query::SqlWriter) {
+        sea_query::SqliteQueryBuilder.prepare_select_lock(select_lock, sql)
+    }
+
+    fn prepare_sub_query_oper(&self, oper: &sea_query::SubQueryOper, sql: &mut dyn sea_query::SqlWriter) {
+        sea_query::SqliteQueryBuilder.prepare_sub_query_oper(oper, sql)
+    }
+
+    fn prepare_bin_oper(&self, bin_oper: &sea_query::BinOper, sql: &mut dyn sea_query::SqlWriter) {
+        check_postgres_only_oper(bin_oper);
+        sea_query::SqliteQueryBuilder.prepare_bin_oper(bin_oper, sql)
+    }
+
+    fn prepare_union_statement(
+        &self,
+        union_type: sea_query::UnionType,
+        select_statement: &sea_query::SelectStatement,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        match union_type {
+            sea_query::UnionType::Intersect => write!(sql, " INTERSECT ").unwrap(),
+            sea_query::UnionType::Distinct => write!(sql, " UNION ").unwrap(),
+            sea_query::UnionType::Except => write!(sql, " EXCEPT ").unwrap(),
+            sea_query::UnionType::All => write!(sql, " UNION ALL ").unwrap(),
+        }
+        self.prepare_select_statement(select_statement, sql);
+    }
+
+    fn prepare_query_statement(
+        &self,
+        query: &sea_query::SubQueryStatement,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        prepare_query_statement(self, query, sql)
+    }
+
+    fn prepare_with_clause_recursive_options(
+        &self,
+        with_clause: &sea_query::WithClause,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        sea_query::SqliteQueryBuilder.prepare_with_clause_recursive_options(with_clause, sql)
+    }
+
+    fn prepare_table_ref(&self, table_ref: &sea_query::TableRef, sql: &mut dyn sea_query::SqlWriter) {
+        prepare_table_ref(self, table_ref, sql)
+    }
+
+    fn prepare_join_on(&self, join_on: &sea_query::JoinOn, sql: &mut dyn sea_query::SqlWriter) {
+        prepare_join_on(self, join_on, sql)
+    }
+
+    fn prepare_order_expr(&self, order_expr: &sea_query::OrderExpr, sql: &mut dyn sea_query::SqlWriter) {
+        prepare_order_expr(self, order_expr, sql, BackendKind::Sqlite)
+    }
+
+    fn prepare_select_limit_offset(
+        &self,
+        select: &sea_query::SelectStatement,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        // A negative limit means no limit, but the value is unsigned
+        prepare_select_limit_offset(self, &sea_query::SqliteQueryBuilder, select, sql, i64::MAX as u64)
+    }
+
+    fn prepare_value(&self, value: &sea_query::Value, sql: &mut dyn sea_query::SqlWriter) {
+        sql.push_param(value.clone(), self as _);
+    }
+
+    fn greatest_function(&self) -> &str {
+        sea_query::SqliteQueryBuilder.greatest_function()
+    }
+
+    fn least_function(&self) -> &str {
+        sea_query::SqliteQueryBuilder.least_function()
+    }
+
+    fn char_length_function(&self) -> &str {
+        sea_query::SqliteQueryBuilder.char_length_function()
+    }
+
+    fn insert_default_values(&self, num_rows: u32, sql: &mut dyn sea_query::SqlWriter) {
+        sea_query::SqliteQueryBuilder.insert_default_values(num_rows, sql)
+    }
+}
+
+/// A [`sea_query::MysqlQueryBuilder`] which knows how to render our MySQL
+/// specific expressions.
+///
+/// Expressions are created before the backend is known, so operators which have
+/// no common syntax across backends are stored with their standard form and
+/// rewritten here.
+///
+/// Every method overridden by [`sea_query::MysqlQueryBuilder`] must be
+/// forwarded (or copied, when it renders expressions itself) to keep the MySQL
+/// syntax intact.
+pub(crate) struct MysqlBuilder;
+
+impl sea_query::QuotedBuilder for MysqlBuilder {
+    fn quote(&self) -> sea_query::Quote {
+        sea_query::MysqlQueryBuilder.quote()
+    }
+}
+
+impl sea_query::EscapeBuilder for MysqlBuilder {}
+
+impl sea_query::TableRefBuilder for MysqlBuilder {}
+
+impl sea_query::PrecedenceDecider for MysqlBuilder {
+    fn inner_expr_well_known_greater_precedence(
+        &self,
+        inner: &sea_query::SimpleExpr,
+        outer_oper: &sea_query::Oper,
+    ) -> bool {
+        if is_to_bool_comparison(inner) {
+            return is_logical_oper(outer_oper);
+        }
+
+        sea_query::MysqlQueryBuilder.inner_expr_well_known_greater_precedence(inner, outer_oper)
+    }
+}
+
+impl sea_query::OperLeftAssocDecider for MysqlBuilder {
+    fn well_known_left_associative(&self, op: &sea_query::BinOper) -> bool {
+        sea_query::MysqlQueryBuilder.well_known_left_associative(op)
+    }
+}
+
+impl sea_query::QueryBuilder for MysqlBuilder {
+    fn prepare_simple_expr(&self, simple_expr: &sea_query::SimpleExpr, sql: &mut dyn sea_query::SqlWriter) {
+        if prepare_extended_expr(self, simple_expr, sql, BackendKind::Mysql) {
+            return;
+        }
+
+        match simple_expr {
+            sea_query::SimpleExpr::Binary(left, sea_query::BinOper::Custom(op), right)
+                if *op == IS_DISTINCT_FROM =>
+            {
+                write!(sql, "NOT (").unwrap();
+                self.binary_expr(left, &sea_query::BinOper::Custom("<=>"), right, sql);
+                write!(sql, ")").unwrap();
+            }
+            sea_query::SimpleExpr::Binary(left, sea_query::BinOper::Custom(op), right)
+                if *op == IS_NOT_DISTINCT_FROM =>
+            {
+                self.binary_expr(left, &sea_query::BinOper::Custom("<=>"), right, sql);
+            }
+            _ => self.prepare_simple_expr_common(simple_expr, sql),
+        }
+    }
+
+    fn values_list_tuple_prefix(&self) -> &str {
+        sea_query::MysqlQueryBuilder.values_list_tuple_prefix()
+    }
+
+    fn prepare_select_distinct(
+        &self,
+        select_distinct: &sea_query::SelectDistinct,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        sea_query::MysqlQueryBuilder.prepare_select_distinct(select_distinct, sql)
+    }
+
+    fn prepare_bin_oper(&self, bin_oper: &sea_query::BinOper, sql: &mut dyn sea_query::SqlWriter) {
+        check_postgres_only_oper(bin_oper);
+        sea_query::MysqlQueryBuilder.prepare_bin_oper(bin_oper, sql)
+    }
+
+    fn prepare_index_hints(&self, select: &sea_query::SelectStatement, sql: &mut dyn sea_query::SqlWriter) {
+        sea_query::MysqlQueryBuilder.prepare_index_hints(select, sql)
+    }
+
+    fn prepare_query_statement(
+        &self,
+        query: &sea_query::SubQueryStatement,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        prepare_query_statement(self, query, sql)
+    }
+
+    fn prepare_with_clause_recursive_options(
+        &self,
+        with_clause: &sea_query::WithClause,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        sea_query::MysqlQueryBuilder.prepare_with_clause_recursive_options(with_clause, sql)
+    }
+
+    fn prepare_with_query_clause_materialization(
+        &self,
+        cte: &sea_query::CommonTableExpression,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        sea_query::MysqlQueryBuilder.prepare_with_query_clause_materialization(cte, sql)
+    }
+
+    fn prepare_update_join(
+        &self,
+        from: &[sea_query::TableRef],
+        condition: &sea_query::ConditionHolder,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        if from.is_empty() {
+            return;
+        }
+
+        write!(sql, " JOIN ").unwrap();
+        self.prepare_table_ref(&from[0], sql);
+        self.prepare_condition(condition, "ON", sql);
+    }
+
+    fn prepare_update_from(&self, from: &[sea_query::TableRef], sql: &mut dyn sea_query::SqlWriter) {
+        sea_query::MysqlQueryBuilder.prepare_update_from(from, sql)
+    }
+
+    fn prepare_update_column(
+        &self,
+        table: &Option<Box<sea_query::TableRef>>,
+        from: &[sea_query::TableRef],
+        column: &sea_query::DynIden,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        sea_query::MysqlQueryBuilder.prepare_update_column(table, from, column, sql)
+    }
+
+    fn prepare_update_condition(
+        &self,
+        from: &[sea_query::TableRef],
+        condition: &sea_query::ConditionHolder,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        if !from.is_empty() {
+            return;
+        }
+        self.prepare_condition(condition, "WHERE", sql);
+    }
+
+    fn prepare_join_type(&self, join_type: &sea_query::JoinType, sql: &mut dyn sea_query::SqlWriter) {
+        sea_query::MysqlQueryBuilder.prepare_join_type(join_type, sql)
+    }
+
+    fn prepare_table_ref(&self, table_ref: &sea_query::TableRef, sql: &mut dyn sea_query::SqlWriter) {
+        prepare_table_ref(self, table_ref, sql)
+    }
+
+    fn prepare_join_on(&self, join_on: &sea_query::JoinOn, sql: &mut dyn sea_query::SqlWriter) {
+        prepare_join_on(self, join_on, sql)
+    }
+
+    fn prepare_order_expr(&self, order_expr: &sea_query::OrderExpr, sql: &mut dyn sea_query::SqlWriter) {
+        prepare_order_expr(self, order_expr, sql, BackendKind::Mysql)
+    }
+
+    fn prepare_select_limit_offset(
+        &self,
+        select: &sea_query::SelectStatement,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        prepare_select_limit_offset(self, &sea_query::MysqlQueryBuilder, select, sql, u64::MAX)
+    }
+
+    fn prepare_value(&self, value: &sea_query::Value, sql: &mut dyn sea_query::SqlWriter) {
+        sql.push_param(value.clone(), self as _);
+    }
+
+    fn prepare_on_conflict_target(
+        &self,
+        targets: &[sea_query::OnConflictTarget],
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        sea_query::MysqlQueryBuilder.prepare_on_conflict_target(targets, sql)
+    }
+
+    fn prepare_on_conflict_action(
+        &self,
+        on_conflict_action: &Option<sea_query::OnConflictAction>,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        match on_conflict_action {
+            Some(sea_query::OnConflictAction::DoNothing(_)) => {
+                sea_query::MysqlQueryBuilder.prepare_on_conflict_action(on_conflict_action, sql)
+            }
+            _ => self.prepare_on_conflict_action_common(on_conflict_action, sql),
+        }
+    }
+
+    fn prepare_on_conflict_keywords(&self, sql: &mut dyn sea_query::SqlWriter) {
+        sea_query::MysqlQueryBuilder.prepare_on_conflict_keywords(sql)
+    }
+
+    fn prepare_on_conflict_do_update_keywords(&self, sql: &mut dyn sea_query::SqlWriter) {
+        sea_query::MysqlQueryBuilder.prepare_on_conflict_do_update_keywords(sql)
+    }
+
+    fn prepare_on_conflict_excluded_table(
+        &self,
+        col: &sea_query::DynIden,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        sea_query::MysqlQueryBuilder.prepare_on_conflict_excluded_table(col, sql)
+    }
+
+    fn prepare_on_conflict_condition(
+        &self,
+        condition: &sea_query::ConditionHolder,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        sea_query::MysqlQueryBuilder.prepare_on_conflict_condition(condition, sql)
+    }
+
+    fn prepare_returning(
+        &self,
+        returning: &Option<sea_query::ReturningClause>,
+        sql: &mut dyn sea_query::SqlWriter,
+    ) {
+        sea_query::MysqlQueryBuilder.prepare_returning(returning, sql)
+    }
+
+    fn random_function(&self) -> &str {
+        sea_query::MysqlQueryBuilder.random_function()
+    }
+
+    fn insert_default_keyword(&self) -> &str {
+        sea_query::MysqlQueryBuilder.insert_default_keyword()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum BackendKind {
+    Sqlite,
+    Mysql,
+    Postgres,
+}
+
+impl std::fmt::Display for BackendKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackendKind::Sqlite => write!(f, "SQLite"),
+            BackendKind::Mysql => write!(f, "MySQL"),
+            BackendKind::Postgres => write!(f, "PostgreSQL"),
+        }
+    }
+}
+
+impl BackendKind {
+    /// The canonical name of the backend, as accepted by `to_sql` and `build`.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            BackendKind::Sqlite => "sqlite",
+            BackendKind::Mysql => "mysql",
+            BackendKind::Postgres => "postgresql",
+        }
+    }
+}
+
+/// A database backend, validated when it's created.
+///
+/// Accepted everywhere a backend name is, so a misspelled name fails where the
+/// backend is defined rather than when a statement is built.
+#[pyo3::pyclass(module = "rapidquery._lib", name = "Backend", frozen, eq, hash)]
+#[derive(PartialEq, Eq, Hash)]
+pub struct PyBackend {
+    pub(crate) kind: BackendKind,
+}
+
+#[pyo3::pymethods]
+impl PyBackend {
+    #[new]
+    fn new(name: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<Self> {
+        Ok(Self {
+            kind: into_backend_kind(name)?,
+        })
+    }
+
+    #[classattr]
+    #[allow(non_snake_case)]
+    fn POSTGRES() -> Self {
+        Self {
+            kind: BackendKind::Postgres,
+        }
+    }
+
+    #[classattr]
+    #[allow(non_snake_case)]
+    fn MYSQL() -> Self {
+        Self {
+            kind: BackendKind::Mysql,
+        }
+    }
+
+    #[classattr]
+    #[allow(non_snake_case)]
+    fn SQLITE() -> Self {
+        Self {
+            kind: BackendKind::Sqlite,
+        }
+    }
+
+    #[getter]
+    fn name(&self) -> &'static str {
+        self.kind.name()
+    }
+
+    fn __str__(&self) -> &'static str {
+        self.kind.name()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<Backend {:?}>", self.kind.name())
     }
 }
 
 #[inline]
 #[optimize(speed)]
-pub(crate) fn into_schema_builder(
-    object: &pyo3::Bound<'_, pyo3::PyAny>,
-) -> pyo3::PyResult<Box<dyn sea_query::SchemaBuilder>> {
+pub(crate) fn into_backend_kind(object: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<BackendKind> {
     let val = unsafe {
+        if pyo3::ffi::Py_TYPE(object.as_ptr()) == crate::typeref::BACKEND_TYPE {
+            let backend = object.cast_unchecked::<PyBackend>();
+            return Ok(backend.get().kind);
+        }
+
         if pyo3::ffi::PyUnicode_CheckExact(object.as_ptr()) == 0 {
-            return Err(typeerror!("expected str, got {:?}", object.py(), object.as_ptr()));
+            return Err(typeerror!(
+                "expected Backend or str, got {:?}",
+                object.py(),
+                object.as_ptr()
+            ));
         }
 
         let mut size: pyo3::ffi::Py_ssize_t = 0;
@@ -70,14 +1509,67 @@ pub(crate) fn into_schema_builder(
     };
 
     if val == "sqlite" {
-        Ok(Box::new(sea_query::SqliteQueryBuilder))
+        Ok(BackendKind::Sqlite)
     } else if val == "mysql" {
-        Ok(Box::new(sea_query::MysqlQueryBuilder))
+        Ok(BackendKind::Mysql)
     } else if val == "postgresql" || val == "postgres" {
-        Ok(Box::new(sea_query::PostgresQueryBuilder))
+        Ok(BackendKind::Postgres)
     } else {
         Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-            "invalid backend value, got {val}"
+            "invalid backend value, got {val:?}; expected 'postgresql', 'mysql' or 'sqlite'"
         )))
     }
 }
+
+#[inline]
+#[optimize(speed)]
+pub(crate) fn into_query_builder(
+    object: &pyo3::Bound<'_, pyo3::PyAny>,
+) -> pyo3::PyResult<Box<dyn sea_query::QueryBuilder>> {
+    match into_backend_kind(object)? {
+        BackendKind::Sqlite => Ok(Box::new(SqliteBuilder)),
+        BackendKind::Mysql => Ok(Box::new(MysqlBuilder)),
+        BackendKind::Postgres => Ok(Box::new(PostgresBuilder)),
+    }
+}
+
+#[inline]
+#[optimize(speed)]
+pub(crate) fn into_schema_builder(
+    object: &pyo3::Bound<'_, pyo3::PyAny>,
+) -> pyo3::PyResult<Box<dyn sea_query::SchemaBuilder>> {
+    match into_backend_kind(object)? {
+        BackendKind::Sqlite => Ok(Box::new(sea_query::SqliteQueryBuilder)),
+        BackendKind::Mysql => Ok(Box::new(sea_query::MysqlQueryBuilder)),
+        BackendKind::Postgres => Ok(Box::new(sea_query::PostgresQueryBuilder)),
+    }
+}
+
+/// Renders `simple_expr` for `backend` with our builders, as a
+/// `SimpleExpr::Custom` to put into a schema statement.
+///
+/// Schema statements are built by sea-query's own builders, which can't render
+/// our extended expressions; so defaults, checks, generated columns and index
+/// predicates are rendered beforehand. Values are inlined, as schema
+/// statements do anyway.
+pub(crate) fn render_schema_expr(
+    simple_expr: &sea_query::SimpleExpr,
+    backend: BackendKind,
+) -> pyo3::PyResult<sea_query::SimpleExpr> {
+    let builder: Box<dyn sea_query::QueryBuilder> = match backend {
+        BackendKind::Sqlite => Box::new(SqliteBuilder),
+        BackendKind::Mysql => Box::new(MysqlBuilder),
+        BackendKind::Postgres => Box::new(PostgresBuilder),
+    };
+
+    let mut sql = String::new();
+    clear_build_error();
+
+    let assert_unwind = std::panic::AssertUnwindSafe(|| builder.prepare_simple_expr(simple_expr, &mut sql));
+
+    std::panic::catch_unwind(assert_unwind)
+        .map_err(|_| pyo3::PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("build failed"))?;
+    take_build_error()?;
+
+    Ok(sea_query::SimpleExpr::Custom(sql))
+}
@@ -1,5 +1,5 @@
 use crate::parameters::OptionalParam;
-use pyo3::types::PyAnyMethods;
+use pyo3::types::{PyAnyMethods, PyTypeMethods};
 use sea_query::IntoIden;
 
 /// Converting column types into sea_query::ColumnType
@@ -8,6 +8,9 @@ pub mod convert;
 /// Column type implementations
 pub mod types;
 
+/// Parsing column types from the type strings of backends
+pub mod parse;
+
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum ColumnOptions {
@@ -19,6 +22,56 @@ enum ColumnOptions {
     StoredGenerated = 1 << 5,
 }
 
+/// Auto increment is only valid on the integer family of column types.
+fn check_auto_increment_type(r#type: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<()> {
+    use sea_query::ColumnType;
+
+    match convert::convert_to_column_type(r#type) {
+        Some(
+            ColumnType::TinyInteger
+            | ColumnType::SmallInteger
+            | ColumnType::Integer
+            | ColumnType::BigInteger
+            | ColumnType::TinyUnsigned
+            | ColumnType::SmallUnsigned
+            | ColumnType::Unsigned
+            | ColumnType::BigUnsigned,
+        ) => Ok(()),
+        _ => Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "auto_increment requires an integer column type, got {}",
+            r#type.repr()?
+        ))),
+    }
+}
+
+/// Converts `value` into the default of the column `name`, checking a literal
+/// against `type`.
+///
+/// Literals are checked strictly, including the range of sized integers, as an
+/// invalid default would otherwise only fail when the table is created.
+fn default_expr(
+    name: &str,
+    value: pyo3::Bound<'_, pyo3::PyAny>,
+    r#type: &pyo3::Bound<'_, pyo3::PyAny>,
+) -> pyo3::PyResult<crate::expression::PyExpr> {
+    let py = value.py();
+
+    crate::expression::PyExpr::try_with_specific_type(value, Some(r#type), true).map_err(|err| {
+        let r#type = match r#type.get_type().name() {
+            Ok(x) => x,
+            Err(err) => return err,
+        };
+
+        pyo3::PyErr::from_type(
+            err.get_type(py),
+            format!(
+                "invalid default for column {name:?} of type {type}: {}",
+                err.value(py)
+            ),
+        )
+    })
+}
+
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum LazyColumnRef {
@@ -83,7 +136,13 @@ impl LazyColumnRef {
                 let ptr = bound.get();
 
                 let x = {
-                    if let Some(schema) = &ptr.schema {
+                    // An aliased table can only be referenced by its alias
+                    if let Some(alias) = &ptr.alias {
+                        sea_query::ColumnRef::TableColumn(
+                            alias.clone(),
+                            sea_query::Alias::new(name).into_iden(),
+                        )
+                    } else if let Some(schema) = &ptr.schema {
                         sea_query::ColumnRef::SchemaTableColumn(
                             schema.clone(),
                             ptr.name.clone(),
@@ -118,12 +177,45 @@ pub struct ColumnInner {
 
     // Always is `Option<Expr>`
     pub generated: Option<pyo3::Py<pyo3::PyAny>>,
+
+    // Always is `Option<Expr>`
+    pub check: Option<pyo3::Py<pyo3::PyAny>>,
     pub extra: Option<String>,
     pub comment: Option<String>,
     pub column_ref: LazyColumnRef,
 }
 
+/// Rejects auto increment on the integer types which `backend` can't
+/// auto increment.
+///
+/// PostgreSQL only has serial types for smallint, integer and bigint, while
+/// MySQL and SQLite auto increment every integer type.
+fn check_auto_increment_backend(
+    column_type: &sea_query::ColumnType,
+    backend: crate::backend::BackendKind,
+) -> pyo3::PyResult<()> {
+    use sea_query::ColumnType;
+
+    if backend == crate::backend::BackendKind::Postgres
+        && !matches!(
+            column_type,
+            ColumnType::SmallInteger | ColumnType::Integer | ColumnType::BigInteger
+        )
+    {
+        return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "auto increment is only supported on smallint, integer and bigint columns by PostgreSQL",
+        ));
+    }
+
+    Ok(())
+}
+
 impl ColumnInner {
+    #[inline]
+    pub fn is_primary_key(&self) -> bool {
+        self.options & (ColumnOptions::PrimaryKey as u8) > 0
+    }
+
     #[inline]
     #[optimize(speed)]
     pub fn as_column_ref(&mut self, py: pyo3::Python) -> sea_query::ColumnRef {
@@ -136,18 +228,26 @@ impl ColumnInner {
         sea_query::SimpleExpr::Column(self.as_column_ref(py))
     }
 
+    /// Builds the column definition for `backend`, which renders the default,
+    /// generated and check expressions.
     #[inline]
     #[optimize(speed)]
-    pub fn as_column_def(&self, py: pyo3::Python<'_>) -> sea_query::ColumnDef {
-        let mut column_def = sea_query::ColumnDef::new_with_type(
-            sea_query::Alias::new(self.name.clone()),
-            #[cfg(debug_assertions)]
-            convert::convert_to_column_type(self.r#type.bind(py)).unwrap(),
-            #[cfg(not(debug_assertions))]
-            unsafe {
-                convert::convert_to_column_type(self.r#type.bind(py)).unwrap()
-            },
-        );
+    pub fn as_column_def(
+        &self,
+        py: pyo3::Python<'_>,
+        backend: crate::backend::BackendKind,
+    ) -> pyo3::PyResult<sea_query::ColumnDef> {
+        #[cfg(debug_assertions)]
+        let column_type = convert::convert_to_column_type(self.r#type.bind(py)).unwrap();
+        #[cfg(not(debug_assertions))]
+        let column_type = unsafe { convert::convert_to_column_type(self.r#type.bind(py)).unwrap() };
+
+        if self.options & (ColumnOptions::AutoIncrement as u8) > 0 {
+            check_auto_increment_backend(&column_type, backend)?;
+        }
+
+        let mut column_def =
+            sea_query::ColumnDef::new_with_type(sea_query::Alias::new(self.name.clone()), column_type);
 
         if self.options & (ColumnOptions::PrimaryKey as u8) > 0 {
             column_def.primary_key();
@@ -168,8 +268,28 @@ impl ColumnInner {
         if let Some(default) = &self.default {
             let default_expr = unsafe { default.cast_bound_unchecked::<crate::expression::PyExpr>(py) };
 
-            let default_expr = default_expr.get();
-            column_def.default(default_expr.inner.clone());
+            let default_expr = &default_expr.get().inner;
+            let mut sql = crate::backend::render_schema_expr(default_expr, backend)?;
+
+            // MySQL and SQLite only accept literals and some keywords as bare defaults;
+            // other expressions have to be parenthesized
+            let bare = match default_expr {
+                sea_query::SimpleExpr::Value(_)
+                | sea_query::SimpleExpr::Constant(_)
+                | sea_query::SimpleExpr::Custom(_) => true,
+                sea_query::SimpleExpr::Keyword(x) => {
+                    backend != crate::backend::BackendKind::Mysql
+                        || matches!(x, sea_query::Keyword::Null | sea_query::Keyword::CurrentTimestamp)
+                }
+                _ => backend == crate::backend::BackendKind::Postgres,
+            };
+            if !bare {
+                if let sea_query::SimpleExpr::Custom(x) = sql {
+                    sql = sea_query::SimpleExpr::Custom(format!("({x})"));
+                }
+            }
+
+            column_def.default(sql);
         }
 
         if let Some(generated) = &self.generated {
@@ -178,11 +298,19 @@ impl ColumnInner {
             let generated_expr = generated_expr.get();
 
             column_def.generated(
-                generated_expr.inner.clone(),
+                crate::backend::render_schema_expr(&generated_expr.inner, backend)?,
                 self.options & (ColumnOptions::StoredGenerated as u8) > 0,
             );
         }
 
+        if let Some(check) = &self.check {
+            let check_expr = unsafe { check.cast_bound_unchecked::<crate::expression::PyExpr>(py) };
+            column_def.check(crate::backend::render_schema_expr(
+                &check_expr.get().inner,
+                backend,
+            )?);
+        }
+
         if let Some(x) = &self.extra {
             column_def.extra(x);
         }
@@ -190,7 +318,7 @@ impl ColumnInner {
             column_def.comment(x);
         }
 
-        column_def
+        Ok(column_def)
     }
 
     pub fn clone_ref(&self, py: pyo3::Python) -> Self {
@@ -200,6 +328,7 @@ impl ColumnInner {
             options: self.options,
             default: self.default.as_ref().map(|x| x.clone_ref(py)),
             generated: self.generated.as_ref().map(|x| x.clone_ref(py)),
+            check: self.check.as_ref().map(|x| x.clone_ref(py)),
             extra: self.extra.clone(),
             comment: self.comment.clone(),
             column_ref: self.column_ref.clone_ref(py),
@@ -236,6 +365,7 @@ impl PyColumn {
             default=OptionalParam::Undefined,
             generated=OptionalParam::Undefined,
             stored_generated=false,
+            check=OptionalParam::Undefined,
         )
     )]
     #[allow(clippy::too_many_arguments)]
@@ -251,6 +381,7 @@ impl PyColumn {
         default: OptionalParam,
         generated: OptionalParam,
         stored_generated: bool,
+        check: OptionalParam,
     ) -> pyo3::PyResult<Self> {
         if !r#type.is_instance_of::<types::PyColumnTypeMeta>() {
             return Err(typeerror!(
@@ -260,6 +391,10 @@ impl PyColumn {
             ));
         }
 
+        if auto_increment {
+            check_auto_increment_type(r#type)?;
+        }
+
         let mut options = ((primary_key as u8) * (ColumnOptions::PrimaryKey as u8))
             | ((unique as u8) * (ColumnOptions::UniqueKey as u8))
             | ((auto_increment as u8) * (ColumnOptions::AutoIncrement as u8))
@@ -284,10 +419,7 @@ impl PyColumn {
         let default_expr = {
             match default {
                 OptionalParam::Undefined => None,
-                OptionalParam::Defined(x) => Some(crate::expression::PyExpr::try_with_specific_type(
-                    x.to_owned(),
-                    Some(r#type),
-                )?),
+                OptionalParam::Defined(x) => Some(default_expr(&name, x.to_owned(), r#type)?),
             }
         };
 
@@ -298,6 +430,13 @@ impl PyColumn {
             }
         };
 
+        let check_expr = {
+            match check {
+                OptionalParam::Undefined => None,
+                OptionalParam::Defined(x) => Some(crate::expression::PyExpr::try_from(x.to_owned())?),
+            }
+        };
+
         let py = r#type.py();
         let inner = ColumnInner {
             name,
@@ -305,6 +444,7 @@ impl PyColumn {
             options,
             default: default_expr.map(|x| pyo3::Py::new(py, x).unwrap().into_any()),
             generated: generated_expr.map(|x| pyo3::Py::new(py, x).unwrap().into_any()),
+            check: check_expr.map(|x| pyo3::Py::new(py, x).unwrap().into_any()),
             extra,
             comment,
             column_ref: LazyColumnRef::None,
@@ -343,6 +483,10 @@ impl PyColumn {
         }
 
         let mut lock = self.inner.lock();
+        if (lock.options & (ColumnOptions::AutoIncrement as u8)) > 0 {
+            check_auto_increment_type(val)?;
+        }
+
         lock.r#type = val.clone().unbind();
 
         Ok(())
@@ -416,13 +560,16 @@ impl PyColumn {
     }
 
     #[setter]
-    fn set_auto_increment(&self, val: bool) {
+    fn set_auto_increment(&self, py: pyo3::Python<'_>, val: bool) -> pyo3::PyResult<()> {
         let mut lock = self.inner.lock();
         if val {
+            check_auto_increment_type(lock.r#type.bind(py))?;
             lock.options |= ColumnOptions::AutoIncrement as u8;
         } else {
             lock.options &= !(ColumnOptions::AutoIncrement as u8);
         }
+
+        Ok(())
     }
 
     #[getter]
@@ -455,9 +602,9 @@ impl PyColumn {
     #[setter]
     fn set_default(&self, val: pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<()> {
         let py = val.py();
-        let default_expr = crate::expression::PyExpr::try_from(val)?;
 
         let mut lock = self.inner.lock();
+        let default_expr = default_expr(&lock.name, val, lock.r#type.bind(py))?;
         lock.default = Some(pyo3::Py::new(py, default_expr).unwrap().into_any());
 
         Ok(())
@@ -479,6 +626,28 @@ impl PyColumn {
         Ok(())
     }
 
+    #[getter]
+    fn check(slf: pyo3::PyRef<'_, Self>) -> Option<pyo3::Py<pyo3::PyAny>> {
+        slf.inner.lock().check.as_ref().map(|x| x.clone_ref(slf.py()))
+    }
+
+    #[setter]
+    fn set_check(&self, val: Option<pyo3::Bound<'_, pyo3::PyAny>>) -> pyo3::PyResult<()> {
+        let check_expr = match val {
+            Some(x) => {
+                let py = x.py();
+                let expr = crate::expression::PyExpr::try_from(x)?;
+                Some(pyo3::Py::new(py, expr).unwrap().into_any())
+            }
+            None => None,
+        };
+
+        let mut lock = self.inner.lock();
+        lock.check = check_expr;
+
+        Ok(())
+    }
+
     fn to_column_ref(&self, py: pyo3::Python) -> crate::common::PyColumnRef {
         let mut lock = self.inner.lock();
         lock.as_column_ref(py).into()
@@ -489,13 +658,48 @@ impl PyColumn {
         lock.as_simple_expr(py).into()
     }
 
+    fn uuid_default(slf: pyo3::PyRef<'_, Self>) -> pyo3::PyResult<pyo3::PyRef<'_, Self>> {
+        // Resolved to the function of each backend when the column is built
+        let default_expr: crate::expression::PyExpr = sea_query::SimpleExpr::FunctionCall(
+            sea_query::Func::cust(sea_query::Alias::new(crate::backend::UUID_GENERATE)),
+        )
+        .into();
+
+        {
+            let mut lock = slf.inner.lock();
+
+            if unsafe { pyo3::ffi::Py_TYPE(lock.r#type.as_ptr()) != crate::typeref::UUID_COLUMN_TYPE } {
+                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "uuid_default requires a UuidType column",
+                ));
+            }
+
+            lock.default = Some(pyo3::Py::new(slf.py(), default_expr)?.into_any());
+        }
+
+        Ok(slf)
+    }
+
+    #[staticmethod]
+    fn default_now() -> crate::expression::PyExpr {
+        sea_query::SimpleExpr::Keyword(sea_query::Keyword::CurrentTimestamp).into()
+    }
+
+    #[staticmethod]
+    fn default_today() -> crate::expression::PyExpr {
+        sea_query::SimpleExpr::Keyword(sea_query::Keyword::CurrentDate).into()
+    }
+
+    #[pyo3(signature=(value, strict=false))]
     fn adapt(
         &self,
         value: pyo3::Bound<'_, pyo3::PyAny>,
+        strict: bool,
     ) -> pyo3::PyResult<crate::adaptation::PyAdaptedValue> {
         let py = value.py();
         let lock = self.inner.lock();
-        let value = crate::adaptation::ReturnableValue::from_bound(value, Some(lock.r#type.bind(py)))?;
+        let value =
+            crate::adaptation::ReturnableValue::from_bound(value, Some(lock.r#type.bind(py)), strict)?;
 
         Ok(value.into())
     }
@@ -551,6 +755,9 @@ impl PyColumn {
         if lock.options & (ColumnOptions::StoredGenerated as u8) > 0 {
             write!(s, " stored_generated=True").unwrap();
         }
+        if let Some(x) = &lock.check {
+            write!(s, " check={x}").unwrap();
+        }
         write!(s, ">").unwrap();
 
         unsafe { String::from_utf8_unchecked(s) }
@@ -0,0 +1,228 @@
+use super::types::*;
+use crate::backend::BackendKind;
+
+#[inline]
+fn instance<T>(py: pyo3::Python, init: (T, PyColumnTypeMeta)) -> pyo3::PyResult<pyo3::Py<pyo3::PyAny>>
+where
+    T: pyo3::PyClass<BaseType = PyColumnTypeMeta>,
+{
+    Ok(pyo3::Py::new(py, pyo3::PyClassInitializer::from(init))?.into_any())
+}
+
+fn unknown_type(s: &str) -> pyo3::PyErr {
+    pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("unknown column type {s:?}"))
+}
+
+fn invalid_arguments(s: &str) -> pyo3::PyErr {
+    pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("invalid arguments for column type {s:?}"))
+}
+
+/// Splits `name(arg, ...)` into the name and its arguments.
+///
+/// The arguments may come before a suffix of the name, as in `bit(8) varying`.
+fn split_arguments(s: &str) -> Option<(std::borrow::Cow<'_, str>, Vec<u32>)> {
+    let Some(open) = s.find('(') else {
+        return Some((s.into(), Vec::new()));
+    };
+    let close = open + s[open..].find(')')?;
+
+    let args = s[open + 1..close]
+        .split(',')
+        .map(|x| x.trim().parse::<u32>().ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    let name = s[..open].trim_end();
+    let suffix = &s[close + 1..];
+
+    if suffix.is_empty() {
+        Some((name.into(), args))
+    } else {
+        Some((format!("{name}{suffix}").into(), args))
+    }
+}
+
+/// Returns the `INTERVAL_*` constant of the interval fields named `s`, as in
+/// `interval day to second`.
+fn interval_fields(s: &str) -> Option<u8> {
+    let fields = match s {
+        "year" => sea_query::PgInterval::Year,
+        "month" => sea_query::PgInterval::Month,
+        "day" => sea_query::PgInterval::Day,
+        "hour" => sea_query::PgInterval::Hour,
+        "minute" => sea_query::PgInterval::Minute,
+        "second" => sea_query::PgInterval::Second,
+        "year to month" => sea_query::PgInterval::YearToMonth,
+        "day to hour" => sea_query::PgInterval::DayToHour,
+        "day to minute" => sea_query::PgInterval::DayToMinute,
+        "day to second" => sea_query::PgInterval::DayToSecond,
+        "hour to minute" => sea_query::PgInterval::HourToMinute,
+        "hour to second" => sea_query::PgInterval::HourToSecond,
+        "minute to second" => sea_query::PgInterval::MinuteToSecond,
+        _ => return None,
+    };
+    Some(fields as u8)
+}
+
+/// Parses a normalized (lowercase, single spaced) type string of `backend`.
+fn parse_type(py: pyo3::Python, s: &str, backend: BackendKind) -> pyo3::PyResult<pyo3::Py<pyo3::PyAny>> {
+    if let Some(element) = s.strip_suffix("[]") {
+        if backend != BackendKind::Postgres {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "array column types are only supported on PostgreSQL",
+            ));
+        }
+
+        let element = parse_type(py, element.trim_end(), backend)?;
+        return instance(py, PyArrayType::new(py, element)?);
+    }
+
+    let (rest, unsigned) = match s.strip_suffix(" unsigned") {
+        Some(x) if backend == BackendKind::Mysql => (x, true),
+        _ => (s, false),
+    };
+
+    let (name, args) = split_arguments(rest).ok_or_else(|| invalid_arguments(s))?;
+
+    let length = || match args[..] {
+        [] => Ok(None),
+        [length] => Ok(Some(length)),
+        _ => Err(invalid_arguments(s)),
+    };
+    let precision_scale = || match args[..] {
+        [] => Ok(None),
+        [precision] => Ok(Some((precision, 0))),
+        [precision, scale] => Ok(Some((precision, scale))),
+        _ => Err(invalid_arguments(s)),
+    };
+    // MySQL reports a display width for integer types, as in `int(11)`, which
+    // doesn't change the type
+    let integer = || match args[..] {
+        [] => Ok(()),
+        [_] if backend == BackendKind::Mysql => Ok(()),
+        _ => Err(invalid_arguments(s)),
+    };
+    let no_arguments = || {
+        if args.is_empty() {
+            Ok(())
+        } else {
+            Err(invalid_arguments(s))
+        }
+    };
+    // PostgreSQL and MySQL declare a fractional seconds precision for time types,
+    // as in `timestamp(3)`, which the column types don't keep
+    let fractional_seconds = || match args[..] {
+        [] => Ok(()),
+        [_] if backend != BackendKind::Sqlite => Ok(()),
+        _ => Err(invalid_arguments(s)),
+    };
+
+    if unsigned {
+        integer()?;
+
+        return match &*name {
+            "tinyint" => instance(py, PyTinyUnsignedType::new()),
+            "smallint" => instance(py, PySmallUnsignedType::new()),
+            "int" | "integer" => instance(py, PyUnsignedType::new()),
+            "bigint" => instance(py, PyBigUnsignedType::new()),
+            _ => Err(unknown_type(s)),
+        };
+    }
+
+    match (&*name, backend) {
+        // Integers
+        ("tinyint", _) => integer().and_then(|_| instance(py, PyTinyIntegerType::new())),
+        ("smallint" | "int2", _) => integer().and_then(|_| instance(py, PySmallIntegerType::new())),
+        ("int" | "integer" | "int4", _) => integer().and_then(|_| instance(py, PyIntegerType::new())),
+        ("bigint" | "int8", _) => integer().and_then(|_| instance(py, PyBigIntegerType::new())),
+
+        // Floating and fixed point numbers; SQLite has no fixed point type, and declares decimals
+        // as `real(precision, scale)`
+        ("real", BackendKind::Sqlite) => instance(py, PyDecimalType::new(precision_scale()?)),
+        ("real_money", BackendKind::Sqlite) => instance(py, PyMoneyType::new(precision_scale()?)),
+        ("real" | "float" | "float4", _) => no_arguments().and_then(|_| instance(py, PyFloatType::new())),
+        ("double" | "double precision" | "float8", _) => {
+            no_arguments().and_then(|_| instance(py, PyDoubleType::new()))
+        }
+        ("decimal" | "numeric", _) => instance(py, PyDecimalType::new(precision_scale()?)),
+        ("money", _) => instance(py, PyMoneyType::new(precision_scale()?)),
+
+        // Strings
+        ("char" | "character", _) => instance(py, PyCharType::new(length()?)),
+        ("varchar" | "character varying", _) => instance(py, PyStringType::new(length()?)),
+        ("text", _) => no_arguments().and_then(|_| instance(py, PyTextType::new())),
+
+        // Date and time
+        ("datetime" | "datetime_text", _) | ("timestamp without time zone", BackendKind::Postgres) => {
+            fractional_seconds().and_then(|_| instance(py, PyDateTimeType::new()))
+        }
+        ("timestamp" | "timestamp_text", _) => {
+            fractional_seconds().and_then(|_| instance(py, PyTimestampType::new()))
+        }
+        ("timestamptz" | "timestamp with time zone" | "timestamp_with_timezone_text", _) => {
+            fractional_seconds().and_then(|_| instance(py, PyTimestampWithTimeZoneType::new()))
+        }
+        ("time" | "time_text", _) | ("time without time zone", BackendKind::Postgres) => {
+            fractional_seconds().and_then(|_| instance(py, PyTimeType::new()))
+        }
+        ("date" | "date_text", _) => no_arguments().and_then(|_| instance(py, PyDateType::new())),
+        ("year", BackendKind::Mysql) => no_arguments().and_then(|_| instance(py, PyYearType::new())),
+        ("interval", BackendKind::Postgres) => match args[..] {
+            [] => instance(py, PyIntervalType::new(None, None)?),
+            [precision] => instance(py, PyIntervalType::new(None, Some(precision))?),
+            _ => Err(invalid_arguments(s)),
+        },
+        (x, BackendKind::Postgres) if x.starts_with("interval ") => {
+            let fields = interval_fields(&x["interval ".len()..]).ok_or_else(|| unknown_type(s))?;
+
+            match args[..] {
+                [] => instance(py, PyIntervalType::new(Some(fields), None)?),
+                [precision] => instance(py, PyIntervalType::new(Some(fields), Some(precision))?),
+                _ => Err(invalid_arguments(s)),
+            }
+        }
+
+        // Binary data; SQLite declares binaries as `blob(length)`
+        ("blob", BackendKind::Sqlite) if !args.is_empty() => instance(py, PyBinaryType::new(length()?)),
+        ("blob" | "bytea", _) => no_arguments().and_then(|_| instance(py, PyBlobType::new())),
+        ("binary", _) => instance(py, PyBinaryType::new(length()?)),
+        ("varbinary" | "varbinary_blob", _) => instance(py, PyVarBinaryType::new(length()?)),
+        ("bit", _) => instance(py, PyBitType::new(length()?)),
+        ("varbit" | "bit varying", BackendKind::Postgres) => instance(py, PyVarBitType::new(length()?)),
+
+        // Others
+        ("bool" | "boolean", _) => no_arguments().and_then(|_| instance(py, PyBooleanType::new())),
+        ("json" | "json_text", _) => no_arguments().and_then(|_| instance(py, PyJsonType::new())),
+        ("jsonb" | "jsonb_text", _) => no_arguments().and_then(|_| instance(py, PyJsonBinaryType::new())),
+        ("uuid" | "uuid_text", _) => no_arguments().and_then(|_| instance(py, PyUuidType::new())),
+        ("vector", BackendKind::Postgres) => instance(py, PyVectorType::new(length()?)),
+        ("cidr", BackendKind::Postgres) => no_arguments().and_then(|_| instance(py, PyCidrType::new())),
+        ("inet", BackendKind::Postgres) => no_arguments().and_then(|_| instance(py, PyInetType::new())),
+        ("macaddr", BackendKind::Postgres) => {
+            no_arguments().and_then(|_| instance(py, PyMacAddressType::new()))
+        }
+        ("ltree", BackendKind::Postgres) => no_arguments().and_then(|_| instance(py, PyLTreeType::new())),
+
+        _ => Err(unknown_type(s)),
+    }
+}
+
+/// Parses a column type as declared by `backend`, such as `varchar(255)`,
+/// `numeric(10,2)`, `timestamptz` or `int[]`.
+///
+/// The inverse of rendering a column type: the result renders back to an
+/// equivalent type.
+#[pyo3::pyfunction]
+pub fn column_type_from_string(
+    py: pyo3::Python,
+    s: &str,
+    backend: &pyo3::Bound<'_, pyo3::PyAny>,
+) -> pyo3::PyResult<pyo3::Py<pyo3::PyAny>> {
+    let backend = crate::backend::into_backend_kind(backend)?;
+    let normalized = s
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_ascii_lowercase();
+
+    parse_type(py, &normalized, backend)
+}
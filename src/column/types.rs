@@ -41,7 +41,7 @@ macro_rules! impl_column_type {
             #[pyo3::pymethods]
             impl $name {
                 #[new]
-                fn new() -> (Self, PyColumnTypeMeta) {
+                pub(crate) fn new() -> (Self, PyColumnTypeMeta) {
                     (Self::default(), PyColumnTypeMeta::default())
                 }
 
@@ -107,7 +107,7 @@ macro_rules! impl_column_type {
             impl $name {
                 #[new]
                 #[pyo3(signature=(length=None))]
-                fn new(length: Option<u32>) -> (Self, PyColumnTypeMeta) {
+                pub(crate) fn new(length: Option<u32>) -> (Self, PyColumnTypeMeta) {
                     let value = length.unwrap_or(0);
                     (
                         Self {
@@ -213,7 +213,7 @@ macro_rules! impl_column_type {
             impl $name {
                 #[new]
                 #[pyo3(signature=(precision_scale=None))]
-                fn new(precision_scale: Option<(u32, u32)>) -> (Self, PyColumnTypeMeta) {
+                pub(crate) fn new(precision_scale: Option<(u32, u32)>) -> (Self, PyColumnTypeMeta) {
                     let (precision, scale) = precision_scale.unwrap_or((0, 0));
                     (
                         Self {
@@ -229,7 +229,8 @@ macro_rules! impl_column_type {
                     let precision = self.precision.load(std::sync::atomic::Ordering::Relaxed);
                     let scale = self.scale.load(std::sync::atomic::Ordering::Relaxed);
 
-                    if precision == 0 || scale == 0 {
+                    // A zero scale is valid, as in `decimal(10, 0)`
+                    if precision == 0 {
                         None
                     } else {
                         Some((precision, scale))
@@ -357,12 +358,12 @@ impl_column_type!(
 impl_column_type!(
     PyDecimalType(name="DecimalType") => precision_scale(|precision, scale| {
         sea_query::ColumnType::Decimal(
-            precision.and_then(|p| scale.map(|s| (p, s)))
+            precision.zip(scale)
         )
     }),
     PyMoneyType(name="MoneyType") => precision_scale(|precision, scale| {
         sea_query::ColumnType::Money(
-            precision.and_then(|p| scale.map(|s| (p, s)))
+            precision.zip(scale)
         )
     }),
 );
@@ -390,7 +391,10 @@ pub struct PyIntervalType {
 impl PyIntervalType {
     #[new]
     #[pyo3(signature=(fields=None, precision=None))]
-    fn new(fields: Option<u8>, precision: Option<u32>) -> pyo3::PyResult<(Self, PyColumnTypeMeta)> {
+    pub(crate) fn new(
+        fields: Option<u8>,
+        precision: Option<u32>,
+    ) -> pyo3::PyResult<(Self, PyColumnTypeMeta)> {
         let fields = match fields {
             Some(x) => Some(into_pginterval(x).map_err(|_| {
                 pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>("expected INTERVAL_* constants")
@@ -619,7 +623,10 @@ pub struct PyArrayType {
 #[pyo3::pymethods]
 impl PyArrayType {
     #[new]
-    fn new(py: pyo3::Python, element: pyo3::Py<pyo3::PyAny>) -> pyo3::PyResult<(Self, PyColumnTypeMeta)> {
+    pub(crate) fn new(
+        py: pyo3::Python,
+        element: pyo3::Py<pyo3::PyAny>,
+    ) -> pyo3::PyResult<(Self, PyColumnTypeMeta)> {
         if !element.bind(py).is_instance_of::<PyColumnTypeMeta>() {
             Err(pyo3::PyErr::new::<pyo3::exceptions::PyTypeError, _>(
                 "element must be an instance of Base",
@@ -633,6 +640,30 @@ impl PyArrayType {
         }
     }
 
+    #[classmethod]
+    #[pyo3(signature=(element, dimensions=1))]
+    fn of(
+        cls: &pyo3::Bound<'_, pyo3::types::PyType>,
+        element: pyo3::Py<pyo3::PyAny>,
+        dimensions: usize,
+    ) -> pyo3::PyResult<pyo3::Py<pyo3::PyAny>> {
+        let py = cls.py();
+
+        if dimensions == 0 {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "dimensions must be at least 1",
+            ));
+        }
+
+        let mut array = element;
+        for _ in 0..dimensions {
+            let initializer = Self::new(py, array)?;
+            array = pyo3::Py::new(py, pyo3::PyClassInitializer::from(initializer))?.into_any();
+        }
+
+        Ok(array)
+    }
+
     #[getter]
     fn element(&self, py: pyo3::Python) -> pyo3::Py<pyo3::PyAny> {
         let lock = self.inner.lock();
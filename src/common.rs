@@ -22,23 +22,55 @@ impl std::fmt::Display for ColumnNameOrAstrisk {
 }
 
 #[pyo3::pyclass(module = "rapidquery._lib", name = "ColumnRef", frozen)]
-#[derive(Clone)]
 pub struct PyColumnRef {
     pub col: ColumnNameOrAstrisk,
     pub table: Option<sea_query::DynIden>,
     pub schema: Option<sea_query::DynIden>,
+
+    /// The [`sea_query::ColumnRef`] converted on first use; see
+    /// [`IntoColumnRef`](sea_query::IntoColumnRef).
+    pub converted: once_cell::sync::OnceCell<sea_query::ColumnRef>,
+}
+
+/// Clones without the converted reference, because the fields of a clone may be
+/// changed.
+impl Clone for PyColumnRef {
+    fn clone(&self) -> Self {
+        Self {
+            col: self.col.clone(),
+            table: self.table.clone(),
+            schema: self.schema.clone(),
+            converted: once_cell::sync::OnceCell::new(),
+        }
+    }
 }
 
 impl sea_query::IntoColumnRef for PyColumnRef {
     fn into_column_ref(self) -> sea_query::ColumnRef {
-        if let ColumnNameOrAstrisk::Name(name) = self.col {
-            match (self.table, self.schema) {
-                (Some(table), Some(schema)) => sea_query::ColumnRef::SchemaTableColumn(schema, table, name),
-                (Some(table), None) => sea_query::ColumnRef::TableColumn(table, name),
-                _ => sea_query::ColumnRef::Column(name),
+        (&self).into_column_ref()
+    }
+}
+
+/// Borrowing conversion, which converts once and then reuses the result, so
+/// building a statement repeatedly doesn't rebuild the reference every time.
+impl sea_query::IntoColumnRef for &PyColumnRef {
+    fn into_column_ref(self) -> sea_query::ColumnRef {
+        self.converted.get_or_init(|| self.convert()).clone()
+    }
+}
+
+impl PyColumnRef {
+    fn convert(&self) -> sea_query::ColumnRef {
+        if let ColumnNameOrAstrisk::Name(name) = &self.col {
+            match (&self.table, &self.schema) {
+                (Some(table), Some(schema)) => {
+                    sea_query::ColumnRef::SchemaTableColumn(schema.clone(), table.clone(), name.clone())
+                }
+                (Some(table), None) => sea_query::ColumnRef::TableColumn(table.clone(), name.clone()),
+                _ => sea_query::ColumnRef::Column(name.clone()),
             }
-        } else if let Some(table) = self.table {
-            sea_query::ColumnRef::TableAsterisk(table)
+        } else if let Some(table) = &self.table {
+            sea_query::ColumnRef::TableAsterisk(table.clone())
         } else {
             sea_query::ColumnRef::Asterisk
         }
@@ -52,26 +84,31 @@ impl From<sea_query::ColumnRef> for PyColumnRef {
                 col: ColumnNameOrAstrisk::Astrisk,
                 table: None,
                 schema: None,
+                converted: Default::default(),
             },
             sea_query::ColumnRef::TableAsterisk(table) => Self {
                 col: ColumnNameOrAstrisk::Astrisk,
                 table: Some(table),
                 schema: None,
+                converted: Default::default(),
             },
             sea_query::ColumnRef::SchemaTableColumn(schema, table, name) => Self {
                 col: ColumnNameOrAstrisk::Name(name),
                 table: Some(table),
                 schema: Some(schema),
+                converted: Default::default(),
             },
             sea_query::ColumnRef::TableColumn(table, name) => Self {
                 col: ColumnNameOrAstrisk::Name(name),
                 table: Some(table),
                 schema: None,
+                converted: Default::default(),
             },
             sea_query::ColumnRef::Column(name) => Self {
                 col: ColumnNameOrAstrisk::Name(name),
                 table: None,
                 schema: None,
+                converted: Default::default(),
             },
         }
     }
@@ -112,6 +149,7 @@ impl FromStr for PyColumnRef {
             },
             table: table.map(|x| sea_query::Alias::new(x).into_iden()),
             schema: schema.map(|x| sea_query::Alias::new(x).into_iden()),
+            converted: Default::default(),
         })
     }
 }
@@ -125,6 +163,7 @@ impl PyColumnRef {
             col: ColumnNameOrAstrisk::Name(sea_query::Alias::new(name).into_iden()),
             table: table.map(|x| sea_query::Alias::new(x).into_iden()),
             schema: schema.map(|x| sea_query::Alias::new(x).into_iden()),
+            converted: Default::default(),
         }
     }
 
@@ -248,29 +287,65 @@ impl PyColumnRef {
 }
 
 #[pyo3::pyclass(module = "rapidquery._lib", name = "TableName", frozen)]
-#[derive(Clone)]
 pub struct PyTableName {
     pub name: sea_query::DynIden,
     pub schema: Option<sea_query::DynIden>,
     pub database: Option<sea_query::DynIden>,
     pub alias: Option<sea_query::DynIden>,
+
+    /// The [`sea_query::TableRef`] converted on first use; see
+    /// [`IntoTableRef`](sea_query::IntoTableRef).
+    pub converted: once_cell::sync::OnceCell<sea_query::TableRef>,
+}
+
+/// Clones without the converted reference, because the fields of a clone may be
+/// changed.
+impl Clone for PyTableName {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            schema: self.schema.clone(),
+            database: self.database.clone(),
+            alias: self.alias.clone(),
+            converted: once_cell::sync::OnceCell::new(),
+        }
+    }
 }
 
 impl sea_query::IntoTableRef for PyTableName {
     fn into_table_ref(self) -> sea_query::TableRef {
-        match (self.schema, self.database, self.alias) {
-            (Some(schema), Some(database), Some(alias)) => {
-                sea_query::TableRef::DatabaseSchemaTableAlias(database, schema, self.name, alias)
-            }
+        (&self).into_table_ref()
+    }
+}
+
+/// Borrowing conversion, which converts once and then reuses the result, so
+/// building a statement repeatedly doesn't rebuild the reference every time.
+impl sea_query::IntoTableRef for &PyTableName {
+    fn into_table_ref(self) -> sea_query::TableRef {
+        self.converted.get_or_init(|| self.convert()).clone()
+    }
+}
+
+impl PyTableName {
+    fn convert(&self) -> sea_query::TableRef {
+        let name = self.name.clone();
+
+        match (&self.schema, &self.database, &self.alias) {
+            (Some(schema), Some(database), Some(alias)) => sea_query::TableRef::DatabaseSchemaTableAlias(
+                database.clone(),
+                schema.clone(),
+                name,
+                alias.clone(),
+            ),
             (Some(schema), None, Some(alias)) => {
-                sea_query::TableRef::SchemaTableAlias(schema, self.name, alias)
+                sea_query::TableRef::SchemaTableAlias(schema.clone(), name, alias.clone())
             }
             (Some(schema), Some(database), None) => {
-                sea_query::TableRef::DatabaseSchemaTable(database, schema, self.name)
+                sea_query::TableRef::DatabaseSchemaTable(database.clone(), schema.clone(), name)
             }
-            (Some(schema), None, None) => sea_query::TableRef::SchemaTable(schema, self.name),
-            (None, None, Some(alias)) => sea_query::TableRef::TableAlias(self.name, alias),
-            _ => sea_query::TableRef::Table(self.name),
+            (Some(schema), None, None) => sea_query::TableRef::SchemaTable(schema.clone(), name),
+            (None, None, Some(alias)) => sea_query::TableRef::TableAlias(name, alias.clone()),
+            _ => sea_query::TableRef::Table(name),
         }
     }
 }
@@ -285,36 +360,42 @@ impl TryFrom<sea_query::TableRef> for PyTableName {
                 schema: Some(schema),
                 database: Some(db),
                 alias: Some(alias),
+                converted: Default::default(),
             }),
             sea_query::TableRef::SchemaTableAlias(schema, name, alias) => Ok(Self {
                 name,
                 schema: Some(schema),
                 database: None,
                 alias: Some(alias),
+                converted: Default::default(),
             }),
             sea_query::TableRef::TableAlias(name, alias) => Ok(Self {
                 name,
                 schema: None,
                 database: None,
                 alias: Some(alias),
+                converted: Default::default(),
             }),
             sea_query::TableRef::DatabaseSchemaTable(db, schema, name) => Ok(Self {
                 name,
                 schema: Some(schema),
                 database: Some(db),
                 alias: None,
+                converted: Default::default(),
             }),
             sea_query::TableRef::SchemaTable(schema, name) => Ok(Self {
                 name,
                 schema: Some(schema),
                 database: None,
                 alias: None,
+                converted: Default::default(),
             }),
             sea_query::TableRef::Table(name) => Ok(Self {
                 name,
                 schema: None,
                 database: None,
                 alias: None,
+                converted: Default::default(),
             }),
             _ => Err(()),
         }
@@ -353,6 +434,7 @@ impl FromStr for PyTableName {
             schema,
             database,
             alias: None,
+            converted: Default::default(),
         })
     }
 }
@@ -387,6 +469,7 @@ impl PyTableName {
             schema: schema.map(|x| sea_query::Alias::new(x).into_iden()),
             database: database.map(|x| sea_query::Alias::new(x).into_iden()),
             alias: alias.map(|x| sea_query::Alias::new(x).into_iden()),
+            converted: Default::default(),
         }
     }
 
@@ -488,6 +571,28 @@ impl PyTableName {
         Ok(slf.name != other.name || slf.database != other.database || slf.schema != other.schema)
     }
 
+    fn __hash__(&self) -> isize {
+        use std::hash::{Hash, Hasher};
+
+        // Same fields as `__eq__`, so that equal names hash the same
+        let mut hasher = std::hash::DefaultHasher::new();
+        self.name.to_string().hash(&mut hasher);
+        self.schema.as_ref().map(|x| x.to_string()).hash(&mut hasher);
+        self.database.as_ref().map(|x| x.to_string()).hash(&mut hasher);
+
+        hasher.finish() as isize
+    }
+
+    #[pyo3(signature=(other, compare_alias=false))]
+    fn equals(&self, other: &pyo3::Bound<'_, Self>, compare_alias: bool) -> bool {
+        let other = other.get();
+
+        self.name == other.name
+            && self.database == other.database
+            && self.schema == other.schema
+            && (!compare_alias || self.alias == other.alias)
+    }
+
     fn __copy__(&self) -> Self {
         self.clone()
     }
@@ -1,3 +1,4 @@
+use crate::parameters::{LikeEscape, OptionalParam};
 use pyo3::types::PyAnyMethods;
 
 /// Represents a SQL expression that can be built into SQL code.
@@ -74,9 +75,303 @@ impl PyExpr {
         Self { inner: values.into() }
     }
 
+    /// Builds a row expression from the items of a tuple, list or set.
+    ///
+    /// Nested tuples and lists become nested rows instead of being flattened or
+    /// adapted as arrays, so `((a, b), (c, d))` renders as a row of rows.
+    fn tuple_from_iterable(value: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<Self> {
+        let mut values: Vec<sea_query::SimpleExpr> = Vec::with_capacity(value.len()?);
+
+        for op in value.try_iter()? {
+            let op = op?;
+
+            unsafe {
+                if pyo3::ffi::PyTuple_CheckExact(op.as_ptr()) == 1
+                    || pyo3::ffi::PyList_CheckExact(op.as_ptr()) == 1
+                {
+                    values.push(Self::tuple_from_iterable(&op)?.inner);
+                } else {
+                    values.push(Self::try_from(op)?.inner);
+                }
+            }
+        }
+
+        if values.is_empty() {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "tuple expression requires at least one item",
+            ));
+        }
+
+        Ok(Self::from_tuple(values))
+    }
+
+    /// Builds `quantifier(ARRAY[values])`, e.g. `ANY(ARRAY[1, 2, 3])`.
+    fn quantified_array(
+        quantifier: &'static str,
+        values: Vec<pyo3::Bound<'_, pyo3::PyAny>>,
+    ) -> pyo3::PyResult<sea_query::SimpleExpr> {
+        // PostgreSQL can't infer the element type of an empty ARRAY[]
+        if values.is_empty() {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "`values` parameter cannot be an empty sequence",
+            ));
+        }
+
+        let mut exprs = Vec::with_capacity(values.len());
+        for value in values {
+            exprs.push(Self::try_from(value)?.inner);
+        }
+
+        let array = sea_query::Func::cust(sea_query::Alias::new(crate::backend::ARRAY)).args(exprs);
+        let func = sea_query::Func::cust(sea_query::Alias::new(quantifier)).arg(array);
+        Ok(func.into())
+    }
+
+    /// Builds `CAST(expr AS type)` with the type name rendered per backend.
+    fn cast_as_column_type(expr: sea_query::SimpleExpr, column_type: &sea_query::ColumnType) -> Self {
+        let names = crate::backend::cast_type_names(column_type)
+            .into_iter()
+            .map(sea_query::SimpleExpr::Custom)
+            .collect();
+
+        sea_query::SimpleExpr::Binary(
+            Box::new(expr),
+            sea_query::BinOper::Custom(crate::backend::CAST_AS_TYPE),
+            Box::new(sea_query::SimpleExpr::Tuple(names)),
+        )
+        .into()
+    }
+
+    /// Checks that `name` can be put into SQL as a type name, as it's written
+    /// as-is.
+    ///
+    /// Besides being non-empty, a name is limited to the characters of type
+    /// names such as `double precision`, `numeric(10, 2)`, `public.mood` or
+    /// `int[]`, with balanced parentheses.
+    fn check_type_name(name: &str) -> pyo3::PyResult<()> {
+        let invalid = |reason: &str| {
+            pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "invalid type name {name:?}: {reason}"
+            ))
+        };
+
+        if name.trim().is_empty() {
+            return Err(invalid("it's empty"));
+        }
+
+        let mut depth = 0usize;
+        for c in name.chars() {
+            match c {
+                '(' => depth += 1,
+                ')' if depth == 0 => return Err(invalid("unbalanced parentheses")),
+                ')' => depth -= 1,
+                c if c.is_ascii_alphanumeric() || matches!(c, '_' | ' ' | ',' | '.' | '[' | ']') => (),
+                c => return Err(invalid(&format!("unexpected character {c:?}"))),
+            }
+        }
+
+        if depth != 0 {
+            return Err(invalid("unbalanced parentheses"));
+        }
+        Ok(())
+    }
+
+    /// Converts `element_type` into an array column type of it.
+    fn array_column_type(
+        element_type: &pyo3::Bound<'_, pyo3::PyAny>,
+    ) -> pyo3::PyResult<sea_query::ColumnType> {
+        let element_type = crate::column::convert::convert_to_column_type(element_type).ok_or_else(|| {
+            typeerror!(
+                "expected column type, got {}",
+                element_type.py(),
+                element_type.as_ptr()
+            )
+        })?;
+        let column_type = sea_query::ColumnType::Array(sea_query::RcOrArc::new(element_type));
+
+        Ok(column_type)
+    }
+
+    /// Whether `expr` is a cast to an array type, made by `cast_array` or
+    /// `cast_as(ArrayType(...))`.
+    fn is_array_cast(expr: &sea_query::SimpleExpr) -> bool {
+        match expr {
+            sea_query::SimpleExpr::Binary(_, sea_query::BinOper::Custom(oper), right)
+                if *oper == crate::backend::CAST_AS_TYPE =>
+            {
+                // Only PostgreSQL has array types, so its name is the one to look at
+                matches!(
+                    right.as_ref(),
+                    sea_query::SimpleExpr::Tuple(names)
+                        if matches!(names.first(), Some(sea_query::SimpleExpr::Custom(x)) if x.ends_with("[]"))
+                )
+            }
+            _ => false,
+        }
+    }
+
+    /// Builds `quantifier(array)`, e.g. `ANY("tags")`, where `array` is an
+    /// array-typed expression.
+    ///
+    /// The element type can't be known from a plain expression, so `array` must
+    /// be an array-typed `Column` or a cast to an array type, unless
+    /// `element_type` is given to cast it.
+    fn quantified_array_expr(
+        quantifier: &'static str,
+        array: &pyo3::Bound<'_, pyo3::PyAny>,
+        element_type: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+    ) -> pyo3::PyResult<sea_query::SimpleExpr> {
+        let array_expr = if let Some(element_type) = element_type {
+            let column_type = Self::array_column_type(element_type)?;
+            Self::cast_as_column_type(Self::try_from(array.clone())?.inner, &column_type).inner
+        } else {
+            let is_array_column = unsafe {
+                pyo3::ffi::Py_TYPE(array.as_ptr()) == crate::typeref::COLUMN_TYPE && {
+                    let column = array.cast_unchecked::<crate::column::PyColumn>();
+                    let lock = column.get().inner.lock();
+                    pyo3::ffi::Py_TYPE(lock.r#type.as_ptr()) == crate::typeref::ARRAY_COLUMN_TYPE
+                }
+            };
+
+            let array_expr = Self::try_from(array.clone())?.inner;
+            if !is_array_column && !Self::is_array_cast(&array_expr) {
+                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "cannot tell that `array` is array-typed; pass an ArrayType column, \
+                     an expression made by cast_array(), or `element_type`",
+                ));
+            }
+
+            array_expr
+        };
+
+        Ok(sea_query::Func::cust(sea_query::Alias::new(quantifier))
+            .arg(array_expr)
+            .into())
+    }
+
+    /// Converts the values of `in_` and `not_in`.
+    ///
+    /// `values` can be any iterable; its items are converted one by one as
+    /// they're taken, so generators don't have to be collected into a list
+    /// first. `Expr`s and `AdaptedValue`s are used as they are. Other
+    /// values are adapted to `type` when it's given, which is converted
+    /// once for all of them, or inferred one by one otherwise.
+    fn membership_values(
+        values: &pyo3::Bound<'_, pyo3::PyAny>,
+        r#type: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+    ) -> pyo3::PyResult<Vec<sea_query::SimpleExpr>> {
+        let py = values.py();
+
+        // A string is iterable too, but never means a list of characters here
+        if values.is_instance_of::<pyo3::types::PyString>() {
+            return Err(typeerror!(
+                "expected iterable of values, got {}",
+                py,
+                values.as_ptr()
+            ));
+        }
+
+        let column_type = match r#type {
+            Some(x) => Some(std::sync::Arc::new(
+                crate::column::convert::convert_to_column_type(x).ok_or_else(|| {
+                    pyo3::PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                        "could not detect column type. are you sure you're using BaseColumnType instances?",
+                    )
+                })?,
+            )),
+            None => None,
+        };
+
+        // Generators have no length, so they just grow the vector
+        let mut exprs = Vec::with_capacity(values.len().unwrap_or(0));
+
+        for value in values.try_iter()? {
+            let value = value?;
+
+            unsafe {
+                let type_ptr = pyo3::ffi::Py_TYPE(value.as_ptr());
+
+                if type_ptr == crate::typeref::ADAPTED_VALUE_TYPE {
+                    let value = value.cast_into_unchecked::<crate::adaptation::PyAdaptedValue>();
+                    exprs.push(Self::from_adapted_value(py, value.get()).inner);
+                } else if type_ptr == crate::typeref::EXPR_TYPE {
+                    exprs.push(value.cast_into_unchecked::<Self>().get().inner.clone());
+                } else if let Some(column_type) = column_type
+                    .as_ref()
+                    .filter(|_| pyo3::ffi::Py_IsNone(value.as_ptr()) == 0)
+                {
+                    let mut value = crate::adaptation::ReturnableValue::with_specific_type(
+                        value,
+                        column_type.clone(),
+                        false,
+                    )?;
+                    exprs.push(value.create_simple_expr(py));
+                } else {
+                    exprs.push(Self::try_from(value)?.inner);
+                }
+            }
+        }
+
+        if exprs.is_empty() {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "`other` parameter cannot be an empty sequence",
+            ));
+        }
+
+        Ok(exprs)
+    }
+
+    /// Converts the arguments of `between`, `not_between` and `overlaps` into
+    /// bounds.
+    ///
+    /// Accepts both `between(low, high)` and `between((low, high))` forms.
+    fn between_bounds(
+        a: &pyo3::Bound<'_, pyo3::PyAny>,
+        b: OptionalParam<'_, '_>,
+    ) -> pyo3::PyResult<(Self, Self)> {
+        let (a, b) = Self::between_bound_objects(a, b)?;
+        Ok((Self::try_from(a)?, Self::try_from(b)?))
+    }
+
+    /// Same as [`Self::between_bounds`], but leaves the bounds unconverted.
+    fn between_bound_objects<'py>(
+        a: &pyo3::Bound<'py, pyo3::PyAny>,
+        b: OptionalParam<'_, 'py>,
+    ) -> pyo3::PyResult<(pyo3::Bound<'py, pyo3::PyAny>, pyo3::Bound<'py, pyo3::PyAny>)> {
+        if let OptionalParam::Defined(b) = b {
+            return Ok((a.clone(), b.to_owned()));
+        }
+
+        unsafe {
+            if pyo3::ffi::PyTuple_Check(a.as_ptr()) == 0 {
+                return Err(typeerror!(
+                    "expected a (low, high) tuple when only one bound is given, got {:?}",
+                    a.py(),
+                    a.as_ptr()
+                ));
+            }
+
+            let bounds = a.cast_unchecked::<pyo3::types::PyTuple>();
+            if bounds.len()? != 2 {
+                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "expected a (low, high) tuple of length 2, got length {}",
+                    bounds.len()?
+                )));
+            }
+
+            Ok((bounds.get_item(0)?, bounds.get_item(1)?))
+        }
+    }
+
+    /// Converts `value` into an expression, adapting a literal for `type` if
+    /// it's given.
+    ///
+    /// With `strict`, literal integers are also checked against the range of
+    /// sized integer types.
     pub fn try_with_specific_type(
         value: pyo3::Bound<'_, pyo3::PyAny>,
         r#type: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+        strict: bool,
     ) -> pyo3::PyResult<Self> {
         use sea_query::IntoColumnRef;
 
@@ -136,7 +431,7 @@ impl PyExpr {
                 Ok(Self::from_tuple(arr.into_iter().map(|x| x.inner.clone())))
             } else {
                 let py = value.py();
-                let mut value = crate::adaptation::ReturnableValue::from_bound(value, r#type)?;
+                let mut value = crate::adaptation::ReturnableValue::from_bound(value, r#type, strict)?;
 
                 Ok(value.create_simple_expr(py).into())
             }
@@ -148,7 +443,7 @@ impl TryFrom<pyo3::Bound<'_, pyo3::PyAny>> for PyExpr {
     type Error = pyo3::PyErr;
 
     fn try_from(value: pyo3::Bound<'_, pyo3::PyAny>) -> Result<Self, Self::Error> {
-        Self::try_with_specific_type(value, None)
+        Self::try_with_specific_type(value, None, false)
     }
 }
 
@@ -218,47 +513,31 @@ impl PyExpr {
         value: &pyo3::Bound<'_, pyo3::PyAny>,
     ) -> pyo3::PyResult<Self> {
         unsafe {
-            if pyo3::ffi::PyTuple_CheckExact(value.as_ptr()) == 1 {
-                let value = value.cast_unchecked::<pyo3::types::PyTuple>();
-                let mut values: Vec<pyo3::Bound<'_, Self>> = Vec::with_capacity(value.len()?);
-
-                for op in value {
-                    values.push(op.cast_into_exact::<Self>()?);
-                }
-
-                return Ok(Self::from_tuple(
-                    values.into_iter().map(|x| x.get().inner.clone()),
-                ));
-            }
-
-            if pyo3::ffi::PyList_CheckExact(value.as_ptr()) == 1 {
-                let value = value.cast_unchecked::<pyo3::types::PyList>();
-                let mut values: Vec<pyo3::Bound<'_, Self>> = Vec::with_capacity(value.len()?);
-
-                for op in value {
-                    values.push(op.cast_into_exact::<Self>()?);
-                }
-
-                return Ok(Self::from_tuple(
-                    values.into_iter().map(|x| x.get().inner.clone()),
-                ));
+            if pyo3::ffi::PyTuple_CheckExact(value.as_ptr()) == 1
+                || pyo3::ffi::PyList_CheckExact(value.as_ptr()) == 1
+            {
+                return Self::tuple_from_iterable(value);
             }
 
+            // Sets have no defined iteration order, so the items are sorted to render the
+            // same SQL every time
             if pyo3::ffi::PySet_CheckExact(value.as_ptr()) == 1 {
-                let value = value.cast_unchecked::<pyo3::types::PyList>();
-                let mut values: Vec<pyo3::Bound<'_, Self>> = Vec::with_capacity(value.len()?);
-
-                for op in value {
-                    values.push(op.cast_into_exact::<Self>()?);
-                }
-
-                return Ok(Self::from_tuple(
-                    values.into_iter().map(|x| x.get().inner.clone()),
-                ));
+                use pyo3::types::PyListMethods;
+
+                let items = value.try_iter()?.collect::<pyo3::PyResult<Vec<_>>>()?;
+                let items = pyo3::types::PyList::new(value.py(), items)?;
+                items.sort().map_err(|err| {
+                    pyo3::PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+                        "set items must be comparable to be sorted ({}); use a list or tuple instead",
+                        err.value(value.py())
+                    ))
+                })?;
+
+                return Self::tuple_from_iterable(items.as_any());
             }
 
             Err(typeerror!(
-                "expected tuple/list/set of Exprs, got {}",
+                "expected tuple/list/set, got {}",
                 value.py(),
                 value.as_ptr()
             ))
@@ -295,6 +574,54 @@ impl PyExpr {
         sea_query::SimpleExpr::Keyword(sea_query::Keyword::Null).into()
     }
 
+    #[classmethod]
+    #[pyo3(signature=(name, *args))]
+    fn func_call(
+        _cls: &pyo3::Bound<'_, pyo3::types::PyType>,
+        name: String,
+        args: &pyo3::Bound<'_, pyo3::types::PyTuple>,
+    ) -> pyo3::PyResult<Self> {
+        if !is_function_name(&name) {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "invalid function name: {name:?}"
+            )));
+        }
+
+        let mut exprs = Vec::with_capacity(pyo3::types::PyTupleMethods::len(args));
+
+        for arg in pyo3::types::PyTupleMethods::iter(args) {
+            exprs.push(Self::try_from(arg)?.inner);
+        }
+
+        let func = sea_query::Func::cust(sea_query::Alias::new(name)).args(exprs);
+        Ok(sea_query::SimpleExpr::FunctionCall(func).into())
+    }
+
+    #[classmethod]
+    #[pyo3(signature=(width, expr, origin=None))]
+    fn time_bucket(
+        _cls: &pyo3::Bound<'_, pyo3::types::PyType>,
+        width: String,
+        expr: pyo3::Bound<'_, pyo3::PyAny>,
+        origin: Option<pyo3::Bound<'_, pyo3::PyAny>>,
+    ) -> pyo3::PyResult<Self> {
+        if !is_valid_bucket_width(&width) {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "invalid time bucket width: {width:?}"
+            )));
+        }
+
+        let mut func = sea_query::Func::cust(sea_query::Alias::new("time_bucket"));
+        func = func.arg(sea_query::SimpleExpr::Constant(width.into()));
+        func = func.arg(Self::try_from(expr)?.inner);
+
+        if let Some(origin) = origin {
+            func = func.arg(Self::try_from(origin)?.inner);
+        }
+
+        Ok(sea_query::SimpleExpr::FunctionCall(func).into())
+    }
+
     #[classmethod]
     fn exists(
         cls: &pyo3::Bound<'_, pyo3::types::PyType>,
@@ -375,15 +702,146 @@ impl PyExpr {
         }
     }
 
-    fn cast_as(slf: pyo3::PyRef<'_, Self>, value: String) -> Self {
-        slf.inner.clone().cast_as(sea_query::Alias::new(value)).into()
+    #[classmethod]
+    fn any_array(
+        _cls: &pyo3::Bound<'_, pyo3::types::PyType>,
+        values: Vec<pyo3::Bound<'_, pyo3::PyAny>>,
+    ) -> pyo3::PyResult<Self> {
+        Ok(Self::quantified_array("ANY", values)?.into())
+    }
+
+    #[classmethod]
+    fn all_array(
+        _cls: &pyo3::Bound<'_, pyo3::types::PyType>,
+        values: Vec<pyo3::Bound<'_, pyo3::PyAny>>,
+    ) -> pyo3::PyResult<Self> {
+        Ok(Self::quantified_array("ALL", values)?.into())
+    }
+
+    #[classmethod]
+    #[pyo3(signature=(array, element_type=None))]
+    fn any_of(
+        _cls: &pyo3::Bound<'_, pyo3::types::PyType>,
+        array: &pyo3::Bound<'_, pyo3::PyAny>,
+        element_type: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+    ) -> pyo3::PyResult<Self> {
+        Ok(Self::quantified_array_expr("ANY", array, element_type)?.into())
+    }
+
+    #[classmethod]
+    #[pyo3(signature=(array, element_type=None))]
+    fn all_of(
+        _cls: &pyo3::Bound<'_, pyo3::types::PyType>,
+        array: &pyo3::Bound<'_, pyo3::PyAny>,
+        element_type: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+    ) -> pyo3::PyResult<Self> {
+        Ok(Self::quantified_array_expr("ALL", array, element_type)?.into())
+    }
+
+    #[classmethod]
+    #[pyo3(signature=(**pairs))]
+    fn json_build_object(
+        _cls: &pyo3::Bound<'_, pyo3::types::PyType>,
+        pairs: Option<&pyo3::Bound<'_, pyo3::types::PyDict>>,
+    ) -> pyo3::PyResult<Self> {
+        use pyo3::types::PyDictMethods;
+
+        let mut args = Vec::with_capacity(pairs.map_or(0, |x| x.len() * 2));
+
+        if let Some(pairs) = pairs {
+            for (key, value) in pairs.iter() {
+                // Keys are written inline, since PostgreSQL can't infer the type of a parameter
+                // here
+                let key = key.extract::<String>()?;
+                args.push(sea_query::SimpleExpr::Constant(key.into()));
+                args.push(Self::try_from(value)?.inner);
+            }
+        }
+
+        let func = sea_query::Func::cust(sea_query::Alias::new(crate::backend::JSON_BUILD_OBJECT)).args(args);
+        Ok(sea_query::SimpleExpr::from(func).into())
+    }
+
+    #[classmethod]
+    fn json_agg(
+        _cls: &pyo3::Bound<'_, pyo3::types::PyType>,
+        expr: &pyo3::Bound<'_, pyo3::PyAny>,
+    ) -> pyo3::PyResult<Self> {
+        let expr = Self::try_from(expr.clone())?;
+        let func = sea_query::Func::cust(sea_query::Alias::new(crate::backend::JSON_AGG)).arg(expr.inner);
+        Ok(sea_query::SimpleExpr::from(func).into())
+    }
+
+    fn cast_as(slf: pyo3::PyRef<'_, Self>, value: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<Self> {
+        unsafe {
+            if pyo3::ffi::PyUnicode_CheckExact(value.as_ptr()) == 1 {
+                let x = value.extract::<&str>().unwrap_unchecked();
+                Self::check_type_name(x)?;
+
+                return Ok(slf.inner.clone().cast_as(sea_query::Alias::new(x)).into());
+            }
+        }
+
+        // Column types are rendered per backend, so that interval fields, array element
+        // types and enums are kept, which a type name can't do
+        let column_type = crate::column::convert::convert_to_column_type(value)
+            .ok_or_else(|| typeerror!("expected str or column type, got {}", value.py(), value.as_ptr()))?;
+
+        Ok(Self::cast_as_column_type(slf.inner.clone(), &column_type))
+    }
+
+    fn cast_array(
+        slf: pyo3::PyRef<'_, Self>,
+        element_type: &pyo3::Bound<'_, pyo3::PyAny>,
+    ) -> pyo3::PyResult<Self> {
+        let column_type = Self::array_column_type(element_type)?;
+        Ok(Self::cast_as_column_type(slf.inner.clone(), &column_type))
+    }
+
+    fn pg_cast(slf: pyo3::PyRef<'_, Self>, value: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<Self> {
+        let name = unsafe {
+            if pyo3::ffi::PyUnicode_CheckExact(value.as_ptr()) == 1 {
+                let name = value.extract::<String>().unwrap_unchecked();
+                Self::check_type_name(&name)?;
+                name
+            } else {
+                let column_type = crate::column::convert::convert_to_column_type(value).ok_or_else(|| {
+                    typeerror!("expected str or column type, got {}", value.py(), value.as_ptr())
+                })?;
+                let [name, _, _] = crate::backend::cast_type_names(&column_type);
+
+                if name.is_empty() {
+                    return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "{} is not available in PostgreSQL",
+                        value.repr()?
+                    )));
+                }
+                name
+            }
+        };
+
+        Ok(sea_query::SimpleExpr::Binary(
+            Box::new(slf.inner.clone()),
+            sea_query::BinOper::Custom(crate::backend::PG_CAST),
+            Box::new(sea_query::SimpleExpr::Custom(name)),
+        )
+        .into())
+    }
+
+    fn to_bool(slf: pyo3::PyRef<'_, Self>) -> Self {
+        sea_query::SimpleExpr::Binary(
+            Box::new(slf.inner.clone()),
+            sea_query::BinOper::Custom(crate::backend::TO_BOOL),
+            Box::new(sea_query::SimpleExpr::Constant(0i32.into())),
+        )
+        .into()
     }
 
     #[pyo3(signature=(pattern, escape=None))]
-    fn like(slf: pyo3::PyRef<'_, Self>, pattern: String, escape: Option<char>) -> Self {
+    fn like(slf: pyo3::PyRef<'_, Self>, pattern: String, escape: Option<LikeEscape>) -> Self {
         let e = sea_query::LikeExpr::new(pattern);
 
-        if let Some(x) = escape {
+        if let Some(LikeEscape(x)) = escape {
             sea_query::ExprTrait::like(slf.inner.clone(), e.escape(x)).into()
         } else {
             sea_query::ExprTrait::like(slf.inner.clone(), e).into()
@@ -391,16 +849,40 @@ impl PyExpr {
     }
 
     #[pyo3(signature=(pattern, escape=None))]
-    fn not_like(slf: pyo3::PyRef<'_, Self>, pattern: String, escape: Option<char>) -> Self {
+    fn not_like(slf: pyo3::PyRef<'_, Self>, pattern: String, escape: Option<LikeEscape>) -> Self {
         let e = sea_query::LikeExpr::new(pattern);
 
-        if let Some(x) = escape {
+        if let Some(LikeEscape(x)) = escape {
             sea_query::ExprTrait::not_like(slf.inner.clone(), e.escape(x)).into()
         } else {
             sea_query::ExprTrait::not_like(slf.inner.clone(), e).into()
         }
     }
 
+    #[pyo3(signature=(pattern, escape=None))]
+    fn similar_to(slf: pyo3::PyRef<'_, Self>, pattern: String, escape: Option<LikeEscape>) -> Self {
+        let mut e = sea_query::LikeExpr::new(pattern);
+
+        if let Some(LikeEscape(x)) = escape {
+            e = e.escape(x);
+        }
+
+        let op = sea_query::BinOper::Custom(crate::backend::SIMILAR_TO);
+        sea_query::ExprTrait::binary(slf.inner.clone(), op, e).into()
+    }
+
+    #[pyo3(signature=(pattern, escape=None))]
+    fn not_similar_to(slf: pyo3::PyRef<'_, Self>, pattern: String, escape: Option<LikeEscape>) -> Self {
+        let mut e = sea_query::LikeExpr::new(pattern);
+
+        if let Some(LikeEscape(x)) = escape {
+            e = e.escape(x);
+        }
+
+        let op = sea_query::BinOper::Custom(crate::backend::NOT_SIMILAR_TO);
+        sea_query::ExprTrait::binary(slf.inner.clone(), op, e).into()
+    }
+
     fn __eq__<'a>(slf: pyo3::PyRef<'a, Self>, other: &pyo3::Bound<'a, pyo3::PyAny>) -> pyo3::PyResult<Self> {
         let other = Self::try_from(other.clone())?;
         Ok(sea_query::ExprTrait::eq(slf.inner.clone(), other.inner).into())
@@ -465,6 +947,15 @@ impl PyExpr {
         Ok(sea_query::ExprTrait::bit_or(slf.inner.clone(), other.inner).into())
     }
 
+    fn bit_not(&self) -> Self {
+        // A custom function named `~` renders as `~(expr)`, which is the bitwise
+        // complement on every backend we support.
+        sea_query::SimpleExpr::FunctionCall(
+            sea_query::Func::cust(sea_query::Alias::new("~")).arg(self.inner.clone()),
+        )
+        .into()
+    }
+
     fn __truediv__<'a>(
         slf: pyo3::PyRef<'a, Self>,
         other: &pyo3::Bound<'a, pyo3::PyAny>,
@@ -473,6 +964,19 @@ impl PyExpr {
         Ok(sea_query::ExprTrait::div(slf.inner.clone(), other.inner).into())
     }
 
+    fn __floordiv__<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        other: &pyo3::Bound<'a, pyo3::PyAny>,
+    ) -> pyo3::PyResult<Self> {
+        let other = Self::try_from(other.clone())?;
+        Ok(sea_query::ExprTrait::binary(
+            slf.inner.clone(),
+            sea_query::BinOper::Custom(crate::backend::FLOOR_DIV),
+            other.inner,
+        )
+        .into())
+    }
+
     fn is_<'a>(slf: pyo3::PyRef<'a, Self>, other: &pyo3::Bound<'a, pyo3::PyAny>) -> pyo3::PyResult<Self> {
         let other = Self::try_from(other.clone())?;
         Ok(sea_query::ExprTrait::is(slf.inner.clone(), other.inner).into())
@@ -483,6 +987,39 @@ impl PyExpr {
         Ok(sea_query::ExprTrait::is_not(slf.inner.clone(), other.inner).into())
     }
 
+    fn is_distinct_from<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        other: &pyo3::Bound<'a, pyo3::PyAny>,
+    ) -> pyo3::PyResult<Self> {
+        let other = Self::try_from(other.clone())?;
+        Ok(sea_query::ExprTrait::binary(
+            slf.inner.clone(),
+            sea_query::BinOper::Custom(crate::backend::IS_DISTINCT_FROM),
+            other.inner,
+        )
+        .into())
+    }
+
+    fn is_not_distinct_from<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        other: &pyo3::Bound<'a, pyo3::PyAny>,
+    ) -> pyo3::PyResult<Self> {
+        let other = Self::try_from(other.clone())?;
+        Ok(sea_query::ExprTrait::binary(
+            slf.inner.clone(),
+            sea_query::BinOper::Custom(crate::backend::IS_NOT_DISTINCT_FROM),
+            other.inner,
+        )
+        .into())
+    }
+
+    fn eq_null_safe<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        other: &pyo3::Bound<'a, pyo3::PyAny>,
+    ) -> pyo3::PyResult<Self> {
+        Self::is_not_distinct_from(slf, other)
+    }
+
     fn is_null(slf: pyo3::PyRef<'_, Self>) -> Self {
         sea_query::ExprTrait::is_null(slf.inner.clone()).into()
     }
@@ -507,11 +1044,71 @@ impl PyExpr {
         Ok(sea_query::ExprTrait::right_shift(slf.inner.clone(), other.inner).into())
     }
 
+    #[pyo3(signature=(other, strict=false))]
+    fn lshift<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        other: &pyo3::Bound<'a, pyo3::PyAny>,
+        strict: bool,
+    ) -> pyo3::PyResult<Self> {
+        if !strict {
+            return Self::__lshift__(slf, other);
+        }
+
+        let other = Self::try_from(other.clone())?;
+        Ok(sea_query::SimpleExpr::Binary(
+            Box::new(slf.inner.clone()),
+            sea_query::BinOper::Custom(crate::backend::STRICT_LSHIFT),
+            Box::new(other.inner),
+        )
+        .into())
+    }
+
+    #[pyo3(signature=(other, strict=false))]
+    fn rshift<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        other: &pyo3::Bound<'a, pyo3::PyAny>,
+        strict: bool,
+    ) -> pyo3::PyResult<Self> {
+        if !strict {
+            return Self::__rshift__(slf, other);
+        }
+
+        let other = Self::try_from(other.clone())?;
+        Ok(sea_query::SimpleExpr::Binary(
+            Box::new(slf.inner.clone()),
+            sea_query::BinOper::Custom(crate::backend::STRICT_RSHIFT),
+            Box::new(other.inner),
+        )
+        .into())
+    }
+
     fn __mod__<'a>(slf: pyo3::PyRef<'a, Self>, other: &pyo3::Bound<'a, pyo3::PyAny>) -> pyo3::PyResult<Self> {
         let other = Self::try_from(other.clone())?;
         Ok(sea_query::ExprTrait::modulo(slf.inner.clone(), other.inner).into())
     }
 
+    fn power<'a>(slf: pyo3::PyRef<'a, Self>, other: &pyo3::Bound<'a, pyo3::PyAny>) -> pyo3::PyResult<Self> {
+        let other = Self::try_from(other.clone())?;
+
+        // `^` means XOR on MySQL, so `POWER()` is used on every backend
+        Ok(sea_query::SimpleExpr::FunctionCall(
+            sea_query::Func::cust(sea_query::Alias::new("POWER")).args([slf.inner.clone(), other.inner]),
+        )
+        .into())
+    }
+
+    fn __pow__<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        other: &pyo3::Bound<'a, pyo3::PyAny>,
+        modulo: Option<&pyo3::Bound<'a, pyo3::PyAny>>,
+    ) -> pyo3::PyResult<Self> {
+        if modulo.is_some_and(|x| !x.is_none()) {
+            return Err(typeerror!("pow() with a modulo argument is not supported",));
+        }
+
+        Self::power(slf, other)
+    }
+
     fn __mul__<'a>(slf: pyo3::PyRef<'a, Self>, other: &pyo3::Bound<'a, pyo3::PyAny>) -> pyo3::PyResult<Self> {
         let other = Self::try_from(other.clone())?;
         Ok(sea_query::ExprTrait::mul(slf.inner.clone(), other.inner).into())
@@ -598,10 +1195,10 @@ impl PyExpr {
     }
 
     #[pyo3(signature=(pattern, escape=None))]
-    fn pg_ilike(slf: pyo3::PyRef<'_, Self>, pattern: String, escape: Option<char>) -> Self {
+    fn pg_ilike(slf: pyo3::PyRef<'_, Self>, pattern: String, escape: Option<LikeEscape>) -> Self {
         let e = sea_query::LikeExpr::new(pattern);
 
-        if let Some(x) = escape {
+        if let Some(LikeEscape(x)) = escape {
             sea_query::extension::postgres::PgExpr::ilike(slf.inner.clone(), e.escape(x)).into()
         } else {
             sea_query::extension::postgres::PgExpr::ilike(slf.inner.clone(), e).into()
@@ -609,34 +1206,80 @@ impl PyExpr {
     }
 
     #[pyo3(signature=(pattern, escape=None))]
-    fn pg_not_ilike(slf: pyo3::PyRef<'_, Self>, pattern: String, escape: Option<char>) -> Self {
+    fn pg_not_ilike(slf: pyo3::PyRef<'_, Self>, pattern: String, escape: Option<LikeEscape>) -> Self {
         let e = sea_query::LikeExpr::new(pattern);
 
-        if let Some(x) = escape {
+        if let Some(LikeEscape(x)) = escape {
             sea_query::extension::postgres::PgExpr::not_ilike(slf.inner.clone(), e.escape(x)).into()
         } else {
             sea_query::extension::postgres::PgExpr::not_ilike(slf.inner.clone(), e).into()
         }
     }
 
+    #[pyo3(signature=(a, b=OptionalParam::Undefined, *, open_bounds=false))]
     fn between<'a>(
         slf: pyo3::PyRef<'a, Self>,
         a: &pyo3::Bound<'a, pyo3::PyAny>,
-        b: &pyo3::Bound<'a, pyo3::PyAny>,
+        b: OptionalParam<'_, 'a>,
+        open_bounds: bool,
+    ) -> pyo3::PyResult<Self> {
+        let (a, b) = Self::between_bound_objects(a, b)?;
+
+        // With `open_bounds`, a None bound leaves that side of the range unlimited
+        // instead of making the whole comparison NULL
+        let (low, high) = if open_bounds {
+            (Some(a).filter(|x| !x.is_none()), Some(b).filter(|x| !x.is_none()))
+        } else {
+            (Some(a), Some(b))
+        };
+
+        let expr = match (low, high) {
+            (Some(low), Some(high)) => sea_query::ExprTrait::between(
+                slf.inner.clone(),
+                Self::try_from(low)?.inner,
+                Self::try_from(high)?.inner,
+            ),
+            (Some(low), None) => sea_query::ExprTrait::gte(slf.inner.clone(), Self::try_from(low)?.inner),
+            (None, Some(high)) => sea_query::ExprTrait::lte(slf.inner.clone(), Self::try_from(high)?.inner),
+            (None, None) => {
+                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "at least one of the bounds must not be None",
+                ));
+            }
+        };
+
+        Ok(expr.into())
+    }
+
+    #[pyo3(signature=(start, end=OptionalParam::Undefined))]
+    fn overlaps<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        start: &pyo3::Bound<'a, pyo3::PyAny>,
+        end: OptionalParam<'_, 'a>,
     ) -> pyo3::PyResult<Self> {
-        let a = Self::try_from(a.clone())?;
-        let b = Self::try_from(b.clone())?;
+        if !matches!(&slf.inner, sea_query::SimpleExpr::Tuple(items) if items.len() == 2) {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "overlaps() must be called on a (start, end) row, e.g. Expr.tuple([start, end])",
+            ));
+        }
 
-        Ok(sea_query::ExprTrait::between(slf.inner.clone(), a.inner, b.inner).into())
+        let (start, end) = Self::between_bounds(start, end)?;
+
+        Ok(sea_query::SimpleExpr::Binary(
+            Box::new(slf.inner.clone()),
+            sea_query::BinOper::Custom(crate::backend::OVERLAPS),
+            Box::new(sea_query::Expr::tuple([start.inner, end.inner]).into()),
+        )
+        .into())
     }
 
+    #[pyo3(signature=(a, b=OptionalParam::Undefined))]
     fn not_between<'a>(
         slf: pyo3::PyRef<'a, Self>,
         a: &pyo3::Bound<'a, pyo3::PyAny>,
-        b: &pyo3::Bound<'a, pyo3::PyAny>,
+        b: OptionalParam<'_, 'a>,
     ) -> pyo3::PyResult<Self> {
-        let a = Self::try_from(a.clone())?;
-        let b = Self::try_from(b.clone())?;
+        let (a, b) = Self::between_bounds(a, b)?;
 
         Ok(sea_query::ExprTrait::not_between(slf.inner.clone(), a.inner, b.inner).into())
     }
@@ -676,36 +1319,73 @@ impl PyExpr {
         }
     }
 
-    fn in_(slf: pyo3::PyRef<'_, Self>, other: Vec<pyo3::Py<pyo3::PyAny>>) -> pyo3::PyResult<Self> {
-        if other.is_empty() {
-            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                "`other` parameter cannot be an empty sequence",
-            ));
+    #[pyo3(signature=(other, r#type=None, optimize_single=false))]
+    fn in_(
+        slf: pyo3::PyRef<'_, Self>,
+        other: &pyo3::Bound<'_, pyo3::PyAny>,
+        r#type: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+        optimize_single: bool,
+    ) -> pyo3::PyResult<Self> {
+        let mut exprs = Self::membership_values(other, r#type)?;
+
+        if optimize_single && exprs.len() == 1 {
+            let value = exprs.pop().unwrap();
+            return Ok(sea_query::ExprTrait::eq(slf.inner.clone(), value).into());
         }
+        Ok(sea_query::ExprTrait::is_in(slf.inner.clone(), exprs).into())
+    }
+
+    #[pyo3(signature=(other, r#type=None, optimize_single=false))]
+    fn not_in(
+        slf: pyo3::PyRef<'_, Self>,
+        other: &pyo3::Bound<'_, pyo3::PyAny>,
+        r#type: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+        optimize_single: bool,
+    ) -> pyo3::PyResult<Self> {
+        let mut exprs = Self::membership_values(other, r#type)?;
 
-        let mut exprs = Vec::with_capacity(other.len());
-        for exp in other.into_iter() {
-            let exp = Self::try_from(exp.into_bound(slf.py()))?;
-            exprs.push(exp.inner);
+        if optimize_single && exprs.len() == 1 {
+            let value = exprs.pop().unwrap();
+            return Ok(sea_query::ExprTrait::ne(slf.inner.clone(), value).into());
         }
+        Ok(sea_query::ExprTrait::is_not_in(slf.inner.clone(), exprs).into())
+    }
 
-        Ok(sea_query::ExprTrait::is_in(slf.inner.clone(), exprs).into())
+    fn distinct(&self) -> Self {
+        sea_query::SimpleExpr::FunctionCall(
+            sea_query::Func::cust(sea_query::Alias::new(crate::backend::DISTINCT)).arg(self.inner.clone()),
+        )
+        .into()
     }
 
-    fn not_in(slf: pyo3::PyRef<'_, Self>, other: Vec<pyo3::Py<pyo3::PyAny>>) -> pyo3::PyResult<Self> {
-        if other.is_empty() {
+    #[pyo3(signature=(cases, else_=None))]
+    fn case_when(
+        slf: pyo3::PyRef<'_, Self>,
+        cases: Vec<(pyo3::Py<pyo3::PyAny>, pyo3::Py<pyo3::PyAny>)>,
+        else_: Option<pyo3::Py<pyo3::PyAny>>,
+    ) -> pyo3::PyResult<Self> {
+        if cases.is_empty() {
             return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                "`other` parameter cannot be an empty sequence",
+                "`cases` parameter cannot be an empty sequence",
             ));
         }
 
-        let mut exprs = Vec::with_capacity(other.len());
-        for exp in other.into_iter() {
-            let exp = Self::try_from(exp.into_bound(slf.py()))?;
-            exprs.push(exp.inner);
+        let mut arms = Vec::with_capacity(cases.len() * 2 + 1);
+        for (value, result) in cases.into_iter() {
+            arms.push(Self::try_from(value.into_bound(slf.py()))?.inner);
+            arms.push(Self::try_from(result.into_bound(slf.py()))?.inner);
         }
 
-        Ok(sea_query::ExprTrait::is_not_in(slf.inner.clone(), exprs).into())
+        if let Some(x) = else_ {
+            arms.push(Self::try_from(x.into_bound(slf.py()))?.inner);
+        }
+
+        Ok(sea_query::SimpleExpr::Binary(
+            Box::new(slf.inner.clone()),
+            sea_query::BinOper::Custom(crate::backend::SIMPLE_CASE),
+            Box::new(sea_query::SimpleExpr::Tuple(arms)),
+        )
+        .into())
     }
 
     fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<String> {
@@ -722,3 +1402,52 @@ impl PyExpr {
         format!("<Expr {:?}>", self.inner)
     }
 }
+
+/// Checks an `Expr.func_call` name, which is rendered as is: an identifier
+/// which may be qualified by a schema, like `pg_catalog.now`.
+fn is_function_name(name: &str) -> bool {
+    let mut parts = name.split('.');
+    let is_identifier = |part: &str| {
+        part.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+    };
+
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(name), None, _) => is_identifier(name),
+        (Some(schema), Some(name), None) => is_identifier(schema) && is_identifier(name),
+        _ => false,
+    }
+}
+
+/// Checks a `time_bucket` width such as `"15 minutes"` or `"1 day 12 hours"`.
+///
+/// The width is rendered inline as a string literal, so only `<count> <unit>`
+/// pairs are accepted.
+fn is_valid_bucket_width(width: &str) -> bool {
+    const UNITS: [&str; 9] = [
+        "microsecond",
+        "millisecond",
+        "second",
+        "minute",
+        "hour",
+        "day",
+        "week",
+        "month",
+        "year",
+    ];
+
+    let parts = width.split_whitespace().collect::<Vec<_>>();
+    if parts.is_empty() || parts.len() % 2 != 0 {
+        return false;
+    }
+
+    parts.chunks_exact(2).all(|pair| {
+        let count = pair[0].parse::<u64>().is_ok_and(|x| x > 0);
+        let unit = pair[1].to_ascii_lowercase();
+        let unit = unit.strip_suffix('s').unwrap_or(&unit);
+
+        count && UNITS.contains(&unit)
+    })
+}
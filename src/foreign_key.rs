@@ -51,6 +51,45 @@ impl std::fmt::Display for ForeignKeyActionAlias {
     }
 }
 
+/// `MATCH` type of a foreign key, which decides how NULLs in composite keys are
+/// checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForeignKeyMatch {
+    Full,
+    Partial,
+    Simple,
+}
+
+impl FromStr for ForeignKeyMatch {
+    type Err = pyo3::PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.to_ascii_lowercase();
+
+        if lower == "full" {
+            Ok(Self::Full)
+        } else if lower == "partial" {
+            Ok(Self::Partial)
+        } else if lower == "simple" {
+            Ok(Self::Simple)
+        } else {
+            Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "unknown foreign key match type: {s}"
+            )))
+        }
+    }
+}
+
+impl std::fmt::Display for ForeignKeyMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Full => write!(f, "FULL"),
+            Self::Partial => write!(f, "PARTIAL"),
+            Self::Simple => write!(f, "SIMPLE"),
+        }
+    }
+}
+
 pub struct ForeignKeyInner {
     pub name: String,
 
@@ -64,6 +103,105 @@ pub struct ForeignKeyInner {
 
     pub on_delete: Option<ForeignKeyActionAlias>,
     pub on_update: Option<ForeignKeyActionAlias>,
+    pub r#match: Option<ForeignKeyMatch>,
+}
+
+/// Names of the foreign key columns `columns`, a sequence of `Column`,
+/// `ColumnRef` or `str`.
+///
+/// A table-qualified `ColumnRef` must be qualified by `table` (or its alias),
+/// the table the columns belong to, if it's known.
+fn column_names(
+    columns: &pyo3::Bound<'_, pyo3::PyAny>,
+    table: Option<&pyo3::Py<pyo3::PyAny>>,
+) -> pyo3::PyResult<Vec<String>> {
+    use pyo3::types::PyAnyMethods;
+
+    unsafe {
+        // A string is a sequence too, but of characters rather than columns
+        if pyo3::ffi::PyUnicode_Check(columns.as_ptr()) == 1 {
+            return Err(typeerror!(
+                "expected a sequence of columns, got {:?}",
+                columns.py(),
+                columns.as_ptr()
+            ));
+        }
+
+        let mut names = Vec::new();
+
+        for col in columns.try_iter()? {
+            let col = col?;
+
+            if pyo3::ffi::Py_TYPE(col.as_ptr()) == crate::typeref::COLUMN_TYPE {
+                let col = col.cast_into_unchecked::<crate::column::PyColumn>();
+                names.push(col.get().inner.lock().name.clone());
+            } else if pyo3::ffi::Py_TYPE(col.as_ptr()) == crate::typeref::COLUMN_REF_TYPE {
+                let col = col.cast_into_unchecked::<crate::common::PyColumnRef>();
+
+                let crate::common::ColumnNameOrAstrisk::Name(name) = &col.get().col else {
+                    return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "a foreign key can't reference an asterisk column",
+                    ));
+                };
+
+                if let (Some(qualifier), Some(table)) = (&col.get().table, table) {
+                    let table = table
+                        .cast_bound_unchecked::<crate::common::PyTableName>(columns.py())
+                        .get();
+                    let schema_matches = match (&col.get().schema, &table.schema) {
+                        (Some(x), Some(y)) => x == y,
+                        (Some(_), None) => false,
+                        (None, _) => true,
+                    };
+
+                    if table.alias.as_ref().unwrap_or(&table.name) != qualifier || !schema_matches {
+                        return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                            "column {} is qualified by {}, which is not the table of the foreign key columns",
+                            name.to_string(),
+                            qualifier.to_string(),
+                        )));
+                    }
+                }
+
+                names.push(name.to_string());
+            } else if pyo3::ffi::PyUnicode_CheckExact(col.as_ptr()) == 1 {
+                names.push(col.extract::<String>().unwrap_unchecked());
+            } else {
+                return Err(typeerror!(
+                    "expected Column, ColumnRef or str, got {:?}",
+                    col.py(),
+                    col.as_ptr()
+                ));
+            }
+        }
+
+        Ok(names)
+    }
+}
+
+/// Checks that `from_columns` and `to_columns` pair up, the n-th column
+/// referencing the n-th one.
+fn check_columns(from_columns: &[String], to_columns: &[String]) -> pyo3::PyResult<()> {
+    if from_columns.is_empty() {
+        return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "from_columns is empty",
+        ));
+    }
+    if to_columns.is_empty() {
+        return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "to_columns is empty",
+        ));
+    }
+
+    if from_columns.len() != to_columns.len() {
+        return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "from_columns and to_columns must have same length ({} != {})",
+            from_columns.len(),
+            to_columns.len()
+        )));
+    }
+
+    Ok(())
 }
 
 impl ForeignKeyInner {
@@ -73,14 +211,70 @@ impl ForeignKeyInner {
             to_table: self.to_table.clone_ref(py),
             to_columns: self.to_columns.clone(),
             from_table: self.from_table.as_ref().map(|x| x.clone_ref(py)),
-            from_columns: self.to_columns.clone(),
+            from_columns: self.from_columns.clone(),
             on_delete: self.on_delete,
             on_update: self.on_update,
+            r#match: self.r#match,
+        }
+    }
+
+    /// Fails if this foreign key has a `MATCH` type and `backend` isn't
+    /// PostgreSQL, which is the only one to support it.
+    pub(crate) fn check_backend(&self, backend: crate::backend::BackendKind) -> pyo3::PyResult<()> {
+        if self.r#match.is_some() && backend != crate::backend::BackendKind::Postgres {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "foreign key {:?} has a MATCH type, which {backend} doesn't support",
+                self.name
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Renders this foreign key as a constraint of a PostgreSQL `CREATE TABLE`
+    /// or `ALTER TABLE`, depending on `mode`.
+    ///
+    /// sea-query has no `MATCH` support, so the referenced columns are rendered
+    /// by it, and the `MATCH` type and the actions are written after them.
+    pub fn as_postgres_constraint(&self, py: pyo3::Python<'_>, mode: sea_query::Mode) -> String {
+        use sea_query::ForeignKeyBuilder;
+
+        let mut sql = String::new();
+        sea_query::PostgresQueryBuilder.prepare_foreign_key_create_statement_internal(
+            &self.as_reference_statement(py),
+            &mut sql,
+            mode,
+        );
+
+        if let Some(x) = self.r#match {
+            sql += &format!(" MATCH {x}");
+        }
+        if let Some(x) = self.on_delete {
+            sql += &format!(" ON DELETE {x}");
+        }
+        if let Some(x) = self.on_update {
+            sql += &format!(" ON UPDATE {x}");
         }
+
+        sql
     }
 
     #[optimize(speed)]
     pub fn as_statement(&self, py: pyo3::Python<'_>) -> sea_query::ForeignKeyCreateStatement {
+        let mut stmt = self.as_reference_statement(py);
+
+        if let Some(x) = self.on_delete {
+            stmt.on_delete(x.into());
+        }
+        if let Some(x) = self.on_update {
+            stmt.on_update(x.into());
+        }
+
+        stmt
+    }
+
+    /// Same as [`ForeignKeyInner::as_statement`], without the actions.
+    fn as_reference_statement(&self, py: pyo3::Python<'_>) -> sea_query::ForeignKeyCreateStatement {
         let mut stmt = sea_query::ForeignKeyCreateStatement::new();
 
         stmt.name(&self.name);
@@ -105,13 +299,6 @@ impl ForeignKeyInner {
             stmt.to_col(sea_query::Alias::new(c));
         }
 
-        if let Some(x) = self.on_delete {
-            stmt.on_delete(x.into());
-        }
-        if let Some(x) = self.on_update {
-            stmt.on_update(x.into());
-        }
-
         stmt
     }
 }
@@ -132,17 +319,19 @@ impl PyForeignKey {
             from_table=None,
             name=None,
             on_delete=None,
-            on_update=None
+            on_update=None,
+            r#match=None
         )
     )]
     fn new(
-        from_columns: Vec<String>,
-        to_columns: Vec<String>,
+        from_columns: &pyo3::Bound<'_, pyo3::PyAny>,
+        to_columns: &pyo3::Bound<'_, pyo3::PyAny>,
         to_table: &pyo3::Bound<'_, pyo3::PyAny>,
         from_table: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
         name: Option<String>,
         on_delete: Option<String>,
         on_update: Option<String>,
+        r#match: Option<String>,
     ) -> pyo3::PyResult<Self> {
         let py = to_table.py();
 
@@ -156,6 +345,11 @@ impl PyForeignKey {
             Some(x) => Some(ForeignKeyActionAlias::from_str(&x)?),
         };
 
+        let r#match = match r#match {
+            None => None,
+            Some(x) => Some(ForeignKeyMatch::from_str(&x)?),
+        };
+
         let to_table: pyo3::Py<pyo3::PyAny> = crate::common::PyTableName::from_pyobject(to_table)?;
 
         let from_table: Option<pyo3::Py<pyo3::PyAny>> = {
@@ -165,6 +359,9 @@ impl PyForeignKey {
             }
         };
 
+        let from_columns = column_names(from_columns, from_table.as_ref())?;
+        let to_columns = column_names(to_columns, Some(&to_table))?;
+
         let name = match name {
             Some(x) => x,
             None => {
@@ -202,24 +399,7 @@ impl PyForeignKey {
             }
         };
 
-        if from_columns.is_empty() {
-            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                "from_columns is empty",
-            ));
-        }
-        if to_columns.is_empty() {
-            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                "to_columns is empty",
-            ));
-        }
-
-        if from_columns.len() != to_columns.len() {
-            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-                "from_columns and to_columns must have same length ({} != {})",
-                from_columns.len(),
-                to_columns.len()
-            )));
-        }
+        check_columns(&from_columns, &to_columns)?;
 
         Ok(Self {
             inner: parking_lot::Mutex::new(ForeignKeyInner {
@@ -230,6 +410,7 @@ impl PyForeignKey {
                 from_columns,
                 on_delete,
                 on_update,
+                r#match,
             }),
         })
     }
@@ -280,7 +461,14 @@ impl PyForeignKey {
     }
 
     #[setter]
-    fn set_from_columns(&self, val: Vec<String>) -> pyo3::PyResult<()> {
+    fn set_from_columns(&self, val: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<()> {
+        let from_table = self
+            .inner
+            .lock()
+            .from_table
+            .as_ref()
+            .map(|x| x.clone_ref(val.py()));
+        let val = column_names(val, from_table.as_ref())?;
         let mut lock = self.inner.lock();
 
         if val.is_empty() {
@@ -307,7 +495,9 @@ impl PyForeignKey {
     }
 
     #[setter]
-    fn set_to_columns(&self, val: Vec<String>) -> pyo3::PyResult<()> {
+    fn set_to_columns(&self, val: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<()> {
+        let to_table = self.inner.lock().to_table.clone_ref(val.py());
+        let val = column_names(val, Some(&to_table))?;
         let mut lock = self.inner.lock();
 
         if val.is_empty() {
@@ -328,6 +518,29 @@ impl PyForeignKey {
         Ok(())
     }
 
+    fn set_columns(
+        &self,
+        from_columns: &pyo3::Bound<'_, pyo3::PyAny>,
+        to_columns: &pyo3::Bound<'_, pyo3::PyAny>,
+    ) -> pyo3::PyResult<()> {
+        let (from_table, to_table) = {
+            let lock = self.inner.lock();
+            let py = from_columns.py();
+            (
+                lock.from_table.as_ref().map(|x| x.clone_ref(py)),
+                lock.to_table.clone_ref(py),
+            )
+        };
+        let from_columns = column_names(from_columns, from_table.as_ref())?;
+        let to_columns = column_names(to_columns, Some(&to_table))?;
+        check_columns(&from_columns, &to_columns)?;
+
+        let mut lock = self.inner.lock();
+        lock.from_columns = from_columns;
+        lock.to_columns = to_columns;
+        Ok(())
+    }
+
     #[getter]
     fn on_delete(&self) -> Option<String> {
         self.inner.lock().on_delete.map(|x| x.to_string())
@@ -364,6 +577,24 @@ impl PyForeignKey {
         Ok(())
     }
 
+    #[getter]
+    fn r#match(&self) -> Option<String> {
+        self.inner.lock().r#match.map(|x| x.to_string())
+    }
+
+    #[setter]
+    fn set_match(&self, val: Option<String>) -> pyo3::PyResult<()> {
+        let val = match val {
+            None => None,
+            Some(x) => Some(ForeignKeyMatch::from_str(&x)?),
+        };
+
+        let mut lock = self.inner.lock();
+        lock.r#match = val;
+
+        Ok(())
+    }
+
     fn __copy__(&self, py: pyo3::Python) -> Self {
         let lock = self.inner.lock();
 
@@ -402,6 +633,9 @@ impl PyForeignKey {
         if let Some(x) = &lock.on_update {
             write!(s, " on_update={:?}", x.to_string()).unwrap();
         }
+        if let Some(x) = &lock.r#match {
+            write!(s, " match={:?}", x.to_string()).unwrap();
+        }
         write!(s, ">").unwrap();
 
         unsafe { String::from_utf8_unchecked(s) }
@@ -0,0 +1,881 @@
+//! Formatting of generated SQL for reading, used by `to_sql(..., pretty=True)`
+//! and `to_sql(..., quote_identifiers=False)`.
+
+/// Keywords which start a new line when they appear outside of parentheses.
+///
+/// Longer keywords come first, so that e.g. `LEFT JOIN` isn't split before
+/// `JOIN`.
+const CLAUSE_KEYWORDS: &[&str] = &[
+    "ON DUPLICATE KEY UPDATE",
+    "FULL OUTER JOIN",
+    "INNER JOIN",
+    "RIGHT JOIN",
+    "CROSS JOIN",
+    "LEFT JOIN",
+    "ON CONFLICT",
+    "RETURNING",
+    "INTERSECT",
+    "GROUP BY",
+    "ORDER BY",
+    "EXCEPT",
+    "HAVING",
+    "OFFSET",
+    "SELECT",
+    "VALUES",
+    "WINDOW",
+    "LIMIT",
+    "UNION",
+    "WHERE",
+    "FROM",
+    "JOIN",
+    "SET",
+];
+
+/// Returns the clause keyword which starts at `rest`, if `rest` begins with one
+/// as a whole word.
+fn clause_keyword_at(rest: &str) -> Option<&'static str> {
+    CLAUSE_KEYWORDS.iter().copied().find(|keyword| {
+        rest.starts_with(keyword)
+            && rest[keyword.len()..]
+                .chars()
+                .next()
+                .is_none_or(|c| !c.is_ascii_alphanumeric() && c != '_')
+    })
+}
+
+/// Whether the `SET` followed by `rest` assigns values, as in UPDATE, rather
+/// than being part of an action such as `ON DELETE SET NULL` or `ALTER COLUMN
+/// ... SET DEFAULT`.
+fn is_set_clause(rest: &str) -> bool {
+    ![" NULL", " DEFAULT", " NOT NULL", " DATA TYPE"]
+        .iter()
+        .any(|x| rest.starts_with(x))
+}
+
+/// Formats `sql` with one clause per line (`SELECT`, `FROM`, `WHERE`,
+/// `GROUP BY`, ...).
+///
+/// Only the top level of each statement is split; subqueries and other
+/// parenthesized parts stay on one line, except the definitions of `CREATE
+/// TABLE`, which are put one per line. Quoted identifiers and string literals
+/// are never changed; `backslash_escapes` tells whether backslashes escape
+/// characters in string literals, as in [`unquote_identifiers`].
+pub fn format_sql(sql: &str, backslash_escapes: bool) -> String {
+    let mut out = String::with_capacity(sql.len() + sql.len() / 8);
+
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut create_table = false;
+    let mut statement_start = true;
+
+    let mut index = 0;
+    while index < sql.len() {
+        let rest = &sql[index..];
+        let c = rest.chars().next().unwrap();
+
+        if let Some(q) = quote {
+            out.push(c);
+            index += c.len_utf8();
+
+            if c == '\\' && q == '\'' && backslash_escapes {
+                // The escaped character can't close the literal
+                if let Some(next) = sql[index..].chars().next() {
+                    out.push(next);
+                    index += next.len_utf8();
+                }
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        if statement_start && !c.is_whitespace() {
+            statement_start = false;
+            create_table = rest.starts_with("CREATE TABLE") || rest.starts_with("CREATE TEMPORARY TABLE");
+        }
+
+        match c {
+            '\'' | '"' | '`' => {
+                quote = Some(c);
+                out.push(c);
+            }
+            '(' => {
+                depth += 1;
+                out.push(c);
+
+                if create_table && depth == 1 {
+                    out.push_str("\n    ");
+                    index += c.len_utf8();
+                    index += sql[index..].len() - sql[index..].trim_start_matches(' ').len();
+                    continue;
+                }
+            }
+            ')' => {
+                depth = depth.saturating_sub(1);
+
+                if create_table && depth == 0 {
+                    out.truncate(out.trim_end_matches(' ').len());
+                    out.push('\n');
+                }
+                out.push(c);
+            }
+            ',' if create_table && depth == 1 => {
+                out.push_str(",\n    ");
+                index += c.len_utf8();
+                index += sql[index..].len() - sql[index..].trim_start_matches(' ').len();
+                continue;
+            }
+            ';' => {
+                depth = 0;
+                statement_start = true;
+                out.push(c);
+            }
+            ' ' if depth == 0 => {
+                let next = &rest[1..];
+
+                match clause_keyword_at(next) {
+                    // `IS [NOT] DISTINCT FROM` is an operator rather than a clause, and
+                    // `DELETE FROM` reads better as one
+                    Some("FROM") if out.ends_with("DISTINCT") || out.ends_with("DELETE") => out.push(c),
+                    // `SET NULL`, `SET DEFAULT`, ... of foreign key actions and ALTER COLUMN
+                    Some("SET") if !is_set_clause(&next[3..]) => out.push(c),
+                    Some(keyword) => {
+                        out.push('\n');
+                        out.push_str(keyword);
+                        index += 1 + keyword.len();
+                        continue;
+                    }
+                    None => out.push(c),
+                }
+            }
+            _ => out.push(c),
+        }
+
+        index += c.len_utf8();
+    }
+
+    out
+}
+
+/// Formats `sql` with [`format_sql`] and the conventions of `backend` if
+/// `pretty` is set, otherwise returns it unchanged.
+#[inline]
+pub fn format_if(
+    sql: String,
+    backend: &pyo3::Bound<'_, pyo3::PyAny>,
+    pretty: bool,
+) -> pyo3::PyResult<String> {
+    if !pretty {
+        return Ok(sql);
+    }
+
+    let backslash_escapes =
+        crate::backend::into_backend_kind(backend)? != crate::backend::BackendKind::Sqlite;
+    Ok(format_sql(&sql, backslash_escapes))
+}
+
+// The keyword lists are sorted, as they're searched with
+// [`slice::binary_search`].
+
+/// Keywords which PostgreSQL doesn't take as bare column names everywhere:
+/// the reserved ones, and those which can't be function, type or column names.
+const POSTGRES_KEYWORDS: &[&str] = &[
+    "all",
+    "analyse",
+    "analyze",
+    "and",
+    "any",
+    "array",
+    "as",
+    "asc",
+    "asymmetric",
+    "authorization",
+    "between",
+    "bigint",
+    "binary",
+    "bit",
+    "boolean",
+    "both",
+    "case",
+    "cast",
+    "char",
+    "character",
+    "check",
+    "coalesce",
+    "collate",
+    "collation",
+    "column",
+    "concurrently",
+    "constraint",
+    "create",
+    "cross",
+    "current_catalog",
+    "current_date",
+    "current_role",
+    "current_schema",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "dec",
+    "decimal",
+    "default",
+    "deferrable",
+    "desc",
+    "distinct",
+    "do",
+    "else",
+    "end",
+    "except",
+    "exists",
+    "extract",
+    "false",
+    "fetch",
+    "float",
+    "for",
+    "foreign",
+    "freeze",
+    "from",
+    "full",
+    "grant",
+    "greatest",
+    "group",
+    "grouping",
+    "having",
+    "ilike",
+    "in",
+    "initially",
+    "inner",
+    "inout",
+    "int",
+    "integer",
+    "intersect",
+    "interval",
+    "into",
+    "is",
+    "isnull",
+    "join",
+    "json",
+    "json_array",
+    "json_arrayagg",
+    "json_exists",
+    "json_object",
+    "json_objectagg",
+    "json_query",
+    "json_scalar",
+    "json_serialize",
+    "json_table",
+    "json_value",
+    "lateral",
+    "leading",
+    "least",
+    "left",
+    "like",
+    "limit",
+    "localtime",
+    "localtimestamp",
+    "merge_action",
+    "national",
+    "natural",
+    "nchar",
+    "none",
+    "normalize",
+    "not",
+    "notnull",
+    "null",
+    "nullif",
+    "numeric",
+    "offset",
+    "on",
+    "only",
+    "or",
+    "order",
+    "out",
+    "outer",
+    "overlaps",
+    "overlay",
+    "placing",
+    "position",
+    "precision",
+    "primary",
+    "real",
+    "references",
+    "returning",
+    "right",
+    "row",
+    "select",
+    "session_user",
+    "setof",
+    "similar",
+    "smallint",
+    "some",
+    "substring",
+    "symmetric",
+    "system_user",
+    "table",
+    "tablesample",
+    "then",
+    "time",
+    "timestamp",
+    "to",
+    "trailing",
+    "treat",
+    "trim",
+    "true",
+    "union",
+    "unique",
+    "user",
+    "using",
+    "values",
+    "varchar",
+    "variadic",
+    "verbose",
+    "when",
+    "where",
+    "window",
+    "with",
+    "xmlattributes",
+    "xmlconcat",
+    "xmlelement",
+    "xmlexists",
+    "xmlforest",
+    "xmlnamespaces",
+    "xmlparse",
+    "xmlpi",
+    "xmlroot",
+    "xmlserialize",
+    "xmltable",
+];
+
+/// Reserved words of MySQL 8, which must be quoted as identifiers.
+const MYSQL_KEYWORDS: &[&str] = &[
+    "accessible",
+    "add",
+    "all",
+    "alter",
+    "analyze",
+    "and",
+    "as",
+    "asc",
+    "asensitive",
+    "before",
+    "bernoulli",
+    "between",
+    "bigint",
+    "binary",
+    "blob",
+    "both",
+    "by",
+    "call",
+    "cascade",
+    "case",
+    "change",
+    "char",
+    "character",
+    "check",
+    "collate",
+    "column",
+    "condition",
+    "constraint",
+    "continue",
+    "convert",
+    "create",
+    "cross",
+    "cube",
+    "cume_dist",
+    "current_date",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "cursor",
+    "database",
+    "databases",
+    "day_hour",
+    "day_microsecond",
+    "day_minute",
+    "day_second",
+    "dec",
+    "decimal",
+    "declare",
+    "default",
+    "delayed",
+    "delete",
+    "dense_rank",
+    "desc",
+    "describe",
+    "deterministic",
+    "distinct",
+    "distinctrow",
+    "div",
+    "double",
+    "drop",
+    "dual",
+    "each",
+    "else",
+    "elseif",
+    "empty",
+    "enclosed",
+    "escaped",
+    "except",
+    "exists",
+    "exit",
+    "explain",
+    "false",
+    "fetch",
+    "first_value",
+    "float",
+    "float4",
+    "float8",
+    "for",
+    "force",
+    "foreign",
+    "from",
+    "fulltext",
+    "function",
+    "generated",
+    "get",
+    "grant",
+    "group",
+    "grouping",
+    "groups",
+    "having",
+    "high_priority",
+    "hour_microsecond",
+    "hour_minute",
+    "hour_second",
+    "if",
+    "ignore",
+    "in",
+    "index",
+    "infile",
+    "inner",
+    "inout",
+    "insensitive",
+    "insert",
+    "int",
+    "int1",
+    "int2",
+    "int3",
+    "int4",
+    "int8",
+    "integer",
+    "intersect",
+    "interval",
+    "into",
+    "io_after_gtids",
+    "io_before_gtids",
+    "is",
+    "iterate",
+    "join",
+    "json_table",
+    "key",
+    "keys",
+    "kill",
+    "lag",
+    "last_value",
+    "lateral",
+    "lead",
+    "leading",
+    "leave",
+    "left",
+    "library",
+    "like",
+    "limit",
+    "linear",
+    "lines",
+    "load",
+    "localtime",
+    "localtimestamp",
+    "lock",
+    "long",
+    "longblob",
+    "longtext",
+    "loop",
+    "low_priority",
+    "manual",
+    "master_bind",
+    "master_ssl_verify_server_cert",
+    "match",
+    "maxvalue",
+    "mediumblob",
+    "mediumint",
+    "mediumtext",
+    "middleint",
+    "minute_microsecond",
+    "minute_second",
+    "mod",
+    "modifies",
+    "natural",
+    "no_write_to_binlog",
+    "not",
+    "nth_value",
+    "ntile",
+    "null",
+    "numeric",
+    "of",
+    "on",
+    "optimize",
+    "optimizer_costs",
+    "option",
+    "optionally",
+    "or",
+    "order",
+    "out",
+    "outer",
+    "outfile",
+    "over",
+    "parallel",
+    "partition",
+    "percent_rank",
+    "precision",
+    "primary",
+    "procedure",
+    "purge",
+    "qualify",
+    "range",
+    "rank",
+    "read",
+    "read_write",
+    "reads",
+    "real",
+    "recursive",
+    "references",
+    "regexp",
+    "release",
+    "rename",
+    "repeat",
+    "replace",
+    "require",
+    "resignal",
+    "restrict",
+    "return",
+    "revoke",
+    "right",
+    "rlike",
+    "row",
+    "row_number",
+    "rows",
+    "schema",
+    "schemas",
+    "second_microsecond",
+    "select",
+    "sensitive",
+    "separator",
+    "set",
+    "show",
+    "signal",
+    "smallint",
+    "spatial",
+    "specific",
+    "sql",
+    "sql_big_result",
+    "sql_calc_found_rows",
+    "sql_small_result",
+    "sqlexception",
+    "sqlstate",
+    "sqlwarning",
+    "ssl",
+    "starting",
+    "stored",
+    "straight_join",
+    "system",
+    "table",
+    "tablesample",
+    "terminated",
+    "then",
+    "tinyblob",
+    "tinyint",
+    "tinytext",
+    "to",
+    "trailing",
+    "trigger",
+    "true",
+    "undo",
+    "union",
+    "unique",
+    "unlock",
+    "unsigned",
+    "update",
+    "usage",
+    "use",
+    "using",
+    "utc_date",
+    "utc_time",
+    "utc_timestamp",
+    "values",
+    "varbinary",
+    "varchar",
+    "varcharacter",
+    "varying",
+    "virtual",
+    "when",
+    "where",
+    "while",
+    "window",
+    "with",
+    "write",
+    "xor",
+    "year_month",
+    "zerofill",
+];
+
+/// Keywords of SQLite, plus `true` and `false`.
+///
+/// SQLite takes most keywords as identifiers where they aren't ambiguous, but
+/// which ones depends on the context, so all of them are quoted.
+const SQLITE_KEYWORDS: &[&str] = &[
+    "abort",
+    "action",
+    "add",
+    "after",
+    "all",
+    "alter",
+    "always",
+    "analyze",
+    "and",
+    "as",
+    "asc",
+    "attach",
+    "autoincrement",
+    "before",
+    "begin",
+    "between",
+    "by",
+    "cascade",
+    "case",
+    "cast",
+    "check",
+    "collate",
+    "column",
+    "commit",
+    "conflict",
+    "constraint",
+    "create",
+    "cross",
+    "current",
+    "current_date",
+    "current_time",
+    "current_timestamp",
+    "database",
+    "default",
+    "deferrable",
+    "deferred",
+    "delete",
+    "desc",
+    "detach",
+    "distinct",
+    "do",
+    "drop",
+    "each",
+    "else",
+    "end",
+    "escape",
+    "except",
+    "exclude",
+    "exclusive",
+    "exists",
+    "explain",
+    "fail",
+    "false",
+    "filter",
+    "first",
+    "following",
+    "for",
+    "foreign",
+    "from",
+    "full",
+    "generated",
+    "glob",
+    "group",
+    "groups",
+    "having",
+    "if",
+    "ignore",
+    "immediate",
+    "in",
+    "index",
+    "indexed",
+    "initially",
+    "inner",
+    "insert",
+    "instead",
+    "intersect",
+    "into",
+    "is",
+    "isnull",
+    "join",
+    "key",
+    "last",
+    "left",
+    "like",
+    "limit",
+    "match",
+    "materialized",
+    "natural",
+    "no",
+    "not",
+    "nothing",
+    "notnull",
+    "null",
+    "nulls",
+    "of",
+    "offset",
+    "on",
+    "or",
+    "order",
+    "others",
+    "outer",
+    "over",
+    "partition",
+    "plan",
+    "pragma",
+    "preceding",
+    "primary",
+    "query",
+    "raise",
+    "range",
+    "recursive",
+    "references",
+    "regexp",
+    "reindex",
+    "release",
+    "rename",
+    "replace",
+    "restrict",
+    "returning",
+    "right",
+    "rollback",
+    "row",
+    "rows",
+    "savepoint",
+    "select",
+    "set",
+    "table",
+    "temp",
+    "temporary",
+    "then",
+    "ties",
+    "to",
+    "transaction",
+    "trigger",
+    "true",
+    "unbounded",
+    "union",
+    "unique",
+    "update",
+    "using",
+    "vacuum",
+    "values",
+    "view",
+    "virtual",
+    "when",
+    "where",
+    "window",
+    "with",
+    "without",
+];
+
+/// Returns the keywords which `backend` can't take as bare identifiers.
+fn reserved_keywords(backend: crate::backend::BackendKind) -> &'static [&'static str] {
+    match backend {
+        crate::backend::BackendKind::Postgres => POSTGRES_KEYWORDS,
+        crate::backend::BackendKind::Mysql => MYSQL_KEYWORDS,
+        crate::backend::BackendKind::Sqlite => SQLITE_KEYWORDS,
+    }
+}
+
+/// Whether `name` has to stay quoted to be read back as the same identifier.
+///
+/// Bare identifiers are limited to lowercase ASCII letters, digits and
+/// underscores, not starting with a digit, since PostgreSQL folds unquoted
+/// names to lowercase, and can't be one of the `keywords` of the backend.
+fn needs_quoting(name: &str, keywords: &[&str]) -> bool {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(c) if c.is_ascii_lowercase() || c == '_' => (),
+        _ => return true,
+    }
+
+    chars.any(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'))
+        || keywords.binary_search(&name).is_ok()
+}
+
+/// Removes the quotes around the identifiers of `sql` which don't need them.
+///
+/// `quote` is the identifier quote of the backend, `backslash_escapes` tells
+/// whether backslashes escape characters in its string literals, and
+/// `keywords` are its reserved keywords. String literals are copied as-is.
+pub fn unquote_identifiers(sql: &str, quote: char, backslash_escapes: bool, keywords: &[&str]) -> String {
+    let mut out = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            out.push(c);
+
+            while let Some(c) = chars.next() {
+                out.push(c);
+
+                if c == '\\' && backslash_escapes {
+                    if let Some(next) = chars.next() {
+                        out.push(next);
+                    }
+                } else if c == '\'' {
+                    break;
+                }
+            }
+        } else if c == quote {
+            let mut name = String::new();
+
+            while let Some(c) = chars.next() {
+                if c == quote {
+                    // A doubled quote stands for itself
+                    if chars.peek() != Some(&quote) {
+                        break;
+                    }
+                    chars.next();
+                }
+                name.push(c);
+            }
+
+            if needs_quoting(&name, keywords) {
+                out.push(quote);
+                for c in name.chars() {
+                    if c == quote {
+                        out.push(quote);
+                    }
+                    out.push(c);
+                }
+                out.push(quote);
+            } else {
+                out.push_str(&name);
+            }
+        } else {
+            out.push(c);
+        }
+    }
+
+    out
+}
+
+/// Applies [`unquote_identifiers`] with the conventions of `backend`, unless
+/// `quote_identifiers` is set.
+pub fn unquote_if(
+    sql: String,
+    backend: &pyo3::Bound<'_, pyo3::PyAny>,
+    quote_identifiers: bool,
+) -> pyo3::PyResult<String> {
+    if quote_identifiers {
+        return Ok(sql);
+    }
+
+    let kind = crate::backend::into_backend_kind(backend)?;
+    let keywords = reserved_keywords(kind);
+
+    let unquoted = match kind {
+        crate::backend::BackendKind::Sqlite => unquote_identifiers(&sql, '"', false, keywords),
+        crate::backend::BackendKind::Mysql => unquote_identifiers(&sql, '`', true, keywords),
+        crate::backend::BackendKind::Postgres => unquote_identifiers(&sql, '"', true, keywords),
+    };
+    Ok(unquoted)
+}
@@ -1,5 +1,5 @@
 use crate::backend::PySchemaStatement;
-use sea_query::IntoIden;
+use sea_query::{ConditionalStatement, IntoIden};
 
 #[derive(Debug, Clone)]
 pub struct IndexTypeAlias(sea_query::IndexType);
@@ -52,6 +52,7 @@ pub enum IndexOptions {
     Unique = 1 << 1,
     IfNotExists = 1 << 2,
     NullsNotDistinct = 1 << 3,
+    UniqueConstraint = 1 << 4,
 }
 
 pub struct IndexInner {
@@ -69,6 +70,22 @@ pub struct IndexInner {
     // Always is `Option<Expr>`
     pub r#where: Option<pyo3::Py<pyo3::PyAny>>,
     pub include: Vec<String>,
+
+    // PostgreSQL specific; ignored on other backends
+    pub tablespace: Option<String>,
+    pub fillfactor: Option<u8>,
+}
+
+/// Validates the fillfactor given by user; PostgreSQL accepts percentages from
+/// 10 to 100.
+fn check_fillfactor(val: Option<i64>) -> pyo3::PyResult<Option<u8>> {
+    match val {
+        Some(x) if (10..=100).contains(&x) => Ok(Some(x as u8)),
+        Some(x) => Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "fillfactor must be between 10 and 100, got {x}"
+        ))),
+        None => Ok(None),
+    }
 }
 
 impl IndexInner {
@@ -104,11 +121,149 @@ impl IndexInner {
             index_type: self.index_type.clone(),
             r#where: self.r#where.as_ref().map(|x| x.clone_ref(py)),
             include: self.include.clone(),
+            tablespace: self.tablespace.clone(),
+            fillfactor: self.fillfactor,
+        }
+    }
+
+    /// Whether this index is defined in `CREATE TABLE` rather than by its own
+    /// `CREATE INDEX`.
+    pub fn is_table_constraint(&self) -> bool {
+        self.options & (IndexOptions::Primary as u8 | IndexOptions::UniqueConstraint as u8) > 0
+    }
+
+    /// Whether this index has a tablespace or fillfactor.
+    #[inline]
+    pub fn has_storage_clause(&self) -> bool {
+        self.tablespace.is_some() || self.fillfactor.is_some()
+    }
+
+    /// Renders the `WITH (fillfactor=...)` and tablespace clauses of this
+    /// index, which only PostgreSQL has.
+    ///
+    /// Constraints of `CREATE TABLE` name the tablespace with
+    /// `USING INDEX TABLESPACE` instead of `TABLESPACE`.
+    fn storage_clause(&self) -> String {
+        use sea_query::{Iden, QuotedBuilder};
+
+        let mut clause = String::new();
+        if let Some(x) = self.fillfactor {
+            clause += &format!(" WITH (fillfactor={x})");
+        }
+        if let Some(x) = &self.tablespace {
+            clause.push_str(if self.is_table_constraint() {
+                " USING INDEX TABLESPACE "
+            } else {
+                " TABLESPACE "
+            });
+            sea_query::Alias::new(x).prepare(&mut clause, sea_query::PostgresQueryBuilder.quote());
+        }
+
+        clause
+    }
+
+    /// Renders this index as a constraint of a PostgreSQL `CREATE TABLE`.
+    ///
+    /// sea-query has no storage clauses, so they're written after the
+    /// constraint it renders.
+    pub(crate) fn as_postgres_constraint(&self, py: pyo3::Python<'_>) -> String {
+        use sea_query::IndexBuilder;
+
+        let mut sql = String::new();
+        sea_query::PostgresQueryBuilder
+            .prepare_table_index_expression(&self.as_unfiltered_statement(py), &mut sql);
+        sql += &self.storage_clause();
+        sql
+    }
+
+    /// Renders the `CREATE INDEX` statement of this index for `backend`.
+    ///
+    /// On PostgreSQL, the storage clauses come between the columns and the
+    /// `WHERE` predicate; sea-query has no support for them, so the index is
+    /// rendered without its predicate, which is written after them.
+    pub(crate) fn to_create_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<String> {
+        let py = backend.py();
+        let kind = crate::backend::into_backend_kind(backend)?;
+
+        if kind != crate::backend::BackendKind::Postgres || !self.has_storage_clause() {
+            let stmt = self.as_statement(py, kind)?;
+            return build_schema!(backend => build_any(stmt));
+        }
+
+        let stmt = self.as_unfiltered_statement(py);
+        let mut sql = build_schema!(backend => build_any(stmt))?;
+        sql += &self.storage_clause();
+
+        if let Some(x) = &self.r#where {
+            let x = unsafe { x.cast_bound_unchecked::<crate::expression::PyExpr>(py) };
+
+            if let sea_query::SimpleExpr::Custom(x) =
+                crate::backend::render_schema_expr(&x.get().inner, kind)?
+            {
+                sql += " WHERE ";
+                sql += &x;
+            }
+        }
+
+        Ok(sql)
+    }
+
+    /// Fails if a column has a prefix length and `backend` is PostgreSQL, which
+    /// has no syntax for it; sea-query would render the prefix anyway.
+    /// SQLite drops prefixes, and MySQL supports them.
+    ///
+    /// Also fails if this index has a tablespace or fillfactor and `backend`
+    /// isn't PostgreSQL, which is the only one to support them.
+    pub(crate) fn check_backend(
+        &self,
+        py: pyo3::Python,
+        backend: crate::backend::BackendKind,
+    ) -> pyo3::PyResult<()> {
+        if backend != crate::backend::BackendKind::Postgres {
+            if self.has_storage_clause() {
+                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "index {:?} has a tablespace or fillfactor, which {backend} doesn't support",
+                    self.name
+                )));
+            }
+
+            return Ok(());
+        }
+
+        for col in &self.columns {
+            let col = unsafe { col.cast_bound_unchecked::<crate::common::PyIndexColumn>(py) };
+            let col = col.get();
+
+            if col.prefix.is_some() {
+                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "index {:?} has a prefix length on column {:?}, which PostgreSQL doesn't support",
+                    self.name, col.name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Builds the index statement for `backend`, which renders the `WHERE`
+    /// predicate.
+    pub(crate) fn as_statement(
+        &self,
+        py: pyo3::Python,
+        backend: crate::backend::BackendKind,
+    ) -> pyo3::PyResult<sea_query::IndexCreateStatement> {
+        let mut stmt = self.as_unfiltered_statement(py);
+
+        if let Some(x) = &self.r#where {
+            let x = unsafe { x.cast_bound_unchecked::<crate::expression::PyExpr>(py) };
+            stmt.and_where(crate::backend::render_schema_expr(&x.get().inner, backend)?);
         }
+
+        Ok(stmt)
     }
 
     #[optimize(speed)]
-    pub fn as_statement(&self, py: pyo3::Python) -> sea_query::IndexCreateStatement {
+    fn as_unfiltered_statement(&self, py: pyo3::Python) -> sea_query::IndexCreateStatement {
         let mut stmt = sea_query::IndexCreateStatement::new();
 
         stmt.name(&self.name);
@@ -147,7 +302,7 @@ impl IndexInner {
         if self.options & (IndexOptions::Primary as u8) > 0 {
             stmt.primary();
         }
-        if self.options & (IndexOptions::Unique as u8) > 0 {
+        if self.options & (IndexOptions::Unique as u8 | IndexOptions::UniqueConstraint as u8) > 0 {
             stmt.unique();
         }
         if self.options & (IndexOptions::IfNotExists as u8) > 0 {
@@ -203,7 +358,10 @@ impl PyIndex {
             nulls_not_distinct=false,
             include=Vec::new(),
             index_type=None,
-            r#where=None
+            r#where=None,
+            unique_constraint=false,
+            tablespace=None,
+            fillfactor=None
         )
     )]
     fn new(
@@ -218,6 +376,9 @@ impl PyIndex {
         include: Vec<String>,
         index_type: Option<String>,
         r#where: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+        unique_constraint: bool,
+        tablespace: Option<String>,
+        fillfactor: Option<i64>,
     ) -> pyo3::PyResult<pyo3::PyClassInitializer<Self>> {
         let mut cols = Vec::with_capacity(columns.capacity());
         for col in columns {
@@ -249,7 +410,8 @@ impl PyIndex {
         let options = ((primary as u8) * (IndexOptions::Primary as u8))
             | ((unique as u8) * (IndexOptions::Unique as u8))
             | ((if_not_exists as u8) * (IndexOptions::IfNotExists as u8))
-            | ((nulls_not_distinct as u8) * (IndexOptions::NullsNotDistinct as u8));
+            | ((nulls_not_distinct as u8) * (IndexOptions::NullsNotDistinct as u8))
+            | ((unique_constraint as u8) * (IndexOptions::UniqueConstraint as u8));
 
         let mut inner = IndexInner {
             name,
@@ -259,6 +421,8 @@ impl PyIndex {
             index_type: index_type.map(|x| x.into()),
             r#where,
             include,
+            tablespace,
+            fillfactor: check_fillfactor(fillfactor)?,
         };
 
         if inner.name.is_empty() {
@@ -334,6 +498,21 @@ impl PyIndex {
         }
     }
 
+    #[getter]
+    fn unique_constraint(slf: pyo3::PyRef<'_, Self>) -> bool {
+        (slf.inner.lock().options & (IndexOptions::UniqueConstraint as u8)) > 0
+    }
+
+    #[setter]
+    fn set_unique_constraint(slf: pyo3::PyRef<'_, Self>, val: bool) {
+        let mut lock = slf.inner.lock();
+        if val {
+            lock.options |= IndexOptions::UniqueConstraint as u8;
+        } else {
+            lock.options &= !(IndexOptions::UniqueConstraint as u8);
+        }
+    }
+
     #[getter]
     fn if_not_exists(slf: pyo3::PyRef<'_, Self>) -> bool {
         (slf.inner.lock().options & (IndexOptions::IfNotExists as u8)) > 0
@@ -410,6 +589,44 @@ impl PyIndex {
         lock.include = val;
     }
 
+    #[getter]
+    fn tablespace(&self) -> Option<String> {
+        let lock = self.inner.lock();
+        lock.tablespace.clone()
+    }
+
+    #[setter]
+    fn set_tablespace(&self, val: Option<String>) {
+        let mut lock = self.inner.lock();
+        lock.tablespace = val;
+    }
+
+    #[getter]
+    fn fillfactor(&self) -> Option<u8> {
+        let lock = self.inner.lock();
+        lock.fillfactor
+    }
+
+    #[setter]
+    fn set_fillfactor(&self, val: Option<i64>) -> pyo3::PyResult<()> {
+        let fillfactor = check_fillfactor(val)?;
+
+        let mut lock = self.inner.lock();
+        lock.fillfactor = fillfactor;
+        Ok(())
+    }
+
+    #[getter]
+    fn predicate(&self, py: pyo3::Python) -> Option<pyo3::Py<pyo3::PyAny>> {
+        let lock = self.inner.lock();
+        lock.r#where.as_ref().map(|x| x.clone_ref(py))
+    }
+
+    fn is_partial(&self) -> bool {
+        let lock = self.inner.lock();
+        lock.r#where.is_some()
+    }
+
     fn __copy__(&self, py: pyo3::Python) -> pyo3::Py<Self> {
         let lock = self.inner.lock();
 
@@ -428,12 +645,16 @@ impl PyIndex {
         pyo3::Py::new(py, pyo3::PyClassInitializer::from((slf, PySchemaStatement))).unwrap()
     }
 
-    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<String> {
+    #[pyo3(signature=(backend, pretty=false))]
+    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>, pretty: bool) -> pyo3::PyResult<String> {
+        let kind = crate::backend::into_backend_kind(backend)?;
+
         let lock = self.inner.lock();
-        let stmt = lock.as_statement(backend.py());
+        lock.check_backend(backend.py(), kind)?;
+        let sql = lock.to_create_sql(backend)?;
         drop(lock);
 
-        build_schema!(backend => build_any(stmt))
+        crate::format::format_if(sql, backend, pretty)
     }
 
     fn __repr__(&self) -> String {
@@ -471,6 +692,9 @@ impl PyIndex {
         if lock.options & (IndexOptions::NullsNotDistinct as u8) > 0 {
             write!(s, " nulls_not_distinct=True").unwrap();
         }
+        if lock.options & (IndexOptions::UniqueConstraint as u8) > 0 {
+            write!(s, " unique_constraint=True").unwrap();
+        }
 
         if let Some(x) = &lock.index_type {
             write!(s, " index_type={:?}", x.to_string()).unwrap();
@@ -478,6 +702,12 @@ impl PyIndex {
         if !lock.include.is_empty() {
             write!(s, " include={:?}", lock.include).unwrap();
         }
+        if let Some(x) = &lock.tablespace {
+            write!(s, " tablespace={x:?}").unwrap();
+        }
+        if let Some(x) = lock.fillfactor {
+            write!(s, " fillfactor={x}").unwrap();
+        }
         if let Some(x) = &lock.r#where {
             write!(s, " where={x}").unwrap();
         }
@@ -619,12 +849,13 @@ impl PyDropIndex {
         }
     }
 
-    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<String> {
+    #[pyo3(signature=(backend, pretty=false))]
+    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>, pretty: bool) -> pyo3::PyResult<String> {
         let lock = self.inner.lock();
         let stmt = lock.as_statement(backend.py());
         drop(lock);
 
-        build_schema!(backend => build_any(stmt))
+        build_schema!(backend => build_any(stmt)).and_then(|x| crate::format::format_if(x, backend, pretty))
     }
 
     fn __repr__(&self) -> String {
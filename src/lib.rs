@@ -19,6 +19,7 @@ mod column;
 mod common;
 mod expression;
 mod foreign_key;
+mod format;
 mod index;
 mod query;
 mod table;
@@ -30,7 +31,7 @@ mod _lib {
     use pyo3::types::PyModuleMethods;
 
     #[pymodule_export]
-    use super::backend::{PyQueryStatement, PySchemaStatement};
+    use super::backend::{PyBackend, PyQueryStatement, PySchemaStatement};
 
     #[pymodule_export]
     use super::column::types::PyColumnTypeMeta;
@@ -46,7 +47,10 @@ mod _lib {
     };
 
     #[pymodule_export]
-    use super::adaptation::PyAdaptedValue;
+    use super::column::parse::column_type_from_string;
+
+    #[pymodule_export]
+    use super::adaptation::{adapt_many, PyAdaptedValue};
 
     #[pymodule_export]
     use super::common::{PyAsteriskType, PyColumnRef, PyIndexColumn, PyTableName};
@@ -65,10 +69,10 @@ mod _lib {
 
     #[pymodule_export]
     use super::table::{
-        PyAliasedTable, PyAlterTable, PyAlterTableAddColumnOption, PyAlterTableAddForeignKeyOption,
-        PyAlterTableDropColumnOption, PyAlterTableDropForeignKeyOption, PyAlterTableModifyColumnOption,
-        PyAlterTableOptionMeta, PyAlterTableRenameColumnOption, PyDropTable, PyRenameTable, PyTable,
-        PyTruncateTable, Py_AliasedTableColumnsSequence, Py_TableColumnsSequence,
+        build_schema_ordered, drop_all, PyAliasedTable, PyAlterTable, PyAlterTableAddColumnOption,
+        PyAlterTableAddForeignKeyOption, PyAlterTableDropColumnOption, PyAlterTableDropForeignKeyOption,
+        PyAlterTableModifyColumnOption, PyAlterTableOptionMeta, PyAlterTableRenameColumnOption, PyDropTable,
+        PyRenameTable, PyTable, PyTruncateTable, Py_AliasedTableColumnsSequence, Py_TableColumnsSequence,
     };
 
     #[pymodule_export]
@@ -83,6 +87,9 @@ mod _lib {
     #[pymodule_export]
     use super::query::select::{PySelect, PySelectCol};
 
+    #[pymodule_export]
+    use super::query::subquery::{PySubquery, Py_SubqueryColumnsSequence};
+
     #[pymodule_export]
     use super::query::on_conflict::PyOnConflict;
 
@@ -61,11 +61,13 @@ macro_rules! typeerror {
 macro_rules! prepare_sql {
     ($converter:expr => $backend:expr => $method:ident($value:expr, &mut $sql:expr)) => {{
         let builder = $converter($backend)?;
+        $crate::backend::clear_build_error();
 
         let assert_unwind = std::panic::AssertUnwindSafe(|| builder.$method($value, &mut $sql));
 
         std::panic::catch_unwind(assert_unwind)
             .map_err(|_| pyo3::PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("build failed"))
+            .and_then(|_| $crate::backend::take_build_error())
     }};
 }
 
@@ -73,11 +75,13 @@ macro_rules! prepare_sql {
 macro_rules! build_schema {
     ($backend:expr => $build_func:ident($stmt:expr)) => {{
         let builder = $crate::backend::into_schema_builder($backend)?;
+        $crate::backend::clear_build_error();
 
         let assert_unwind = std::panic::AssertUnwindSafe(|| $stmt.$build_func(&*builder));
 
         std::panic::catch_unwind(assert_unwind)
             .map_err(|_| pyo3::PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("build failed"))
+            .and_then(|sql| $crate::backend::take_build_error().map(|_| sql))
     }};
 }
 
@@ -88,12 +92,14 @@ macro_rules! build_query_parts {
 
         let (placeholder, numbered) = builder.placeholder();
         let mut sql = sea_query::SqlWriterValues::new(placeholder, numbered);
+        $crate::backend::clear_build_error();
 
         let assert_unwind =
             std::panic::AssertUnwindSafe(|| $stmt.build_collect_any_into(&*builder, &mut sql));
 
         std::panic::catch_unwind(assert_unwind)
             .map_err(|_| pyo3::PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("build failed"))?;
+        $crate::backend::take_build_error()?;
 
         let (sql, values) = sql.into_parts();
 
@@ -132,12 +138,14 @@ macro_rules! build_query_string {
         let builder = $crate::backend::into_query_builder($backend)?;
 
         let mut sql = String::with_capacity(255);
+        $crate::backend::clear_build_error();
 
         let assert_unwind =
             std::panic::AssertUnwindSafe(|| $stmt.build_collect_any_into(&*builder, &mut sql));
 
         std::panic::catch_unwind(assert_unwind)
             .map_err(|_| pyo3::PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("build failed"))?;
+        $crate::backend::take_build_error()?;
 
         Ok(sql)
     }};
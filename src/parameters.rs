@@ -33,3 +33,23 @@ impl<'a, 'py> OptionalParam<'a, 'py> {
         }
     }
 }
+
+/// The `escape` character of the `LIKE` family, given as a one-character `str`.
+pub struct LikeEscape(pub char);
+
+impl<'a, 'py> pyo3::FromPyObject<'a, 'py> for LikeEscape {
+    type Error = pyo3::PyErr;
+
+    fn extract(obj: pyo3::Borrowed<'a, 'py, pyo3::PyAny>) -> Result<Self, Self::Error> {
+        let escape = obj.extract::<pyo3::pybacked::PyBackedStr>()?;
+        let mut chars = escape.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(x), None) => Ok(Self(x)),
+            _ => Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "escape must be a single character, got {:?}",
+                &*escape
+            ))),
+        }
+    }
+}
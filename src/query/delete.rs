@@ -1,12 +1,15 @@
 use crate::backend::PyQueryStatement;
 use pyo3::types::{PyAnyMethods, PyTupleMethods};
-use sea_query::IntoIden;
+use sea_query::{IntoIden, IntoTableRef, QueryBuilder};
 
 #[derive(Default)]
 pub struct DeleteInner {
     // Always is `Option<TableName>`
     pub table: Option<pyo3::Py<pyo3::PyAny>>,
 
+    // Always is `Vec<TableName>`
+    pub using: Vec<pyo3::Py<pyo3::PyAny>>,
+
     // Always is `Vec<PyExpr>`
     pub r#where: Vec<pyo3::Py<pyo3::PyAny>>,
     pub limit: Option<u64>,
@@ -62,6 +65,53 @@ impl DeleteInner {
 
         stmt
     }
+
+    /// Sea-query has no support for `DELETE .. USING ..`, so this renders the
+    /// USING clause separately. Returns the clause and the position it
+    /// should be inserted at.
+    fn prepare_using(
+        &self,
+        py: pyo3::Python,
+        sql: &str,
+        backend: &pyo3::Bound<'_, pyo3::PyAny>,
+    ) -> pyo3::PyResult<Option<(usize, String)>> {
+        if self.using.is_empty() {
+            return Ok(None);
+        }
+
+        if crate::backend::into_backend_kind(backend)? != crate::backend::BackendKind::Postgres {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "USING clause is only supported by PostgreSQL backend",
+            ));
+        }
+
+        let table = match &self.table {
+            Some(x) => unsafe { x.cast_bound_unchecked::<crate::common::PyTableName>(py) },
+            None => {
+                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "USING clause requires a table; call `from_table()` first",
+                ));
+            }
+        };
+
+        let builder = sea_query::PostgresQueryBuilder;
+
+        let mut prefix = String::from("DELETE FROM ");
+        builder.prepare_table_ref(&table.get().clone().into_table_ref(), &mut prefix);
+
+        let mut clause = String::from(" USING ");
+        for (index, x) in self.using.iter().enumerate() {
+            let x = unsafe { x.cast_bound_unchecked::<crate::common::PyTableName>(py) };
+
+            if index > 0 {
+                clause.push_str(", ");
+            }
+            builder.prepare_table_ref(&x.get().clone().into_table_ref(), &mut clause);
+        }
+
+        let position = sql.find(&prefix).map(|x| x + prefix.len()).unwrap_or(sql.len());
+        Ok(Some((position, clause)))
+    }
 }
 
 #[pyo3::pyclass(module = "rapidquery._lib", name = "Delete", frozen, extends=PyQueryStatement)]
@@ -101,6 +151,27 @@ impl PyDelete {
         Ok(slf)
     }
 
+    fn using<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        table: &'a pyo3::Bound<'_, pyo3::PyAny>,
+    ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
+        let table = {
+            if let Ok(x) = table.cast_exact::<crate::table::PyTable>() {
+                let guard = x.get().inner.lock();
+                guard.name.clone_ref(slf.py())
+            } else {
+                crate::common::PyTableName::from_pyobject(table)?
+            }
+        };
+
+        {
+            let mut lock = slf.inner.lock();
+            lock.using.push(table);
+        }
+
+        Ok(slf)
+    }
+
     fn limit(slf: pyo3::PyRef<'_, Self>, n: u64) -> pyo3::PyRef<'_, Self> {
         {
             let mut lock = slf.inner.lock();
@@ -188,17 +259,37 @@ impl PyDelete {
     ) -> pyo3::PyResult<(String, pyo3::Py<pyo3::PyAny>)> {
         let lock = self.inner.lock();
         let stmt = lock.as_statement(backend.py());
-        drop(lock);
 
-        build_query_parts!(backend => build_collect_any_into(stmt))
+        let result: pyo3::PyResult<(String, pyo3::Py<pyo3::PyAny>)> =
+            build_query_parts!(backend => build_collect_any_into(stmt));
+        let (mut sql, values) = result?;
+
+        if let Some((position, clause)) = lock.prepare_using(backend.py(), &sql, backend)? {
+            sql.insert_str(position, &clause);
+        }
+
+        Ok((sql, values))
     }
 
-    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<String> {
+    #[pyo3(signature=(backend, pretty=false, quote_identifiers=true))]
+    fn to_sql(
+        &self,
+        backend: &pyo3::Bound<'_, pyo3::PyAny>,
+        pretty: bool,
+        quote_identifiers: bool,
+    ) -> pyo3::PyResult<String> {
         let lock = self.inner.lock();
         let stmt = lock.as_statement(backend.py());
-        drop(lock);
 
-        build_query_string!(backend => build_collect_any_into(stmt))
+        let result: pyo3::PyResult<String> = build_query_string!(backend => build_collect_any_into(stmt));
+        let mut sql = result?;
+
+        if let Some((position, clause)) = lock.prepare_using(backend.py(), &sql, backend)? {
+            sql.insert_str(position, &clause);
+        }
+
+        let sql = crate::format::unquote_if(sql, backend, quote_identifiers)?;
+        crate::format::format_if(sql, backend, pretty)
     }
 
     fn __repr__(&self) -> String {
@@ -212,6 +303,9 @@ impl PyDelete {
         if let Some(x) = &lock.table {
             write!(s, " from_table={x}").unwrap();
         }
+        for x in lock.using.iter() {
+            write!(s, " using={x}").unwrap();
+        }
         if let Some(x) = lock.limit {
             write!(s, " limit={x}").unwrap();
         }
@@ -17,6 +17,53 @@ pub enum InsertValueSource {
     ),
 }
 
+/// Fails unless a row of `count` values matches the established `columns`.
+fn check_row_length(columns: &[String], count: usize) -> pyo3::PyResult<()> {
+    if columns.len() == count {
+        Ok(())
+    } else {
+        Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "expected {} values to match the columns {:?}, got {}",
+            columns.len(),
+            columns,
+            count
+        )))
+    }
+}
+
+/// Name of a `ColumnRef` given to `Insert.returning`.
+///
+/// RETURNING only has the columns of the inserted rows, so a table-qualified
+/// reference must be qualified by the table being inserted into (or its alias).
+fn returning_column_name(
+    py: pyo3::Python,
+    table: &Option<pyo3::Py<pyo3::PyAny>>,
+    col: &crate::common::PyColumnRef,
+) -> pyo3::PyResult<String> {
+    let crate::common::ColumnNameOrAstrisk::Name(name) = &col.col else {
+        return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "cannot return an asterisk column reference, use returning_all() instead",
+        ));
+    };
+
+    if let Some(qualifier) = &col.table {
+        let is_target = table.as_ref().is_some_and(|x| {
+            let x = unsafe { x.cast_bound_unchecked::<crate::common::PyTableName>(py) }.get();
+            x.alias.as_ref().unwrap_or(&x.name) == qualifier
+        });
+
+        if !is_target {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "column {} is qualified by {}, which is not the table being inserted into",
+                name.to_string(),
+                qualifier.to_string(),
+            )));
+        }
+    }
+
+    Ok(name.to_string())
+}
+
 #[derive(Default)]
 pub struct InsertInner {
     pub replace: bool,
@@ -35,6 +82,32 @@ pub struct InsertInner {
 }
 
 impl InsertInner {
+    /// Rejects the options which `backend` can't render.
+    fn check_backend(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<()> {
+        let kind = crate::backend::into_backend_kind(backend)?;
+
+        if self.replace && kind == crate::backend::BackendKind::Postgres {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "REPLACE is not supported by PostgreSQL backend; use `on_conflict()` instead",
+            ));
+        }
+
+        // SQLite can only insert a single row of defaults, and would silently drop the
+        // others
+        if let Some(rows @ 2..) = self.default_values {
+            if kind == crate::backend::BackendKind::Sqlite
+                && self.columns.is_empty()
+                && matches!(self.source, InsertValueSource::None)
+            {
+                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "SQLite can't insert {rows} rows of default values in one statement"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
     #[inline]
     fn as_statement(&self, py: pyo3::Python) -> sea_query::InsertStatement {
         let mut stmt = sea_query::InsertStatement::new();
@@ -113,36 +186,45 @@ impl PyInsert {
         slf: pyo3::PyRef<'a, Self>,
         kwds: &'a pyo3::Bound<'_, pyo3::types::PyDict>,
     ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
-        {
-            let lock = slf.inner.lock();
-
-            if !lock.columns.is_empty() && lock.columns.len() != kwds.len() {
-                return Err(
-                    pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                        "values length isn't equal to columns length - this occurres when you're calling `.values()` method multiple times with different columns."
-                    )
-                );
-            }
-        }
+        let columns = slf.inner.lock().columns.clone();
 
         let mut cols = Vec::<String>::new();
         let mut vals = Vec::<pyo3::Py<pyo3::PyAny>>::new();
 
-        unsafe {
-            for (key, value) in kwds.iter() {
-                let key = key.extract::<String>().unwrap_unchecked();
-                cols.push(key);
-                vals.push(crate::expression::PyExpr::from_bound_into_any(value)?);
+        if columns.is_empty() {
+            unsafe {
+                for (key, value) in kwds.iter() {
+                    let key = key.extract::<String>().unwrap_unchecked();
+                    cols.push(key);
+                    vals.push(crate::expression::PyExpr::from_bound_into_any(value)?);
+                }
+            }
+        } else {
+            // Once the columns are established, the keys only pick which value goes where
+            check_row_length(&columns, kwds.len())?;
+
+            for col in columns.iter() {
+                match kwds.get_item(col)? {
+                    Some(value) => vals.push(crate::expression::PyExpr::from_bound_into_any(value)?),
+                    None => {
+                        return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                            "row has no value for column {col:?}"
+                        )));
+                    }
+                }
             }
         }
 
         {
             let mut lock = slf.inner.lock();
 
+            if !cols.is_empty() {
+                lock.columns = cols;
+            }
+
             match std::mem::take(&mut lock.source) {
                 InsertValueSource::None => {
                     lock.source = InsertValueSource::Single(vals);
-                    lock.columns = cols;
                 }
                 InsertValueSource::Single(oldvals) => {
                     lock.source = InsertValueSource::Many(vec![oldvals, vals]);
@@ -164,12 +246,7 @@ impl PyInsert {
     ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
         {
             let lock = slf.inner.lock();
-
-            if lock.columns.len() != PyTupleMethods::len(args) {
-                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    "values length isn't equal to columns length",
-                ));
-            }
+            check_row_length(&lock.columns, PyTupleMethods::len(args))?;
         }
 
         let mut vals = Vec::<pyo3::Py<pyo3::PyAny>>::new();
@@ -292,14 +369,121 @@ impl PyInsert {
         }
     }
 
+    fn values_many<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        rows: Vec<pyo3::Bound<'a, pyo3::PyAny>>,
+    ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
+        if rows.is_empty() {
+            return Ok(slf);
+        }
+
+        {
+            let mut lock = slf.inner.lock();
+
+            // Columns taken from the first dictionary row when none were specified
+            let mut inferred: Option<Vec<String>> = None;
+            let mut batch = Vec::<Vec<pyo3::Py<pyo3::PyAny>>>::with_capacity(rows.len());
+
+            for row in rows {
+                let columns = inferred.as_ref().unwrap_or(&lock.columns);
+
+                unsafe {
+                    if pyo3::ffi::PyTuple_CheckExact(row.as_ptr()) == 1 {
+                        let row = row.cast_into_unchecked::<pyo3::types::PyTuple>();
+
+                        check_row_length(columns, PyTupleMethods::len(&row))?;
+
+                        let mut vals = Vec::with_capacity(columns.len());
+                        for value in PyTupleMethods::iter(&row) {
+                            vals.push(crate::expression::PyExpr::from_bound_into_any(value)?);
+                        }
+
+                        batch.push(vals);
+                    } else if pyo3::ffi::PyDict_CheckExact(row.as_ptr()) == 1 {
+                        let row = row.cast_into_unchecked::<pyo3::types::PyDict>();
+
+                        if columns.is_empty() {
+                            let mut cols = Vec::with_capacity(PyDictMethods::len(&row));
+
+                            for key in row.keys() {
+                                if pyo3::ffi::PyUnicode_Check(key.as_ptr()) == 0 {
+                                    return Err(typeerror!(
+                                        "expected str as column name, got {:?}",
+                                        key.py(),
+                                        key.as_ptr()
+                                    ));
+                                }
+                                cols.push(key.extract::<String>().unwrap_unchecked());
+                            }
+
+                            inferred = Some(cols);
+                        }
+
+                        let columns = inferred.as_ref().unwrap_or(&lock.columns);
+
+                        check_row_length(columns, PyDictMethods::len(&row))?;
+
+                        let mut vals = Vec::with_capacity(columns.len());
+                        for col in columns {
+                            match row.get_item(col)? {
+                                Some(value) => {
+                                    vals.push(crate::expression::PyExpr::from_bound_into_any(value)?);
+                                }
+                                None => {
+                                    return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                                        format!("row has no value for column {col:?}"),
+                                    ));
+                                }
+                            }
+                        }
+
+                        batch.push(vals);
+                    } else {
+                        return Err(typeerror!(
+                            "expected tuple or dict as row, got {:?}",
+                            row.py(),
+                            row.as_ptr()
+                        ));
+                    }
+                }
+            }
+
+            if let Some(cols) = inferred {
+                lock.columns = cols;
+            }
+
+            lock.source = match std::mem::take(&mut lock.source) {
+                InsertValueSource::None => InsertValueSource::Many(batch),
+                InsertValueSource::Single(oldvals) => {
+                    let mut arr_of_vals = Vec::with_capacity(batch.len() + 1);
+                    arr_of_vals.push(oldvals);
+                    arr_of_vals.extend(batch);
+                    InsertValueSource::Many(arr_of_vals)
+                }
+                InsertValueSource::Many(mut arr_of_vals) => {
+                    arr_of_vals.extend(batch);
+                    InsertValueSource::Many(arr_of_vals)
+                }
+            };
+        }
+
+        Ok(slf)
+    }
+
     #[pyo3(signature=(rows=1))]
-    fn or_default_values(slf: pyo3::PyRef<'_, Self>, rows: u32) -> pyo3::PyRef<'_, Self> {
+    fn or_default_values(slf: pyo3::PyRef<'_, Self>, rows: u32) -> pyo3::PyResult<pyo3::PyRef<'_, Self>> {
+        if rows == 0 {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "rows must be at least 1",
+            ));
+        }
+
         {
             let mut lock = slf.inner.lock();
             lock.default_values = Some(rows);
         }
 
-        slf
+        Ok(slf)
     }
 
     fn on_conflict<'a>(
@@ -326,11 +510,15 @@ impl PyInsert {
                 if pyo3::ffi::Py_TYPE(col.as_ptr()) == crate::typeref::COLUMN_TYPE {
                     let col = col.cast_into_unchecked::<crate::column::PyColumn>();
                     cols.push(col.get().inner.lock().name.clone());
+                } else if pyo3::ffi::Py_TYPE(col.as_ptr()) == crate::typeref::COLUMN_REF_TYPE {
+                    let col = col.cast_into_unchecked::<crate::common::PyColumnRef>();
+                    let name = returning_column_name(slf.py(), &slf.inner.lock().table, col.get())?;
+                    cols.push(name);
                 } else if pyo3::ffi::PyUnicode_CheckExact(col.as_ptr()) == 1 {
                     cols.push(col.extract::<String>().unwrap_unchecked());
                 } else {
                     return Err(typeerror!(
-                        "expected Column or str, got {:?}",
+                        "expected Column, ColumnRef or str, got {:?}",
                         col.py(),
                         col.as_ptr()
                     ));
@@ -360,18 +548,28 @@ impl PyInsert {
         backend: &pyo3::Bound<'_, pyo3::PyAny>,
     ) -> pyo3::PyResult<(String, pyo3::Py<pyo3::PyAny>)> {
         let lock = self.inner.lock();
+        lock.check_backend(backend)?;
         let stmt = lock.as_statement(backend.py());
         drop(lock);
 
         build_query_parts!(backend => build_collect_any_into(stmt))
     }
 
-    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<String> {
+    #[pyo3(signature=(backend, pretty=false, quote_identifiers=true))]
+    fn to_sql(
+        &self,
+        backend: &pyo3::Bound<'_, pyo3::PyAny>,
+        pretty: bool,
+        quote_identifiers: bool,
+    ) -> pyo3::PyResult<String> {
         let lock = self.inner.lock();
+        lock.check_backend(backend)?;
         let stmt = lock.as_statement(backend.py());
         drop(lock);
 
-        build_query_string!(backend => build_collect_any_into(stmt))
+        let sql: pyo3::PyResult<String> = build_query_string!(backend => build_collect_any_into(stmt));
+        let sql = crate::format::unquote_if(sql?, backend, quote_identifiers)?;
+        crate::format::format_if(sql, backend, pretty)
     }
 
     fn __repr__(&self) -> String {
@@ -5,5 +5,6 @@ pub mod on_conflict;
 pub mod order;
 pub mod returning;
 pub mod select;
+pub mod subquery;
 pub mod update;
 pub mod window;
@@ -1,7 +1,7 @@
 use crate::backend::PyQueryStatement;
-use pyo3::types::PyTupleMethods;
+use pyo3::types::{PyAnyMethods, PyTupleMethods};
 use pyo3::PyTypeInfo;
-use sea_query::IntoIden;
+use sea_query::{IntoIden, QueryStatementBuilder};
 
 #[pyo3::pyclass(module = "rapidquery._lib", name = "SelectCol", frozen)]
 pub struct PySelectCol {
@@ -166,7 +166,7 @@ pub enum DistinctMode {
     None,
     Distinct,
     DistinctOn(
-        // Always is `Vec<ColumnRef | String>`
+        // Always is `Vec<ColumnRef | String | PyExpr>`
         Vec<pyo3::Py<pyo3::PyAny>>,
     ),
 }
@@ -185,9 +185,53 @@ pub struct JoinOptions {
     // Always is `TableName | PySelect`
     pub table: pyo3::Py<pyo3::PyAny>,
 
-    // Always is `PyExpr`
-    pub on: pyo3::Py<pyo3::PyAny>,
+    // Always is `Option<PyExpr>`; only a CROSS JOIN has no condition
+    pub on: Option<pyo3::Py<pyo3::PyAny>>,
     pub lateral: Option<String>,
+
+    // Alias of a joined `PySelect` which isn't lateral
+    pub subquery: Option<String>,
+}
+
+pub struct RecursiveCteOptions {
+    pub name: String,
+    pub columns: Vec<String>,
+
+    // Always is `PySelect`
+    pub base: pyo3::Py<pyo3::PyAny>,
+
+    // Always is `PySelect`
+    pub recursive: pyo3::Py<pyo3::PyAny>,
+    pub union_all: bool,
+}
+
+impl RecursiveCteOptions {
+    fn as_with_clause(&self, py: pyo3::Python) -> sea_query::WithClause {
+        let mut query = {
+            let base = unsafe { self.base.cast_bound_unchecked::<PySelect>(py) };
+            base.get().inner.lock().as_statement(py)
+        };
+
+        let recursive = {
+            let recursive = unsafe { self.recursive.cast_bound_unchecked::<PySelect>(py) };
+            recursive.get().inner.lock().as_statement(py)
+        };
+
+        if self.union_all {
+            query.union(sea_query::UnionType::All, recursive);
+        } else {
+            query.union(sea_query::UnionType::Distinct, recursive);
+        }
+
+        let mut cte = sea_query::CommonTableExpression::new();
+        cte.table_name(sea_query::Alias::new(&self.name))
+            .columns(self.columns.iter().map(sea_query::Alias::new))
+            .query(query);
+
+        let mut clause = sea_query::WithClause::new();
+        clause.recursive(true).cte(cte);
+        clause
+    }
 }
 
 pub enum SelectReference {
@@ -201,10 +245,170 @@ pub enum SelectReference {
         pyo3::Py<pyo3::PyAny>,
         String,
     ),
+    Lateral(
+        // Always is `PySelect`
+        pyo3::Py<pyo3::PyAny>,
+        String,
+    ),
     TableName(
         // Always is `PyTableName`
         pyo3::Py<pyo3::PyAny>,
     ),
+    /// PostgreSQL `ONLY table`, which excludes the tables inheriting from it
+    OnlyTableName(
+        // Always is `PyTableName`
+        pyo3::Py<pyo3::PyAny>,
+    ),
+}
+
+/// First identifier of a `FROM ONLY` table reference, which writes the `ONLY`
+/// keyword before itself.
+///
+/// sea-query has no `ONLY` on table references, so it goes in front of whatever
+/// is rendered first: the database, the schema or the table name.
+#[derive(Debug)]
+struct OnlyIden(sea_query::DynIden);
+
+impl sea_query::Iden for OnlyIden {
+    fn prepare(&self, s: &mut dyn std::fmt::Write, q: sea_query::Quote) {
+        write!(s, "ONLY ").unwrap();
+        self.0.prepare(s, q);
+    }
+
+    fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+        self.0.unquoted(s);
+    }
+}
+
+fn only_table_ref(table: &crate::common::PyTableName) -> sea_query::TableRef {
+    use sea_query::IntoTableRef;
+
+    let only = |x: &sea_query::DynIden| sea_query::SeaRc::new(OnlyIden(x.clone())) as sea_query::DynIden;
+
+    let table = match (&table.database, &table.schema) {
+        (Some(database), _) => crate::common::PyTableName {
+            database: Some(only(database)),
+            ..table.clone()
+        },
+        (None, Some(schema)) => crate::common::PyTableName {
+            schema: Some(only(schema)),
+            ..table.clone()
+        },
+        (None, None) => crate::common::PyTableName {
+            name: only(&table.name),
+            ..table.clone()
+        },
+    };
+    table.into_table_ref()
+}
+
+/// Converts a side of a join column pair, a `ColumnRef` or a `"table.column"`
+/// string.
+fn join_pair_column(value: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<sea_query::SimpleExpr> {
+    use sea_query::IntoColumnRef;
+    use std::str::FromStr;
+
+    unsafe {
+        if pyo3::ffi::Py_TYPE(value.as_ptr()) == crate::typeref::COLUMN_REF_TYPE {
+            let x = value.cast_unchecked::<crate::common::PyColumnRef>();
+            return Ok(sea_query::SimpleExpr::Column(x.get().clone().into_column_ref()));
+        }
+
+        if pyo3::ffi::PyUnicode_CheckExact(value.as_ptr()) == 1 {
+            let x = value.extract::<&str>().unwrap_unchecked();
+            let colref = crate::common::PyColumnRef::from_str(x)?.into_column_ref();
+            return Ok(sea_query::SimpleExpr::Column(colref));
+        }
+
+        Err(typeerror!(
+            "expected ColumnRef or str in join column pair, got {:?}",
+            value.py(),
+            value.as_ptr()
+        ))
+    }
+}
+
+/// Converts the `on` condition of a join into an `Expr`.
+///
+/// Besides anything convertible to `Expr`, `on` may be a list of `(left,
+/// right)` column pairs, which are compared with `=` and combined with `AND`.
+fn join_condition(on: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<pyo3::Py<pyo3::PyAny>> {
+    use pyo3::types::{PyListMethods, PyTupleMethods};
+
+    let pairs = match on.cast_exact::<pyo3::types::PyList>() {
+        Ok(list) => list,
+        Err(_) => return crate::expression::PyExpr::from_bound_into_any(on.clone()),
+    };
+    if pairs.is_empty() {
+        return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "expected at least one column pair in join condition",
+        ));
+    }
+
+    let mut condition: Option<sea_query::SimpleExpr> = None;
+    for pair in pairs.iter() {
+        let pair = match pair.cast_exact::<pyo3::types::PyTuple>() {
+            Ok(x) if x.len() == 2 => x.clone(),
+            _ => {
+                return Err(typeerror!(
+                    "expected (left, right) tuple in join column pairs, got {:?}",
+                    pair.py(),
+                    pair.as_ptr()
+                ))
+            }
+        };
+        let (left, right) = (pair.get_item(0)?, pair.get_item(1)?);
+        let equals = sea_query::ExprTrait::eq(join_pair_column(&left)?, join_pair_column(&right)?);
+
+        condition = Some(match condition {
+            Some(x) => sea_query::ExprTrait::and(x, equals),
+            None => equals,
+        });
+    }
+
+    let expr = crate::expression::PyExpr::from(condition.unwrap());
+    Ok(pyo3::Py::new(on.py(), expr)?.into_any())
+}
+
+/// Parses the `type` argument of the join methods, case-insensitively.
+///
+/// An empty string is a plain `JOIN`.
+fn join_type_from_str(mut r#type: String) -> pyo3::PyResult<sea_query::JoinType> {
+    r#type.make_ascii_lowercase();
+
+    match r#type.as_str() {
+        "" => Ok(sea_query::JoinType::Join),
+        "cross" => Ok(sea_query::JoinType::CrossJoin),
+        "full" => Ok(sea_query::JoinType::FullOuterJoin),
+        "inner" => Ok(sea_query::JoinType::InnerJoin),
+        "left" => Ok(sea_query::JoinType::LeftJoin),
+        "right" => Ok(sea_query::JoinType::RightJoin),
+        _ => Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "acceptable join types are: '', 'cross', 'full', 'left', 'right', and 'inner'. got invalid type",
+        )),
+    }
+}
+
+/// Converts the optional `on` condition of a join of type `join_type` with
+/// [`join_condition`].
+///
+/// A CROSS JOIN takes no condition, and every other join requires one.
+fn join_on(
+    on: Option<&pyo3::Bound<'_, pyo3::PyAny>>,
+    join_type: sea_query::JoinType,
+) -> pyo3::PyResult<Option<pyo3::Py<pyo3::PyAny>>> {
+    match (on, join_type) {
+        (Some(_), sea_query::JoinType::CrossJoin) => {
+            Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "a cross join has no condition; use another join type to join on a condition",
+            ))
+        }
+        (None, sea_query::JoinType::CrossJoin) => Ok(None),
+        (Some(on), _) => join_condition(on).map(Some),
+        (None, _) => Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "a join condition is required, unless type is 'cross'",
+        )),
+    }
 }
 
 #[derive(Default)]
@@ -234,23 +438,387 @@ pub struct SelectInner {
     pub limit: Option<u64>,
     pub offset: Option<u64>,
     pub window: Option<(String, pyo3::Py<pyo3::PyAny>)>,
+    pub with_recursive: Option<RecursiveCteOptions>,
 
     // TODO
-    // pub with: Option<pyo3::Py<pyo3::PyAny>>,
     // pub table_sample: Option<pyo3::Py<pyo3::PyAny>>,
     // pub index_hint: Option<pyo3::Py<pyo3::PyAny>>,
 }
 
+/// Removes the table and schema qualifiers from `sql`, rendered with the
+/// PostgreSQL builder, e.g. `"t"."a" + 1` becomes `"a" + 1`.
+///
+/// A qualifier is a quoted identifier followed by a dot; string literals are
+/// copied as-is.
+fn strip_qualifiers(sql: &str) -> String {
+    let mut out = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                out.push(c);
+                while let Some(c) = chars.next() {
+                    out.push(c);
+                    if c == '\\' {
+                        if let Some(next) = chars.next() {
+                            out.push(next);
+                        }
+                    } else if c == '\'' {
+                        break;
+                    }
+                }
+            }
+            '"' => {
+                let mut name = String::from('"');
+                while let Some(c) = chars.next() {
+                    name.push(c);
+                    if c == '"' {
+                        // A doubled quote stands for itself
+                        if chars.peek() != Some(&'"') {
+                            break;
+                        }
+                        name.push(chars.next().unwrap());
+                    }
+                }
+
+                if chars.peek() == Some(&'.') {
+                    chars.next();
+                } else {
+                    out.push_str(&name);
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
 impl SelectInner {
+    /// Returns a table name referring to the CTE called `name`, which this
+    /// statement must define.
+    fn cte_table_name(&self, py: pyo3::Python, name: &str) -> pyo3::PyResult<pyo3::Py<pyo3::PyAny>> {
+        match &self.with_recursive {
+            Some(cte) if cte.name == name => {
+                let table = crate::common::PyTableName {
+                    name: sea_query::Alias::new(name).into_iden(),
+                    schema: None,
+                    database: None,
+                    alias: None,
+                    converted: Default::default(),
+                };
+                Ok(pyo3::Py::new(py, table)?.into_any())
+            }
+            _ => Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "no CTE named {name:?} is defined on this statement; define it with with_recursive() first"
+            ))),
+        }
+    }
+
+    /// Returns the names the tables of FROM and of the joins are referred to by
+    /// in this statement, plus the name of its CTE.
+    fn reference_names(&self, py: pyo3::Python) -> Vec<String> {
+        let table_name = |x: &pyo3::Py<pyo3::PyAny>| {
+            x.cast_bound::<crate::common::PyTableName>(py)
+                .ok()
+                .map(|x| x.get().alias.as_ref().unwrap_or(&x.get().name).to_string())
+        };
+
+        let mut names: Vec<String> = self.with_recursive.iter().map(|x| x.name.clone()).collect();
+        for reference in self.tables.iter() {
+            match reference {
+                SelectReference::TableName(x) | SelectReference::OnlyTableName(x) => {
+                    names.extend(table_name(x))
+                }
+                SelectReference::SubQuery(_, alias)
+                | SelectReference::FunctionCall(_, alias)
+                | SelectReference::Lateral(_, alias) => names.push(alias.clone()),
+            }
+        }
+        for join in self.join.iter() {
+            names.extend(table_name(&join.table).or_else(|| join.lateral.clone().or(join.subquery.clone())));
+        }
+
+        names
+    }
+
+    /// Returns the names of the projected columns, in order.
+    ///
+    /// A column is named by its alias or, failing that, by the column it refers
+    /// to. Other unaliased expressions have no name and are skipped.
+    pub fn projected_names(&self, py: pyo3::Python) -> Vec<String> {
+        self.cols
+            .iter()
+            .filter_map(|x| unsafe {
+                let col = x.cast_bound_unchecked::<PySelectCol>(py);
+                let col = col.get();
+
+                if let Some(alias) = &col.alias {
+                    return Some(alias.clone());
+                }
+
+                let expr = col.expr.cast_bound_unchecked::<crate::expression::PyExpr>(py);
+                match &expr.get().inner {
+                    sea_query::SimpleExpr::Column(
+                        sea_query::ColumnRef::Column(name)
+                        | sea_query::ColumnRef::TableColumn(_, name)
+                        | sea_query::ColumnRef::SchemaTableColumn(_, _, name),
+                    ) => Some(name.to_string()),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Checks that every ORDER BY expression of a `SELECT DISTINCT` is selected
+    /// too, which PostgreSQL requires.
+    ///
+    /// An expression is selected if it's a bare column named like an output
+    /// column, or if it equals a selected expression once table qualifiers are
+    /// ignored; qualifiers may be aliases which can't be resolved here, so only
+    /// expressions which can't be selected are rejected. Selecting `*` allows
+    /// anything.
+    fn check_distinct_orders(&self, py: pyo3::Python) -> pyo3::PyResult<()> {
+        if !matches!(self.distinct, DistinctMode::Distinct) || self.orders.is_empty() {
+            return Ok(());
+        }
+
+        let render = |expr: &sea_query::SimpleExpr| {
+            let mut sql = String::new();
+            sea_query::QueryBuilder::prepare_simple_expr(&crate::backend::PostgresBuilder, expr, &mut sql);
+            sql
+        };
+
+        let mut output_names = Vec::with_capacity(self.cols.len());
+        let mut selected = Vec::with_capacity(self.cols.len());
+
+        for col in self.cols.iter() {
+            let col = unsafe { col.cast_bound_unchecked::<PySelectCol>(py) }.get();
+            let expr = unsafe { col.expr.cast_bound_unchecked::<crate::expression::PyExpr>(py) };
+            let expr = &expr.get().inner;
+
+            match expr {
+                sea_query::SimpleExpr::Column(
+                    sea_query::ColumnRef::Asterisk | sea_query::ColumnRef::TableAsterisk(_),
+                ) => return Ok(()),
+                sea_query::SimpleExpr::Column(
+                    sea_query::ColumnRef::Column(name)
+                    | sea_query::ColumnRef::TableColumn(_, name)
+                    | sea_query::ColumnRef::SchemaTableColumn(_, _, name),
+                ) if col.alias.is_none() => output_names.push(name.to_string()),
+                _ => (),
+            }
+            if let Some(alias) = &col.alias {
+                output_names.push(alias.clone());
+            }
+
+            selected.push(strip_qualifiers(&render(expr)));
+        }
+        if selected.is_empty() {
+            return Ok(());
+        }
+
+        for order in self.orders.iter() {
+            let target = unsafe { order.target.cast_bound_unchecked::<crate::expression::PyExpr>(py) };
+            let target = &target.get().inner;
+
+            if let sea_query::SimpleExpr::Column(sea_query::ColumnRef::Column(name)) = target {
+                if output_names.contains(&name.to_string()) {
+                    continue;
+                }
+            }
+
+            let sql = render(target);
+            if selected.contains(&strip_qualifiers(&sql)) {
+                continue;
+            }
+
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "for SELECT DISTINCT, ORDER BY expressions must appear in select list, got {sql}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Resolves a `FOR UPDATE OF` target to the name it has in this statement.
+    ///
+    /// Tables can only be referred to unqualified there, and an aliased table
+    /// only by its alias, so a target naming a table of FROM or of a join is
+    /// replaced by that table's alias, or its bare name.
+    fn lock_target(
+        &self,
+        py: pyo3::Python,
+        target: &crate::common::PyTableName,
+    ) -> pyo3::PyResult<sea_query::TableRef> {
+        if let Some(alias) = &target.alias {
+            return Ok(sea_query::TableRef::Table(alias.clone()));
+        }
+
+        let target_name = target.name.to_string();
+
+        let mut tables = Vec::new();
+        let mut aliases = Vec::new();
+        for reference in self.tables.iter() {
+            match reference {
+                SelectReference::TableName(x) | SelectReference::OnlyTableName(x) => {
+                    tables.push(unsafe { x.cast_bound_unchecked::<crate::common::PyTableName>(py) }.get());
+                }
+                SelectReference::SubQuery(_, alias)
+                | SelectReference::FunctionCall(_, alias)
+                | SelectReference::Lateral(_, alias) => aliases.push(alias.clone()),
+            }
+        }
+        for join in self.join.iter() {
+            if let Ok(x) = join.table.cast_bound::<crate::common::PyTableName>(py) {
+                tables.push(x.get());
+            } else if let Some(alias) = join.lateral.as_ref().or(join.subquery.as_ref()) {
+                aliases.push(alias.clone());
+            }
+        }
+        aliases.extend(
+            tables
+                .iter()
+                .filter_map(|x| x.alias.as_ref().map(|x| x.to_string())),
+        );
+
+        if target.schema.is_none() && target.database.is_none() && aliases.contains(&target_name) {
+            return Ok(sea_query::TableRef::Table(target.name.clone()));
+        }
+
+        let same = |a: &Option<sea_query::DynIden>, b: &Option<sea_query::DynIden>| {
+            a.is_none() || a.as_ref().map(|x| x.to_string()) == b.as_ref().map(|x| x.to_string())
+        };
+
+        let mut names: Vec<sea_query::DynIden> = Vec::new();
+        for table in tables {
+            if table.name.to_string() != target_name
+                || !same(&target.schema, &table.schema)
+                || !same(&target.database, &table.database)
+            {
+                continue;
+            }
+
+            let name = table.alias.as_ref().unwrap_or(&table.name);
+            if !names.iter().any(|x| x.to_string() == name.to_string()) {
+                names.push(name.clone());
+            }
+        }
+
+        match names.len() {
+            1 => Ok(sea_query::TableRef::Table(names.pop().unwrap())),
+            0 => Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "lock target {target_name:?} isn't a table of FROM or of a join"
+            ))),
+            _ => Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "lock target {target_name:?} is ambiguous, refer to it by one of its aliases"
+            ))),
+        }
+    }
+
+    /// Whether this statement can't be a member of a compound select in SQLite
+    /// as it is.
+    ///
+    /// SQLite has no parentheses around the members, so a member which is
+    /// compound itself, or has its own ORDER BY, LIMIT or OFFSET, has to be
+    /// wrapped in a subquery.
+    fn is_complex_union_member(&self) -> bool {
+        !self.unions.is_empty() || !self.orders.is_empty() || self.limit.is_some() || self.offset.is_some()
+    }
+
+    /// Whether `target` is one of the statements combined with this one, at any
+    /// depth.
+    fn has_union_with(&self, py: pyo3::Python, target: *mut pyo3::ffi::PyObject) -> bool {
+        self.unions.iter().any(|(_, x)| {
+            x.as_ptr() == target || {
+                let x = unsafe { x.cast_bound_unchecked::<PySelect>(py) };
+                let inner = x.get().inner.lock();
+                inner.has_union_with(py, target)
+            }
+        })
+    }
+
+    /// Same as [`SelectInner::as_statement`], but also applies the backend
+    /// requirements.
+    ///
+    /// On PostgreSQL, the ORDER BY of a `SELECT DISTINCT` is validated with
+    /// [`SelectInner::check_distinct_orders`]. On SQLite, complex members
+    /// of a compound select are wrapped in subqueries (see
+    /// [`SelectInner::is_complex_union_member`]).
+    pub fn as_backend_statement(
+        &self,
+        backend: &pyo3::Bound<'_, pyo3::PyAny>,
+    ) -> pyo3::PyResult<sea_query::SelectStatement> {
+        let kind = crate::backend::into_backend_kind(backend)?;
+
+        if kind != crate::backend::BackendKind::Sqlite {
+            if let Some(lock) = &self.lock {
+                for table in lock.tables.iter() {
+                    let table =
+                        unsafe { table.cast_bound_unchecked::<crate::common::PyTableName>(backend.py()) };
+                    self.lock_target(backend.py(), table.get())?;
+                }
+            }
+        }
+
+        if kind == crate::backend::BackendKind::Postgres {
+            self.check_distinct_orders(backend.py())?;
+        } else if self
+            .tables
+            .iter()
+            .any(|x| matches!(x, SelectReference::OnlyTableName(_)))
+        {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "FROM ONLY is only supported by PostgreSQL",
+            ));
+        }
+
+        Ok(self.build_statement(backend.py(), kind == crate::backend::BackendKind::Sqlite))
+    }
+
     #[inline]
     pub fn as_statement(&self, py: pyo3::Python) -> sea_query::SelectStatement {
+        self.build_statement(py, false)
+    }
+
+    /// Builds the statement, wrapping complex union members in subqueries if
+    /// `wrap_unions`.
+    fn build_statement(&self, py: pyo3::Python, wrap_unions: bool) -> sea_query::SelectStatement {
         let mut stmt = sea_query::SelectStatement::new();
 
+        // sea-query only accepts column references in `DISTINCT ON`; expressions are
+        // rendered through the `DISTINCT_ON` marker wrapped around the first
+        // selected expression.
+        let mut distinct_on_exprs = None;
+
         match &self.distinct {
             DistinctMode::None => (),
             DistinctMode::Distinct => {
                 stmt.distinct();
             }
+            DistinctMode::DistinctOn(cols)
+                if cols
+                    .iter()
+                    .any(|col| unsafe { pyo3::ffi::Py_TYPE(col.as_ptr()) == crate::typeref::EXPR_TYPE }) =>
+            {
+                distinct_on_exprs = Some(
+                    cols.iter()
+                        .map(|col| unsafe {
+                            if pyo3::ffi::PyUnicode_Check(col.as_ptr()) == 1 {
+                                let x = sea_query::Alias::new(col.extract::<String>(py).unwrap_unchecked());
+                                sea_query::Expr::col(x).into()
+                            } else if pyo3::ffi::Py_TYPE(col.as_ptr()) == crate::typeref::EXPR_TYPE {
+                                let x = col.cast_bound_unchecked::<crate::expression::PyExpr>(py);
+                                x.get().inner.clone()
+                            } else {
+                                let x = col.cast_bound_unchecked::<crate::common::PyColumnRef>(py).get();
+                                sea_query::Expr::col(x).into()
+                            }
+                        })
+                        .collect::<Vec<sea_query::SimpleExpr>>(),
+                );
+            }
             DistinctMode::DistinctOn(cols) => {
                 use sea_query::IntoColumnRef;
 
@@ -260,7 +828,7 @@ impl SelectInner {
                         x.into_column_ref()
                     } else {
                         let x = col.cast_bound_unchecked::<crate::common::PyColumnRef>(py).get();
-                        x.clone().into_column_ref()
+                        x.into_column_ref()
                     }
                 }));
             }
@@ -270,7 +838,11 @@ impl SelectInner {
             match table {
                 SelectReference::TableName(x) => unsafe {
                     let x = unsafe { x.cast_bound_unchecked::<crate::common::PyTableName>(py) };
-                    stmt.from(x.get().clone());
+                    stmt.from(x.get());
+                },
+                SelectReference::OnlyTableName(x) => unsafe {
+                    let x = unsafe { x.cast_bound_unchecked::<crate::common::PyTableName>(py) };
+                    stmt.from(only_table_ref(x.get()));
                 },
                 SelectReference::FunctionCall(x, alias) => unsafe {
                     let x = unsafe { x.cast_bound_unchecked::<crate::expression::PyFunctionCall>(py) };
@@ -282,13 +854,37 @@ impl SelectInner {
 
                     stmt.from_subquery(inner.as_statement(py), sea_query::Alias::new(alias));
                 },
+                SelectReference::Lateral(x, alias) => unsafe {
+                    let x = unsafe { x.cast_bound_unchecked::<PySelect>(py) };
+                    let inner = x.get().inner.lock();
+
+                    let func = sea_query::Func::cust(sea_query::Alias::new(crate::backend::LATERAL)).arg(
+                        sea_query::SimpleExpr::SubQuery(
+                            None,
+                            Box::new(inner.as_statement(py).into_sub_query_statement()),
+                        ),
+                    );
+                    stmt.from_function(func, sea_query::Alias::new(alias));
+                },
             }
         }
 
         if !self.cols.is_empty() {
-            stmt.exprs(self.cols.iter().map(|x| unsafe {
+            stmt.exprs(self.cols.iter().enumerate().map(|(index, x)| unsafe {
                 let expr = x.cast_bound_unchecked::<PySelectCol>(py);
-                expr.get().as_statement(py)
+                let mut expr = expr.get().as_statement(py);
+
+                if index == 0 {
+                    if let Some(on) = distinct_on_exprs.take() {
+                        expr.expr = sea_query::SimpleExpr::Binary(
+                            Box::new(sea_query::SimpleExpr::Tuple(on)),
+                            sea_query::BinOper::Custom(crate::backend::DISTINCT_ON),
+                            Box::new(expr.expr),
+                        );
+                    }
+                }
+
+                expr
             }));
         }
 
@@ -335,7 +931,9 @@ impl SelectInner {
                         lock.r#type,
                         lock.tables.iter().map(|table| {
                             let x = unsafe { table.cast_bound_unchecked::<crate::common::PyTableName>(py) };
-                            x.get().clone()
+                            // Unresolvable targets are rejected by `as_backend_statement`
+                            self.lock_target(py, x.get())
+                                .unwrap_or_else(|_| sea_query::TableRef::Table(x.get().name.clone()))
                         }),
                         behavior,
                     );
@@ -348,7 +946,9 @@ impl SelectInner {
                         lock.r#type,
                         lock.tables.iter().map(|table| {
                             let x = unsafe { table.cast_bound_unchecked::<crate::common::PyTableName>(py) };
-                            x.get().clone()
+                            // Unresolvable targets are rejected by `as_backend_statement`
+                            self.lock_target(py, x.get())
+                                .unwrap_or_else(|_| sea_query::TableRef::Table(x.get().name.clone()))
                         }),
                     );
                 }
@@ -362,12 +962,27 @@ impl SelectInner {
             let union_stmt = unsafe { union_stmt.cast_bound_unchecked::<PySelect>(py) };
 
             let inner = union_stmt.get().inner.lock();
-            (*union_type, inner.as_statement(py))
+            let member = inner.build_statement(py, wrap_unions);
+
+            if wrap_unions && inner.is_complex_union_member() {
+                let wrapped = sea_query::SelectStatement::new()
+                    .column(sea_query::Asterisk)
+                    .from_subquery(member, sea_query::Alias::new("compound"))
+                    .to_owned();
+                (*union_type, wrapped)
+            } else {
+                (*union_type, member)
+            }
         }));
 
         for join in self.join.iter() {
-            let condition = unsafe { join.on.cast_bound_unchecked::<crate::expression::PyExpr>(py) };
-            let condition = condition.get().inner.clone();
+            let condition = match &join.on {
+                Some(on) => {
+                    let on = unsafe { on.cast_bound_unchecked::<crate::expression::PyExpr>(py) };
+                    sea_query::Condition::all().add(on.get().inner.clone())
+                }
+                None => sea_query::Condition::all(),
+            };
 
             if let Some(lateral) = &join.lateral {
                 let query = unsafe { join.table.cast_bound_unchecked::<PySelect>(py) };
@@ -379,9 +994,19 @@ impl SelectInner {
                     sea_query::Alias::new(lateral),
                     condition,
                 );
+            } else if let Some(alias) = &join.subquery {
+                let query = unsafe { join.table.cast_bound_unchecked::<PySelect>(py) };
+                let query = query.get().inner.lock();
+
+                stmt.join_subquery(
+                    join.r#type,
+                    query.as_statement(py),
+                    sea_query::Alias::new(alias),
+                    condition,
+                );
             } else {
                 let table = unsafe { join.table.cast_bound_unchecked::<crate::common::PyTableName>(py) };
-                stmt.join(join.r#type, table.get().clone(), condition);
+                stmt.join(join.r#type, table.get(), condition);
             }
         }
 
@@ -401,6 +1026,87 @@ pub struct PySelect {
     pub inner: parking_lot::Mutex<SelectInner>,
 }
 
+impl PySelect {
+    /// Combines `condition` with the current HAVING condition using `op`, or
+    /// sets it if there's none.
+    fn extend_having(
+        slf: &pyo3::PyRef<'_, Self>,
+        condition: pyo3::Bound<'_, pyo3::PyAny>,
+        op: fn(sea_query::SimpleExpr, sea_query::SimpleExpr) -> sea_query::SimpleExpr,
+    ) -> pyo3::PyResult<()> {
+        let py = condition.py();
+        let condition = crate::expression::PyExpr::from_bound_into_any(condition)?;
+
+        let mut lock = slf.inner.lock();
+        let combined = match &lock.having {
+            Some(current) => {
+                let current = unsafe { current.cast_bound_unchecked::<crate::expression::PyExpr>(py) };
+                let condition = unsafe { condition.cast_bound_unchecked::<crate::expression::PyExpr>(py) };
+
+                let expr = op(current.get().inner.clone(), condition.get().inner.clone());
+                pyo3::Py::new(py, crate::expression::PyExpr::from(expr))?.into_any()
+            }
+            None => condition,
+        };
+        lock.having = Some(combined);
+
+        Ok(())
+    }
+
+    /// Converts the targets of `lock` and `of` into `TableName`s.
+    fn lock_tables<'py>(
+        py: pyo3::Python<'py>,
+        tables: impl ExactSizeIterator<Item = pyo3::Bound<'py, pyo3::PyAny>>,
+    ) -> pyo3::PyResult<Vec<pyo3::Py<pyo3::PyAny>>> {
+        let mut tbs = Vec::with_capacity(tables.len());
+
+        for tb in tables {
+            if let Ok(x) = tb.cast_exact::<crate::table::PyTable>() {
+                let guard = x.get().inner.lock();
+                tbs.push(guard.name.clone_ref(py));
+            } else {
+                tbs.push(crate::common::PyTableName::from_pyobject(&tb)?);
+            }
+        }
+
+        Ok(tbs)
+    }
+
+    /// Parses a `(target, order)` or `(target, order, null_order)` tuple into
+    /// an [`OrderClause`].
+    ///
+    /// [`OrderClause`]: super::order::OrderClause
+    fn order_from_spec(spec: pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<super::order::OrderClause> {
+        let spec = unsafe {
+            if pyo3::ffi::PyTuple_CheckExact(spec.as_ptr()) == 0 {
+                return Err(typeerror!(
+                    "expected (target, order, null_order) tuple, got {:?}",
+                    spec.py(),
+                    spec.as_ptr()
+                ));
+            }
+
+            spec.cast_into_unchecked::<pyo3::types::PyTuple>()
+        };
+
+        match PyTupleMethods::len(&spec) {
+            2 => super::order::OrderClause::from_parameters(
+                spec.get_item(0)?,
+                spec.get_item(1)?.extract()?,
+                None,
+            ),
+            3 => super::order::OrderClause::from_parameters(
+                spec.get_item(0)?,
+                spec.get_item(1)?.extract()?,
+                spec.get_item(2)?.extract()?,
+            ),
+            n => Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "expected (target, order) or (target, order, null_order), got tuple of length {n}"
+            ))),
+        }
+    }
+}
+
 #[pyo3::pymethods]
 impl PySelect {
     #[new]
@@ -449,9 +1155,13 @@ impl PySelect {
                         || (pyo3::ffi::PyUnicode_Check(col.as_ptr()) == 1)
                     {
                         cols.push(col.unbind());
+                    } else if (col_ptr == crate::typeref::EXPR_TYPE)
+                        || (col_ptr == crate::typeref::FUNCTION_CALL_TYPE)
+                    {
+                        cols.push(crate::expression::PyExpr::from_bound_into_any(col)?);
                     } else {
                         return Err(typeerror!(
-                            "expected Column or ColumnRef or str, got {:?}",
+                            "expected Column, ColumnRef, Expr, FunctionCall or str, got {:?}",
                             col.py(),
                             col.as_ptr()
                         ));
@@ -485,35 +1195,166 @@ impl PySelect {
         Ok(slf)
     }
 
+    #[pyo3(signature=(expr, alias=None))]
+    fn add_column<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        expr: pyo3::Bound<'a, pyo3::PyAny>,
+        alias: Option<String>,
+    ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
+        let mut col = PySelectCol::from_bound_into_any(&expr)?;
+
+        if let Some(alias) = alias {
+            let aliased = unsafe {
+                let col = col.cast_bound_unchecked::<PySelectCol>(slf.py()).get();
+                PySelectCol {
+                    alias: Some(alias),
+                    ..col.clone_ref(slf.py())
+                }
+            };
+            col = pyo3::Py::new(slf.py(), aliased)?.into_any();
+        }
+
+        {
+            let mut lock = slf.inner.lock();
+            lock.cols.push(col);
+        }
+
+        Ok(slf)
+    }
+
+    #[getter]
+    fn selected_columns(&self, py: pyo3::Python) -> Vec<(pyo3::Py<pyo3::PyAny>, Option<String>)> {
+        let lock = self.inner.lock();
+
+        lock.cols
+            .iter()
+            .map(|x| unsafe {
+                let col = x.cast_bound_unchecked::<PySelectCol>(py).get();
+                (col.expr.clone_ref(py), col.alias.clone())
+            })
+            .collect()
+    }
+
+    #[pyo3(signature=(expr, alias=None))]
+    fn count_distinct<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        expr: pyo3::Bound<'a, pyo3::PyAny>,
+        alias: Option<String>,
+    ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
+        let expr = crate::expression::PyExpr::try_from(expr)?;
+        let count = crate::expression::PyExpr::from(sea_query::SimpleExpr::from(
+            sea_query::Func::count_distinct(expr.inner),
+        ));
+
+        let col = PySelectCol {
+            expr: pyo3::Py::new(slf.py(), count)?.into_any(),
+            alias,
+            window: None,
+        };
+        let col = pyo3::Py::new(slf.py(), col)?.into_any();
+
+        {
+            let mut lock = slf.inner.lock();
+            lock.cols = vec![col];
+        }
+
+        Ok(slf)
+    }
+
     #[allow(clippy::wrong_self_convention)]
+    #[pyo3(signature=(table, only=false))]
     fn from_table<'a>(
         slf: pyo3::PyRef<'a, Self>,
         table: &'a pyo3::Bound<'_, pyo3::PyAny>,
+        only: bool,
     ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
         let table = {
             if let Ok(x) = table.cast_exact::<crate::table::PyTable>() {
                 let guard = x.get().inner.lock();
-                guard.name.clone_ref(slf.py())
+                SelectReference::TableName(guard.name.clone_ref(slf.py()))
             } else if let Ok(x) = table.cast_exact::<crate::table::PyAliasedTable>() {
-                x.get().name(slf.py())?
+                SelectReference::TableName(x.get().name(slf.py())?)
+            } else if let Ok(x) = table.cast_exact::<super::subquery::PySubquery>() {
+                let x = x.get();
+
+                if std::hint::unlikely(slf.as_ptr() == x.select.as_ptr()) {
+                    return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "A Select statement cannot select from itself",
+                    ));
+                }
+                if only {
+                    return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "only applies to tables, not subqueries",
+                    ));
+                }
+
+                SelectReference::SubQuery(x.select.clone_ref(slf.py()), x.alias.clone())
+            } else {
+                SelectReference::TableName(crate::common::PyTableName::from_pyobject(table)?)
+            }
+        };
+
+        let table = match table {
+            SelectReference::TableName(x) if only => SelectReference::OnlyTableName(x),
+            x => x,
+        };
+
+        {
+            let mut lock = slf.inner.lock();
+            lock.tables.push(table);
+        }
+
+        Ok(slf)
+    }
+
+    fn subquery(slf: pyo3::PyRef<'_, Self>, alias: String) -> super::subquery::PySubquery {
+        super::subquery::PySubquery {
+            select: pyo3::Py::<Self>::from(slf).into_any(),
+            alias,
+        }
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn from_subquery<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        subquery: &'a pyo3::Bound<'_, pyo3::PyAny>,
+        alias: String,
+    ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
+        if std::hint::unlikely(slf.as_ptr() == subquery.as_ptr()) {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "A Select statement cannot select from itself",
+            ));
+        }
+
+        let subquery = unsafe {
+            if std::hint::likely(
+                pyo3::ffi::Py_TYPE(subquery.as_ptr()) == crate::typeref::SELECT_STATEMENT_TYPE,
+            ) {
+                subquery.clone().unbind()
             } else {
-                crate::common::PyTableName::from_pyobject(table)?
+                return Err(typeerror!(
+                    "expected Select, got {:?}",
+                    subquery.py(),
+                    subquery.as_ptr()
+                ));
             }
         };
 
         {
             let mut lock = slf.inner.lock();
-            lock.tables.push(SelectReference::TableName(table));
+            lock.tables.push(SelectReference::SubQuery(subquery, alias));
         }
 
         Ok(slf)
     }
 
     #[allow(clippy::wrong_self_convention)]
-    fn from_subquery<'a>(
+    #[pyo3(signature=(subquery, alias, on=None))]
+    fn from_lateral<'a>(
         slf: pyo3::PyRef<'a, Self>,
         subquery: &'a pyo3::Bound<'_, pyo3::PyAny>,
         alias: String,
+        on: Option<pyo3::Bound<'a, pyo3::PyAny>>,
     ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
         if std::hint::unlikely(slf.as_ptr() == subquery.as_ptr()) {
             return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
@@ -535,9 +1376,15 @@ impl PySelect {
             }
         };
 
+        // There's no JOIN to attach the condition to in a comma-style FROM list
+        let on = on
+            .map(crate::expression::PyExpr::from_bound_into_any)
+            .transpose()?;
+
         {
             let mut lock = slf.inner.lock();
-            lock.tables.push(SelectReference::SubQuery(subquery, alias));
+            lock.tables.push(SelectReference::Lateral(subquery, alias));
+            lock.r#where.extend(on);
         }
 
         Ok(slf)
@@ -602,6 +1449,19 @@ impl PySelect {
         Ok(slf)
     }
 
+    fn clear_where(slf: pyo3::PyRef<'_, Self>) -> pyo3::PyRef<'_, Self> {
+        {
+            let mut lock = slf.inner.lock();
+            lock.r#where.clear();
+        }
+
+        slf
+    }
+
+    fn has_where(&self) -> bool {
+        !self.inner.lock().r#where.is_empty()
+    }
+
     fn having<'a>(
         slf: pyo3::PyRef<'a, Self>,
         condition: pyo3::Bound<'a, pyo3::PyAny>,
@@ -616,6 +1476,27 @@ impl PySelect {
         Ok(slf)
     }
 
+    fn and_having<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        condition: pyo3::Bound<'a, pyo3::PyAny>,
+    ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
+        Self::extend_having(&slf, condition, sea_query::ExprTrait::and)?;
+        Ok(slf)
+    }
+
+    fn or_having<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        condition: pyo3::Bound<'a, pyo3::PyAny>,
+    ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
+        Self::extend_having(&slf, condition, sea_query::ExprTrait::or)?;
+        Ok(slf)
+    }
+
+    #[getter]
+    fn current_having(&self, py: pyo3::Python) -> Option<pyo3::Py<pyo3::PyAny>> {
+        self.inner.lock().having.as_ref().map(|x| x.clone_ref(py))
+    }
+
     #[pyo3(signature=(target, order, null_order=None))]
     fn order_by<'a>(
         slf: pyo3::PyRef<'a, Self>,
@@ -633,13 +1514,166 @@ impl PySelect {
         Ok(slf)
     }
 
+    #[pyo3(signature=(*specs))]
+    fn order_by_columns<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        specs: &'a pyo3::Bound<'_, pyo3::types::PyTuple>,
+    ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
+        let mut orders = Vec::with_capacity(PyTupleMethods::len(specs));
+
+        for spec in PyTupleMethods::iter(specs) {
+            orders.push(Self::order_from_spec(spec)?);
+        }
+
+        {
+            let mut lock = slf.inner.lock();
+            lock.orders.extend(orders);
+        }
+
+        Ok(slf)
+    }
+
+    #[pyo3(signature=(partition_by, order_by, n, rank_alias=None, alias=None))]
+    fn top_n_per_group<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        partition_by: Vec<pyo3::Bound<'a, pyo3::PyAny>>,
+        order_by: Vec<pyo3::Bound<'a, pyo3::PyAny>>,
+        n: u64,
+        rank_alias: Option<String>,
+        alias: Option<String>,
+    ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
+        let py = slf.py();
+
+        if n == 0 {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "n must be greater than zero",
+            ));
+        }
+
+        let mut partitions = Vec::with_capacity(partition_by.len());
+        for value in partition_by {
+            partitions.push(crate::expression::PyExpr::from_bound_into_any(value)?);
+        }
+
+        let mut orders = Vec::with_capacity(order_by.len());
+        for spec in order_by {
+            orders.push(Self::order_from_spec(spec)?);
+        }
+
+        // The outer query projects the selected columns by name, which leaves the
+        // rank column out of the result
+        let (names, references) = {
+            let lock = slf.inner.lock();
+            let names = lock.projected_names(py);
+
+            if lock.cols.is_empty() || names.len() != lock.cols.len() {
+                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "top_n_per_group requires named columns; select the columns explicitly and alias expressions",
+                ));
+            }
+            if let Some(x) = names.iter().enumerate().find(|(i, x)| names[..*i].contains(x)) {
+                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "top_n_per_group requires unique column names, but {:?} is selected twice",
+                    x.1
+                )));
+            }
+            (names, lock.reference_names(py))
+        };
+
+        let rank_alias = match rank_alias {
+            Some(x) if names.contains(&x) => {
+                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "rank_alias {x:?} is already a selected column name"
+                )));
+            }
+            Some(x) => x,
+            None => std::iter::once(String::from("rn"))
+                .chain((1..).map(|i| format!("rn_{i}")))
+                .find(|x| !names.contains(x))
+                .unwrap(),
+        };
+
+        let alias = match alias {
+            Some(x) if x.is_empty() => {
+                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "alias must not be empty",
+                ));
+            }
+            Some(x) => x,
+            None => std::iter::once(String::from("ranked"))
+                .chain((1..).map(|i| format!("ranked_{i}")))
+                .find(|x| !references.contains(x))
+                .unwrap(),
+        };
+
+        let window = super::window::PyWindow {
+            inner: parking_lot::Mutex::new(super::window::WindowInner {
+                partition_by: partitions,
+                orders,
+                frame: None,
+            }),
+        };
+        let rank = sea_query::SimpleExpr::from(sea_query::Func::cust(sea_query::Alias::new("ROW_NUMBER")));
+        let rank = PySelectCol {
+            expr: pyo3::Py::new(py, crate::expression::PyExpr::from(rank))?.into_any(),
+            alias: Some(rank_alias.clone()),
+            window: Some(pyo3::Py::new(py, window)?.into_any()),
+        };
+
+        let mut outer_cols = Vec::with_capacity(names.len());
+        for name in names {
+            let expr = crate::expression::PyExpr::from(sea_query::Expr::column(sea_query::Alias::new(name)));
+            let col = PySelectCol {
+                expr: pyo3::Py::new(py, expr)?.into_any(),
+                alias: None,
+                window: None,
+            };
+            outer_cols.push(pyo3::Py::new(py, col)?.into_any());
+        }
+
+        {
+            let mut lock = slf.inner.lock();
+            let mut inner = std::mem::take(&mut *lock);
+
+            // The CTEs must stay visible to the whole statement, and ORDER BY,
+            // LIMIT, OFFSET and the lock apply to its result
+            lock.with_recursive = inner.with_recursive.take();
+            lock.orders = std::mem::take(&mut inner.orders);
+            lock.limit = inner.limit.take();
+            lock.offset = inner.offset.take();
+            lock.lock = inner.lock.take();
+
+            inner.cols.push(pyo3::Py::new(py, rank)?.into_any());
+
+            let ranked = pyo3::Py::new(
+                py,
+                pyo3::PyClassInitializer::from((
+                    Self {
+                        inner: parking_lot::Mutex::new(inner),
+                    },
+                    PyQueryStatement,
+                )),
+            )?;
+
+            let condition = sea_query::Expr::col(sea_query::Alias::new(rank_alias)).lte(n);
+
+            lock.cols = outer_cols;
+            lock.tables
+                .push(SelectReference::SubQuery(ranked.into_any(), alias));
+            lock.r#where
+                .push(pyo3::Py::new(py, crate::expression::PyExpr::from(condition))?.into_any());
+        }
+
+        Ok(slf)
+    }
+
     #[pyo3(signature=(r#type=String::from("exclusive"), behavior=None, tables=Vec::new()))]
-    fn lock(
-        slf: pyo3::PyRef<'_, Self>,
+    fn lock<'a>(
+        slf: pyo3::PyRef<'a, Self>,
         mut r#type: String,
         mut behavior: Option<String>,
-        tables: Vec<pyo3::Py<pyo3::PyAny>>,
-    ) -> pyo3::PyResult<pyo3::PyRef<'_, Self>> {
+        tables: Vec<pyo3::Bound<'a, pyo3::PyAny>>,
+    ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
         let r#type = {
             r#type.make_ascii_lowercase();
 
@@ -671,18 +1705,7 @@ impl PySelect {
             }
         };
 
-        let mut tbs = Vec::with_capacity(tables.len());
-
-        for tb in tables.into_iter() {
-            let bound = tb.bind(slf.py());
-
-            if let Ok(x) = bound.cast_exact::<crate::table::PyTable>() {
-                let guard = x.get().inner.lock();
-                tbs.push(guard.name.clone_ref(slf.py()));
-            } else {
-                tbs.push(crate::common::PyTableName::from_pyobject(bound)?);
-            }
-        }
+        let tbs = Self::lock_tables(slf.py(), tables.into_iter())?;
 
         {
             let mut lock = slf.inner.lock();
@@ -696,6 +1719,29 @@ impl PySelect {
         Ok(slf)
     }
 
+    #[pyo3(signature=(*tables))]
+    fn of<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        tables: &'a pyo3::Bound<'a, pyo3::types::PyTuple>,
+    ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
+        let tbs = Self::lock_tables(slf.py(), PyTupleMethods::iter(tables))?;
+
+        {
+            let mut lock = slf.inner.lock();
+
+            match &mut lock.lock {
+                Some(x) => x.tables = tbs,
+                None => {
+                    return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "of() requires a lock, call lock() first",
+                    ));
+                }
+            }
+        }
+
+        Ok(slf)
+    }
+
     #[pyo3(signature=(*cols))]
     fn group_by<'a>(
         slf: pyo3::PyRef<'a, Self>,
@@ -715,6 +1761,25 @@ impl PySelect {
         Ok(slf)
     }
 
+    #[pyo3(signature=(*cols))]
+    fn add_group_by<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        cols: &'a pyo3::Bound<'a, pyo3::types::PyTuple>,
+    ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
+        let mut exprs = Vec::with_capacity(PyTupleMethods::len(cols));
+
+        for expr in PyTupleMethods::iter(cols) {
+            exprs.push(crate::expression::PyExpr::from_bound_into_any(expr)?);
+        }
+
+        {
+            let mut lock = slf.inner.lock();
+            lock.groups.extend(exprs);
+        }
+
+        Ok(slf)
+    }
+
     #[pyo3(signature=(statement, r#type=String::from("distinct")))]
     fn union<'a>(
         slf: pyo3::PyRef<'a, Self>,
@@ -737,6 +1802,14 @@ impl PySelect {
             }
         }
 
+        // A cycle of unions could never be built
+        let other = unsafe { statement.cast_unchecked::<PySelect>() };
+        if other.get().inner.lock().has_union_with(slf.py(), slf.as_ptr()) {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "A Select statement cannot union a statement which already unions it",
+            ));
+        }
+
         let r#type = {
             r#type.make_ascii_lowercase();
 
@@ -763,53 +1836,125 @@ impl PySelect {
         Ok(slf)
     }
 
-    #[pyo3(signature=(table, on, r#type=String::new()))]
-    fn join<'a>(
+    #[pyo3(signature=(name, base_select, recursive_select, union_all=true, columns=Vec::new()))]
+    fn with_recursive<'a>(
         slf: pyo3::PyRef<'a, Self>,
-        table: &'a pyo3::Bound<'a, pyo3::PyAny>,
-        on: &'a pyo3::Bound<'a, pyo3::PyAny>,
-        mut r#type: String,
+        name: String,
+        base_select: &'a pyo3::Bound<'a, pyo3::PyAny>,
+        recursive_select: &'a pyo3::Bound<'a, pyo3::PyAny>,
+        union_all: bool,
+        columns: Vec<String>,
     ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
-        let r#type = {
-            r#type.make_ascii_lowercase();
-
-            if r#type.is_empty() {
-                sea_query::JoinType::Join
-            } else if r#type == "cross" {
-                sea_query::JoinType::CrossJoin
-            } else if r#type == "full" {
-                sea_query::JoinType::FullOuterJoin
-            } else if r#type == "inner" {
-                sea_query::JoinType::InnerJoin
-            } else if r#type == "left" {
-                sea_query::JoinType::LeftJoin
-            } else if r#type == "right" {
-                sea_query::JoinType::RightJoin
-            } else {
+        for statement in [base_select, recursive_select] {
+            if std::hint::unlikely(slf.as_ptr() == statement.as_ptr()) {
                 return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    "acceptable join types are: '', 'cross', 'full', 'left', 'right', and 'inner'. got invalid type",
+                    "A Select statement cannot be used in its own WITH clause",
                 ));
             }
-        };
 
+            unsafe {
+                if pyo3::ffi::Py_TYPE(statement.as_ptr()) != crate::typeref::SELECT_STATEMENT_TYPE {
+                    return Err(typeerror!(
+                        "expected Select, got {:?}",
+                        statement.py(),
+                        statement.as_ptr()
+                    ));
+                }
+            }
+        }
+
+        {
+            let mut lock = slf.inner.lock();
+            lock.with_recursive = Some(RecursiveCteOptions {
+                name,
+                columns,
+                base: base_select.clone().unbind(),
+                recursive: recursive_select.clone().unbind(),
+                union_all,
+            });
+        }
+
+        Ok(slf)
+    }
+
+    #[pyo3(signature=(name))]
+    fn add_cte_from<'a>(slf: pyo3::PyRef<'a, Self>, name: &str) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
+        {
+            let mut lock = slf.inner.lock();
+            let table = lock.cte_table_name(slf.py(), name)?;
+            lock.tables.push(SelectReference::TableName(table));
+        }
+
+        Ok(slf)
+    }
+
+    #[pyo3(signature=(name, on=None, r#type=String::new()))]
+    fn join_cte<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        name: &str,
+        on: Option<&'a pyo3::Bound<'a, pyo3::PyAny>>,
+        r#type: String,
+    ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
+        let r#type = join_type_from_str(r#type)?;
+
+        let expr = join_on(on, r#type)?;
+
+        {
+            let mut lock = slf.inner.lock();
+            let table = lock.cte_table_name(slf.py(), name)?;
+
+            lock.join.push(JoinOptions {
+                r#type,
+                table,
+                on: expr,
+                lateral: None,
+                subquery: None,
+            });
+        }
+
+        Ok(slf)
+    }
+
+    #[pyo3(signature=(table, on=None, r#type=String::new()))]
+    fn join<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        table: &'a pyo3::Bound<'a, pyo3::PyAny>,
+        on: Option<&'a pyo3::Bound<'a, pyo3::PyAny>>,
+        r#type: String,
+    ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
+        let r#type = join_type_from_str(r#type)?;
+
+        let mut subquery = None;
         let table = {
             if let Ok(x) = table.cast_exact::<crate::table::PyTable>() {
                 let guard = x.get().inner.lock();
                 guard.name.clone_ref(slf.py())
             } else if let Ok(x) = table.cast_exact::<crate::table::PyAliasedTable>() {
                 x.get().name(slf.py())?
+            } else if let Ok(x) = table.cast_exact::<super::subquery::PySubquery>() {
+                let x = x.get();
+
+                if std::hint::unlikely(slf.as_ptr() == x.select.as_ptr()) {
+                    return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "A Select statement cannot join itself",
+                    ));
+                }
+
+                subquery = Some(x.alias.clone());
+                x.select.clone_ref(slf.py())
             } else {
                 crate::common::PyTableName::from_pyobject(table)?
             }
         };
 
-        let expr = crate::expression::PyExpr::from_bound_into_any(on.clone())?;
+        let expr = join_on(on, r#type)?;
 
         let join_expr = JoinOptions {
             r#type,
             table,
             on: expr,
             lateral: None,
+            subquery,
         };
 
         {
@@ -820,36 +1965,54 @@ impl PySelect {
         Ok(slf)
     }
 
-    #[pyo3(signature=(query, alias, on, r#type=String::new()))]
+    #[pyo3(signature=(query, alias, on=None, r#type=String::new()))]
     fn join_lateral<'a>(
         slf: pyo3::PyRef<'a, Self>,
         query: &'a pyo3::Bound<'a, pyo3::PyAny>,
         alias: String,
-        on: &'a pyo3::Bound<'a, pyo3::PyAny>,
-        mut r#type: String,
+        on: Option<&'a pyo3::Bound<'a, pyo3::PyAny>>,
+        r#type: String,
     ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
-        let r#type = {
-            r#type.make_ascii_lowercase();
+        let r#type = join_type_from_str(r#type)?;
 
-            if r#type.is_empty() {
-                sea_query::JoinType::Join
-            } else if r#type == "cross" {
-                sea_query::JoinType::CrossJoin
-            } else if r#type == "full" {
-                sea_query::JoinType::FullOuterJoin
-            } else if r#type == "inner" {
-                sea_query::JoinType::InnerJoin
-            } else if r#type == "left" {
-                sea_query::JoinType::LeftJoin
-            } else if r#type == "right" {
-                sea_query::JoinType::RightJoin
-            } else {
-                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    "acceptable join types are: '', 'cross', 'full', 'left', 'right', and 'inner'. got invalid type",
+        unsafe {
+            if pyo3::ffi::Py_TYPE(query.as_ptr()) != crate::typeref::SELECT_STATEMENT_TYPE {
+                return Err(typeerror!(
+                    "expected Select, got {:?}",
+                    query.py(),
+                    query.as_ptr()
                 ));
             }
+        }
+
+        let expr = join_on(on, r#type)?;
+
+        let join_expr = JoinOptions {
+            r#type,
+            table: query.clone().unbind(),
+            on: expr,
+            lateral: Some(alias),
+            subquery: None,
         };
 
+        {
+            let mut lock = slf.inner.lock();
+            lock.join.push(join_expr);
+        }
+
+        Ok(slf)
+    }
+
+    #[pyo3(signature=(query, alias, on=None, r#type=String::new()))]
+    fn join_subquery<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        query: &'a pyo3::Bound<'a, pyo3::PyAny>,
+        alias: String,
+        on: Option<&'a pyo3::Bound<'a, pyo3::PyAny>>,
+        r#type: String,
+    ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
+        let r#type = join_type_from_str(r#type)?;
+
         unsafe {
             if pyo3::ffi::Py_TYPE(query.as_ptr()) != crate::typeref::SELECT_STATEMENT_TYPE {
                 return Err(typeerror!(
@@ -860,13 +2023,20 @@ impl PySelect {
             }
         }
 
-        let expr = crate::expression::PyExpr::from_bound_into_any(on.clone())?;
+        if std::hint::unlikely(slf.as_ptr() == query.as_ptr()) {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "A Select statement cannot join itself",
+            ));
+        }
+
+        let expr = join_on(on, r#type)?;
 
         let join_expr = JoinOptions {
             r#type,
             table: query.clone().unbind(),
             on: expr,
-            lateral: Some(alias),
+            lateral: None,
+            subquery: Some(alias),
         };
 
         {
@@ -904,17 +2074,83 @@ impl PySelect {
         backend: &pyo3::Bound<'_, pyo3::PyAny>,
     ) -> pyo3::PyResult<(String, pyo3::Py<pyo3::PyAny>)> {
         let lock = self.inner.lock();
-        let stmt = lock.as_statement(backend.py());
+        let stmt = lock.as_backend_statement(backend)?;
+        let with = lock
+            .with_recursive
+            .as_ref()
+            .map(|x| x.as_with_clause(backend.py()));
         drop(lock);
 
-        build_query_parts!(backend => build_collect_any_into(stmt))
+        match with {
+            Some(with) => build_query_parts!(backend => build_collect_any_into(stmt.with(with))),
+            None => build_query_parts!(backend => build_collect_any_into(stmt)),
+        }
     }
 
-    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<String> {
+    #[pyo3(signature=(backend, pretty=false, quote_identifiers=true))]
+    fn to_sql(
+        &self,
+        backend: &pyo3::Bound<'_, pyo3::PyAny>,
+        pretty: bool,
+        quote_identifiers: bool,
+    ) -> pyo3::PyResult<String> {
         let lock = self.inner.lock();
-        let stmt = lock.as_statement(backend.py());
+        let stmt = lock.as_backend_statement(backend)?;
+        let with = lock
+            .with_recursive
+            .as_ref()
+            .map(|x| x.as_with_clause(backend.py()));
         drop(lock);
 
-        build_query_string!(backend => build_collect_any_into(stmt))
+        let sql: pyo3::PyResult<String> = match with {
+            Some(with) => build_query_string!(backend => build_collect_any_into(stmt.with(with))),
+            None => build_query_string!(backend => build_collect_any_into(stmt)),
+        };
+        let sql = crate::format::unquote_if(sql?, backend, quote_identifiers)?;
+        crate::format::format_if(sql, backend, pretty)
+    }
+
+    #[pyo3(signature=(backend, analyze=false, format=None))]
+    fn explain(
+        &self,
+        backend: &pyo3::Bound<'_, pyo3::PyAny>,
+        analyze: bool,
+        format: Option<String>,
+    ) -> pyo3::PyResult<String> {
+        use crate::backend::BackendKind;
+
+        let format = format.map(|x| x.to_ascii_uppercase());
+
+        let prefix = match (crate::backend::into_backend_kind(backend)?, format.as_deref()) {
+            (BackendKind::Postgres, None) if analyze => "EXPLAIN ANALYZE".to_owned(),
+            (BackendKind::Postgres, None) => "EXPLAIN".to_owned(),
+            (BackendKind::Postgres, Some(x @ ("TEXT" | "JSON" | "XML" | "YAML"))) if analyze => {
+                format!("EXPLAIN (ANALYZE, FORMAT {x})")
+            }
+            (BackendKind::Postgres, Some(x @ ("TEXT" | "JSON" | "XML" | "YAML"))) => {
+                format!("EXPLAIN (FORMAT {x})")
+            }
+            // `EXPLAIN ANALYZE` only has the tree format
+            (BackendKind::Mysql, None | Some("TREE")) if analyze => "EXPLAIN ANALYZE".to_owned(),
+            (BackendKind::Mysql, None) => "EXPLAIN".to_owned(),
+            (BackendKind::Mysql, Some(x @ ("TRADITIONAL" | "JSON" | "TREE"))) if !analyze => {
+                format!("EXPLAIN FORMAT={x}")
+            }
+            (BackendKind::Sqlite, _) if analyze => {
+                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "EXPLAIN ANALYZE is not supported by SQLite",
+                ));
+            }
+            (BackendKind::Sqlite, None) => "EXPLAIN QUERY PLAN".to_owned(),
+            (_, Some(x)) => {
+                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "EXPLAIN{} format {x:?} is not supported by {}",
+                    if analyze { " ANALYZE" } else { "" },
+                    backend.str()?,
+                )));
+            }
+        };
+
+        Ok(format!("{prefix} {}", self.to_sql(backend, false, true)?))
     }
 }
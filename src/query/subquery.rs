@@ -0,0 +1,92 @@
+use sea_query::IntoIden;
+
+#[pyo3::pyclass(module = "rapidquery._lib", name = "_SubqueryColumnsSequence", frozen)]
+#[allow(non_camel_case_types)]
+pub struct Py_SubqueryColumnsSequence {
+    // Always is `PySelect`
+    pub select: pyo3::Py<pyo3::PyAny>,
+    pub alias: String,
+}
+
+impl Py_SubqueryColumnsSequence {
+    fn projected_names(&self, py: pyo3::Python) -> Vec<String> {
+        let select = unsafe { self.select.cast_bound_unchecked::<super::select::PySelect>(py) };
+        let lock = select.get().inner.lock();
+        lock.projected_names(py)
+    }
+
+    fn column_ref(&self, py: pyo3::Python, name: String) -> pyo3::PyResult<pyo3::Py<pyo3::PyAny>> {
+        let result = crate::common::PyColumnRef {
+            col: crate::common::ColumnNameOrAstrisk::Name(sea_query::Alias::new(name).into_iden()),
+            table: Some(sea_query::Alias::new(&self.alias).into_iden()),
+            schema: None,
+            converted: Default::default(),
+        };
+        pyo3::Py::new(py, result).map(|x| x.into_any())
+    }
+}
+
+#[pyo3::pymethods]
+impl Py_SubqueryColumnsSequence {
+    fn __getattr__(slf: pyo3::PyRef<'_, Self>, name: String) -> pyo3::PyResult<pyo3::Py<pyo3::PyAny>> {
+        Self::get(slf, name)
+    }
+
+    fn get(slf: pyo3::PyRef<'_, Self>, name: String) -> pyo3::PyResult<pyo3::Py<pyo3::PyAny>> {
+        if !slf.projected_names(slf.py()).contains(&name) {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyKeyError, _>(name));
+        }
+
+        slf.column_ref(slf.py(), name)
+    }
+
+    fn to_list(slf: pyo3::PyRef<'_, Self>) -> pyo3::PyResult<Vec<pyo3::Py<pyo3::PyAny>>> {
+        slf.projected_names(slf.py())
+            .into_iter()
+            .map(|name| slf.column_ref(slf.py(), name))
+            .collect()
+    }
+
+    fn __len__(slf: pyo3::PyRef<'_, Self>) -> usize {
+        slf.projected_names(slf.py()).len()
+    }
+}
+
+/// A [`super::select::PySelect`] used as a derived table under an alias.
+#[pyo3::pyclass(module = "rapidquery._lib", name = "Subquery", frozen)]
+pub struct PySubquery {
+    // Always is `PySelect`
+    pub select: pyo3::Py<pyo3::PyAny>,
+    pub alias: String,
+}
+
+#[pyo3::pymethods]
+impl PySubquery {
+    #[getter]
+    fn select(&self, py: pyo3::Python) -> pyo3::Py<pyo3::PyAny> {
+        self.select.clone_ref(py)
+    }
+
+    #[getter]
+    fn alias(&self) -> String {
+        self.alias.clone()
+    }
+
+    #[getter]
+    fn columns(&self, py: pyo3::Python) -> pyo3::PyResult<pyo3::Py<pyo3::PyAny>> {
+        let seq = Py_SubqueryColumnsSequence {
+            select: self.select.clone_ref(py),
+            alias: self.alias.clone(),
+        };
+        pyo3::Py::new(py, seq).map(|x| x.into_any())
+    }
+
+    #[getter]
+    fn c(&self, py: pyo3::Python) -> pyo3::PyResult<pyo3::Py<pyo3::PyAny>> {
+        self.columns(py)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<Subquery alias={:?}>", self.alias)
+    }
+}
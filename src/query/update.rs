@@ -13,6 +13,10 @@ pub struct UpdateInner {
     // Always is `Vec<String, PyExpr>`
     pub values: Vec<(String, pyo3::Py<pyo3::PyAny>)>,
 
+    // Always is `Vec<String, PyExpr>`; assignments of `set_expr`, which
+    // `values` doesn't replace
+    pub exprs: Vec<(String, pyo3::Py<pyo3::PyAny>)>,
+
     // Always is `Vec<PyExpr>`
     pub r#where: Vec<pyo3::Py<pyo3::PyAny>>,
     pub limit: Option<u64>,
@@ -23,6 +27,26 @@ pub struct UpdateInner {
 }
 
 impl UpdateInner {
+    /// Returns the SET clause assignments, in the order they were given.
+    ///
+    /// `set_expr` assignments replace those of `values` for the same column in
+    /// place; the others come after the `values` ones.
+    fn assignments(&self) -> Vec<&(String, pyo3::Py<pyo3::PyAny>)> {
+        let mut result: Vec<_> = self
+            .values
+            .iter()
+            .map(|value| self.exprs.iter().find(|(k, _)| *k == value.0).unwrap_or(value))
+            .collect();
+
+        for expr in &self.exprs {
+            if !self.values.iter().any(|(k, _)| *k == expr.0) {
+                result.push(expr);
+            }
+        }
+
+        result
+    }
+
     fn as_statement(&self, py: pyo3::Python) -> sea_query::UpdateStatement {
         let mut stmt = sea_query::UpdateStatement::new();
 
@@ -45,7 +69,7 @@ impl UpdateInner {
             stmt.limit(n);
         }
 
-        stmt.values(self.values.iter().map(|(key, val)| {
+        stmt.values(self.assignments().into_iter().map(|(key, val)| {
             let val = unsafe { val.cast_bound_unchecked::<crate::expression::PyExpr>(py) };
 
             (sea_query::Alias::new(key), val.get().inner.clone())
@@ -220,33 +244,85 @@ impl PyUpdate {
         Ok(slf)
     }
 
-    #[pyo3(signature=(**kwds))]
+    #[pyo3(signature=(mapping=None, /, **kwds))]
     fn values<'a>(
         slf: pyo3::PyRef<'a, Self>,
+        mapping: Option<&'a pyo3::Bound<'_, pyo3::types::PyDict>>,
         kwds: Option<&'a pyo3::Bound<'_, pyo3::types::PyDict>>,
     ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
-        if kwds.is_none() {
+        if mapping.is_none() && kwds.is_none() {
             return Ok(slf);
         }
 
-        let kwds = unsafe { kwds.unwrap_unchecked() };
         let mut vals = Vec::<(String, pyo3::Py<pyo3::PyAny>)>::new();
 
-        unsafe {
-            for (key, value) in kwds.iter() {
-                let key = key.extract::<String>().unwrap_unchecked();
-                vals.push((key, crate::expression::PyExpr::from_bound_into_any(value)?));
+        for dict in mapping.into_iter().chain(kwds) {
+            for (key, value) in dict.iter() {
+                let key = unsafe {
+                    if pyo3::ffi::PyUnicode_Check(key.as_ptr()) == 0 {
+                        return Err(typeerror!(
+                            "expected str as column name, got {:?}",
+                            key.py(),
+                            key.as_ptr()
+                        ));
+                    }
+
+                    key.extract::<String>().unwrap_unchecked()
+                };
+                let value = crate::expression::PyExpr::from_bound_into_any(value)?;
+
+                match vals.iter_mut().find(|(k, _)| *k == key) {
+                    Some(x) => x.1 = value,
+                    None => vals.push((key, value)),
+                }
             }
         }
 
         {
             let mut lock = slf.inner.lock();
+
+            // `set_expr` assignments of these columns are overridden
+            lock.exprs.retain(|(key, _)| !vals.iter().any(|(k, _)| k == key));
             lock.values = vals;
         }
 
         Ok(slf)
     }
 
+    fn set_expr<'a>(
+        slf: pyo3::PyRef<'a, Self>,
+        column: &'a pyo3::Bound<'_, pyo3::PyAny>,
+        expr: pyo3::Bound<'a, pyo3::PyAny>,
+    ) -> pyo3::PyResult<pyo3::PyRef<'a, Self>> {
+        let column = unsafe {
+            if pyo3::ffi::Py_TYPE(column.as_ptr()) == crate::typeref::COLUMN_TYPE {
+                let col = column.cast_unchecked::<crate::column::PyColumn>();
+                col.get().inner.lock().name.clone()
+            } else if pyo3::ffi::PyUnicode_CheckExact(column.as_ptr()) == 1 {
+                column.extract::<String>().unwrap_unchecked()
+            } else {
+                return Err(typeerror!(
+                    "expected Column or str, got {:?}",
+                    column.py(),
+                    column.as_ptr()
+                ));
+            }
+        };
+
+        let expr = crate::expression::PyExpr::from_bound_into_any(expr)?;
+
+        {
+            let mut lock = slf.inner.lock();
+
+            match lock.exprs.iter_mut().find(|(key, _)| *key == column) {
+                Some(x) => x.1 = expr,
+                None => lock.exprs.push((column, expr)),
+            }
+        }
+
+        Ok(slf)
+    }
+
     fn build(
         &self,
         backend: &pyo3::Bound<'_, pyo3::PyAny>,
@@ -258,12 +334,20 @@ impl PyUpdate {
         build_query_parts!(backend => build_collect_any_into(stmt))
     }
 
-    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<String> {
+    #[pyo3(signature=(backend, pretty=false, quote_identifiers=true))]
+    fn to_sql(
+        &self,
+        backend: &pyo3::Bound<'_, pyo3::PyAny>,
+        pretty: bool,
+        quote_identifiers: bool,
+    ) -> pyo3::PyResult<String> {
         let lock = self.inner.lock();
         let stmt = lock.as_statement(backend.py());
         drop(lock);
 
-        build_query_string!(backend => build_collect_any_into(stmt))
+        let sql: pyo3::PyResult<String> = build_query_string!(backend => build_collect_any_into(stmt));
+        let sql = crate::format::unquote_if(sql?, backend, quote_identifiers)?;
+        crate::format::format_if(sql, backend, pretty)
     }
 
     fn __repr__(&self) -> String {
@@ -318,12 +402,13 @@ impl PyUpdate {
 
         write!(s, " values=[").unwrap();
 
-        let n = lock.values.len();
-        for (index, expr) in lock.values.iter().enumerate() {
+        let assignments = lock.assignments();
+        let n = assignments.len();
+        for (index, expr) in assignments.into_iter().enumerate() {
             if index + 1 == n {
-                write!(s, "({}, {})]", &expr.0, &expr.1).unwrap();
+                write!(s, "({}, {})]", expr.0, expr.1).unwrap();
             } else {
-                write!(s, "({}, {}), ", &expr.0, &expr.1).unwrap();
+                write!(s, "({}, {}), ", expr.0, expr.1).unwrap();
             }
         }
 
@@ -25,6 +25,7 @@ impl Py_AliasedTableColumnsSequence {
             col: crate::common::ColumnNameOrAstrisk::Name(col_name.into_iden()),
             table: Some(slf.alias.clone()),
             schema: None,
+            converted: Default::default(),
         };
         pyo3::Py::new(slf.py(), result).map(|x| x.into_any())
     }
@@ -45,10 +46,28 @@ impl Py_AliasedTableColumnsSequence {
             col: crate::common::ColumnNameOrAstrisk::Name(col_name.into_iden()),
             table: Some(slf.alias.clone()),
             schema: None,
+            converted: Default::default(),
         };
         pyo3::Py::new(slf.py(), result).map(|x| x.into_any())
     }
 
+    fn to_list(slf: pyo3::PyRef<'_, Self>) -> pyo3::PyResult<Vec<pyo3::Py<pyo3::PyAny>>> {
+        let lock = slf.inner.lock();
+
+        lock.columns
+            .keys()
+            .map(|name| {
+                let result = crate::common::PyColumnRef {
+                    col: crate::common::ColumnNameOrAstrisk::Name(sea_query::Alias::new(name).into_iden()),
+                    table: Some(slf.alias.clone()),
+                    schema: None,
+                    converted: Default::default(),
+                };
+                pyo3::Py::new(slf.py(), result).map(|x| x.into_any())
+            })
+            .collect()
+    }
+
     fn __len__(slf: pyo3::PyRef<'_, Self>) -> usize {
         let lock = slf.inner.lock();
         lock.columns.len()
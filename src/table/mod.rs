@@ -10,4 +10,4 @@ pub use ops::{
     PyAlterTableDropForeignKeyOption, PyAlterTableModifyColumnOption, PyAlterTableOptionMeta,
     PyAlterTableRenameColumnOption, PyDropTable, PyRenameTable, PyTruncateTable,
 };
-pub use table::{PyTable, Py_TableColumnsSequence};
+pub use table::{build_schema_ordered, drop_all, PyTable, Py_TableColumnsSequence};
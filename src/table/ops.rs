@@ -151,12 +151,13 @@ impl PyDropTable {
         pyo3::Py::new(py, pyo3::PyClassInitializer::from((slf, PySchemaStatement))).unwrap()
     }
 
-    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<String> {
+    #[pyo3(signature=(backend, pretty=false))]
+    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>, pretty: bool) -> pyo3::PyResult<String> {
         let lock = self.inner.lock();
         let stmt = lock.as_statement(backend.py());
         drop(lock);
 
-        build_schema!(backend => build_any(stmt))
+        build_schema!(backend => build_any(stmt)).and_then(|x| crate::format::format_if(x, backend, pretty))
     }
 
     fn __repr__(&self) -> String {
@@ -281,14 +282,13 @@ impl PyRenameTable {
         pyo3::Py::new(py, pyo3::PyClassInitializer::from((slf, PySchemaStatement))).unwrap()
     }
 
-    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<String> {
+    #[pyo3(signature=(backend, pretty=false))]
+    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>, pretty: bool) -> pyo3::PyResult<String> {
         let lock = self.inner.lock();
         let stmt = lock.as_statement(backend.py());
         drop(lock);
 
-        build_schema!(
-            backend => build_any(stmt)
-        )
+        build_schema!(backend => build_any(stmt)).and_then(|x| crate::format::format_if(x, backend, pretty))
     }
 
     fn __repr__(&self) -> String {
@@ -366,14 +366,13 @@ impl PyTruncateTable {
         pyo3::Py::new(py, pyo3::PyClassInitializer::from((slf, PySchemaStatement))).unwrap()
     }
 
-    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<String> {
+    #[pyo3(signature=(backend, pretty=false))]
+    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>, pretty: bool) -> pyo3::PyResult<String> {
         let lock = self.inner.lock();
         let stmt = lock.as_statement(backend.py());
         drop(lock);
 
-        build_schema!(
-             backend => build_any(stmt)
-        )
+        build_schema!(backend => build_any(stmt)).and_then(|x| crate::format::format_if(x, backend, pretty))
     }
 
     fn __repr__(&self) -> String {
@@ -549,14 +548,53 @@ impl PyAlterTableRenameColumnOption {
 )]
 pub struct PyAlterTableDropColumnOption {
     name: String,
+    if_exists: bool,
+    cascade: bool,
+}
+
+/// Column name of a PostgreSQL `DROP COLUMN`, carrying its `IF EXISTS` and
+/// `CASCADE` flags.
+///
+/// sea-query's `DropColumn` only takes an identifier, and renders it right
+/// after `DROP COLUMN `.
+#[derive(Debug)]
+struct DropColumnIden {
+    name: String,
+    if_exists: bool,
+    cascade: bool,
+}
+
+impl sea_query::Iden for DropColumnIden {
+    fn prepare(&self, s: &mut dyn std::fmt::Write, q: sea_query::Quote) {
+        if self.if_exists {
+            write!(s, "IF EXISTS ").unwrap();
+        }
+
+        write!(s, "{}{}{}", q.left(), self.quoted(q), q.right()).unwrap();
+
+        if self.cascade {
+            write!(s, " CASCADE").unwrap();
+        }
+    }
+
+    fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+        write!(s, "{}", self.name).unwrap();
+    }
 }
 
 #[pyo3::pymethods]
 impl PyAlterTableDropColumnOption {
     #[new]
-    #[pyo3(signature=(name))]
-    fn new(name: String) -> pyo3::PyResult<(Self, PyAlterTableOptionMeta)> {
-        Ok((Self { name }, PyAlterTableOptionMeta))
+    #[pyo3(signature=(name, if_exists=false, cascade=false))]
+    fn new(name: String, if_exists: bool, cascade: bool) -> pyo3::PyResult<(Self, PyAlterTableOptionMeta)> {
+        Ok((
+            Self {
+                name,
+                if_exists,
+                cascade,
+            },
+            PyAlterTableOptionMeta,
+        ))
     }
 
     #[getter]
@@ -564,8 +602,31 @@ impl PyAlterTableDropColumnOption {
         self.name.clone()
     }
 
+    #[getter]
+    fn if_exists(&self) -> bool {
+        self.if_exists
+    }
+
+    #[getter]
+    fn cascade(&self) -> bool {
+        self.cascade
+    }
+
     fn __repr__(&self) -> String {
-        format!("<AlterTableDropColumnOption {:?}>", self.name,)
+        use std::io::Write;
+
+        let mut s: Vec<u8> = Vec::with_capacity(20);
+
+        write!(s, "<AlterTableDropColumnOption {:?}", self.name).unwrap();
+        if self.if_exists {
+            write!(s, " if_exists=True").unwrap();
+        }
+        if self.cascade {
+            write!(s, " cascade=True").unwrap();
+        }
+        write!(s, ">").unwrap();
+
+        unsafe { String::from_utf8_unchecked(s) }
     }
 }
 
@@ -655,7 +716,43 @@ impl AlterTableInner {
         }
     }
 
-    fn as_statement(&self, py: pyo3::Python) -> sea_query::TableAlterStatement {
+    /// Rejects the options which `backend` can't render.
+    fn check_backend(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<()> {
+        let py = backend.py();
+
+        let has_pg_drop = self.options.iter().any(|op| {
+            op.bind(py)
+                .cast_exact::<PyAlterTableDropColumnOption>()
+                .is_ok_and(|x| x.get().if_exists || x.get().cascade)
+        });
+
+        let kind = crate::backend::into_backend_kind(backend)?;
+
+        if has_pg_drop && kind != crate::backend::BackendKind::Postgres {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "DROP COLUMN IF EXISTS / CASCADE is only supported by PostgreSQL backend",
+            ));
+        }
+
+        for op in self.options.iter() {
+            if let Ok(x) = op.bind(py).cast_exact::<PyAlterTableAddForeignKeyOption>() {
+                let spec = unsafe {
+                    x.get()
+                        .foreign_key
+                        .cast_bound_unchecked::<crate::foreign_key::PyForeignKey>(py)
+                };
+                spec.get().inner.lock().check_backend(kind)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn as_statement(
+        &self,
+        py: pyo3::Python,
+        backend: crate::backend::BackendKind,
+    ) -> pyo3::PyResult<sea_query::TableAlterStatement> {
         let mut stmt = sea_query::TableAlterStatement::new();
 
         let x = unsafe { self.name.cast_bound_unchecked::<crate::common::PyTableName>(py) };
@@ -670,7 +767,7 @@ impl AlterTableInner {
                     let x = bound.get();
 
                     let column = x.column.cast_bound_unchecked::<crate::column::PyColumn>(py);
-                    let column = column.get().inner.lock().as_column_def(py);
+                    let column = column.get().inner.lock().as_column_def(py, backend)?;
 
                     if x.if_not_exists {
                         stmt.add_column_if_not_exists(column);
@@ -684,14 +781,31 @@ impl AlterTableInner {
                     let spec = x
                         .foreign_key
                         .cast_bound_unchecked::<crate::foreign_key::PyForeignKey>(py);
-                    let spec = spec.get().inner.lock().as_statement(py);
-
-                    stmt.add_foreign_key(spec.get_foreign_key());
+                    let spec = spec.get().inner.lock();
+
+                    if backend == crate::backend::BackendKind::Postgres && spec.r#match.is_some() {
+                        // sea-query has no MATCH, so the constraint is rendered by us;
+                        // a column change without type renders nothing but its extra
+                        let constraint = spec.as_postgres_constraint(py, sea_query::Mode::TableAlter);
+                        stmt.modify_column(
+                            sea_query::ColumnDef::new(sea_query::Alias::new("")).extra(constraint),
+                        );
+                    } else {
+                        stmt.add_foreign_key(spec.as_statement(py).get_foreign_key());
+                    }
                 } else if op_type == PyAlterTableDropColumnOption::type_object_raw(py) {
                     let bound = op.cast_bound_unchecked::<PyAlterTableDropColumnOption>(py);
                     let x = bound.get();
 
-                    stmt.drop_column(sea_query::Alias::new(&x.name).into_iden());
+                    if x.if_exists || x.cascade {
+                        stmt.drop_column(DropColumnIden {
+                            name: x.name.clone(),
+                            if_exists: x.if_exists,
+                            cascade: x.cascade,
+                        });
+                    } else {
+                        stmt.drop_column(sea_query::Alias::new(&x.name).into_iden());
+                    }
                 } else if op_type == PyAlterTableDropForeignKeyOption::type_object_raw(py) {
                     let bound = op.cast_bound_unchecked::<PyAlterTableDropForeignKeyOption>(py);
                     let x = bound.get();
@@ -702,7 +816,7 @@ impl AlterTableInner {
                     let x = bound.get();
 
                     let column = x.column.cast_bound_unchecked::<crate::column::PyColumn>(py);
-                    let column = column.get().inner.lock().as_column_def(py);
+                    let column = column.get().inner.lock().as_column_def(py, backend)?;
 
                     stmt.modify_column(column);
                 } else if op_type == PyAlterTableRenameColumnOption::type_object_raw(py) {
@@ -717,7 +831,7 @@ impl AlterTableInner {
             }
         }
 
-        stmt
+        Ok(stmt)
     }
 }
 
@@ -726,6 +840,32 @@ pub struct PyAlterTable {
     inner: parking_lot::Mutex<AlterTableInner>,
 }
 
+impl PyAlterTable {
+    /// Creates `ALTER TABLE name RENAME COLUMN from_name TO to_name`.
+    pub(crate) fn rename_column(
+        py: pyo3::Python,
+        name: pyo3::Py<pyo3::PyAny>,
+        from_name: String,
+        to_name: String,
+    ) -> pyo3::PyResult<pyo3::Py<Self>> {
+        let option = pyo3::Py::new(
+            py,
+            (
+                PyAlterTableRenameColumnOption { from_name, to_name },
+                PyAlterTableOptionMeta,
+            ),
+        )?;
+
+        let slf = Self {
+            inner: parking_lot::Mutex::new(AlterTableInner {
+                name,
+                options: vec![option.into_any()],
+            }),
+        };
+        pyo3::Py::new(py, pyo3::PyClassInitializer::from((slf, PySchemaStatement)))
+    }
+}
+
 #[pyo3::pymethods]
 impl PyAlterTable {
     #[new]
@@ -820,14 +960,17 @@ impl PyAlterTable {
         pyo3::Py::new(py, pyo3::PyClassInitializer::from((slf, PySchemaStatement))).unwrap()
     }
 
-    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<String> {
+    #[pyo3(signature=(backend, pretty=false))]
+    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>, pretty: bool) -> pyo3::PyResult<String> {
         let lock = self.inner.lock();
-        let stmt = lock.as_statement(backend.py());
-        drop(lock);
+        lock.check_backend(backend)?;
+        let stmt = lock.as_statement(backend.py(), crate::backend::into_backend_kind(backend)?)?;
 
-        build_schema!(
+        let sql = build_schema!(
            backend => build_any(stmt)
-        )
+        )?;
+
+        crate::format::format_if(sql, backend, pretty)
     }
 
     fn __repr__(&self) -> String {
@@ -1,8 +1,75 @@
 use crate::backend::PySchemaStatement;
-use pyo3::types::PyAnyMethods;
+use pyo3::types::{PyAnyMethods, PyDictMethods};
 
 type ColumnsSequence = indexmap::IndexMap<String, pyo3::Py<pyo3::PyAny>>;
 
+type StorageParams = indexmap::IndexMap<String, pyo3::Py<pyo3::PyAny>>;
+
+/// Returns `true` if `name` is a storage parameter name, an identifier which
+/// may be prefixed by a namespace, like `toast.autovacuum_enabled`.
+fn is_storage_param_name(name: &str) -> bool {
+    let mut parts = name.split('.');
+    let is_identifier = |part: &str| {
+        part.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    };
+
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(name), None, _) => is_identifier(name),
+        (Some(namespace), Some(name), None) => is_identifier(namespace) && is_identifier(name),
+        _ => false,
+    }
+}
+
+/// Validates the PostgreSQL storage parameters given by user.
+///
+/// Keys must be `str` storage parameter names, and values must be `bool`,
+/// `int`, finite `float` or `str`.
+fn storage_params_from_dict(dict: &pyo3::Bound<'_, pyo3::types::PyDict>) -> pyo3::PyResult<StorageParams> {
+    let mut params = StorageParams::with_capacity(dict.len());
+
+    for (key, value) in dict.iter() {
+        unsafe {
+            if pyo3::ffi::PyUnicode_Check(key.as_ptr()) == 0 {
+                return Err(typeerror!(
+                    "expected str as storage parameter name, got {:?}",
+                    key.py(),
+                    key.as_ptr()
+                ));
+            }
+
+            if pyo3::ffi::PyBool_Check(value.as_ptr()) == 0
+                && pyo3::ffi::PyLong_Check(value.as_ptr()) == 0
+                && pyo3::ffi::PyFloat_Check(value.as_ptr()) == 0
+                && pyo3::ffi::PyUnicode_Check(value.as_ptr()) == 0
+            {
+                return Err(typeerror!(
+                    "expected bool, int, float or str as storage parameter value, got {:?}",
+                    value.py(),
+                    value.as_ptr()
+                ));
+            }
+
+            let key = key.extract::<String>().unwrap_unchecked();
+            if !is_storage_param_name(&key) {
+                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "invalid storage parameter name: {key:?}"
+                )));
+            }
+
+            if pyo3::ffi::PyFloat_Check(value.as_ptr()) == 1 && !value.extract::<f64>()?.is_finite() {
+                return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "storage parameter {key:?} must be a finite number"
+                )));
+            }
+
+            params.insert(key, value.unbind());
+        }
+    }
+
+    Ok(params)
+}
+
 pub struct TableInner {
     // Always is `TableName`
     pub name: pyo3::Py<pyo3::PyAny>,
@@ -20,16 +87,50 @@ pub struct TableInner {
     pub checks: Vec<pyo3::Py<pyo3::PyAny>>,
     pub if_not_exists: bool,
     pub temporary: bool,
+    pub without_rowid: bool,
     pub comment: Option<String>,
     pub engine: Option<String>,
     pub collate: Option<String>,
     pub character_set: Option<String>,
     pub extra: Option<String>,
+
+    // Always is `IndexMap<String, bool | int | float | str>`
+    pub storage_params: StorageParams,
 }
 
 impl TableInner {
+    /// Renders `storage_params` as PostgreSQL `WITH (...)` clause.
+    fn storage_params_clause(&self, py: pyo3::Python) -> Option<String> {
+        if self.storage_params.is_empty() {
+            return None;
+        }
+
+        let params: Vec<String> = self
+            .storage_params
+            .iter()
+            .map(|(key, value)| {
+                let value = value.bind(py);
+
+                if unsafe { pyo3::ffi::PyBool_Check(value.as_ptr()) == 1 } {
+                    format!("{key}={}", value.is_truthy().unwrap_or_default())
+                } else if unsafe { pyo3::ffi::PyUnicode_Check(value.as_ptr()) == 1 } {
+                    // Quoted as a string literal, like PostgreSQL itself dumps them
+                    format!("{key}='{}'", value.to_string().replace('\'', "''"))
+                } else {
+                    format!("{key}={value}")
+                }
+            })
+            .collect();
+
+        Some(format!("WITH ({})", params.join(", ")))
+    }
+
     #[optimize(speed)]
-    pub fn as_table_create_statement(&self, py: pyo3::Python) -> sea_query::TableCreateStatement {
+    pub(crate) fn as_table_create_statement(
+        &self,
+        py: pyo3::Python,
+        backend: crate::backend::BackendKind,
+    ) -> pyo3::PyResult<sea_query::TableCreateStatement> {
         let mut stmt = sea_query::TableCreateStatement::new();
 
         stmt.table(unsafe {
@@ -41,31 +142,50 @@ impl TableInner {
             let colbound = unsafe { col.cast_bound_unchecked::<crate::column::PyColumn>(py) };
             let collock = colbound.get().inner.lock();
 
-            stmt.col(collock.as_column_def(py));
+            stmt.col(collock.as_column_def(py, backend)?);
         }
 
         for ix in self.indexes.iter() {
             let ixbound = unsafe { ix.cast_bound_unchecked::<crate::index::PyIndex>(py) };
             let ixlock = ixbound.get().inner.lock();
 
-            // We only want PRIMARY KEY indexes here.
-            if ixlock.options & (crate::index::IndexOptions::Primary as u8) > 0 {
-                stmt.primary_key(&mut ixlock.as_statement(py));
+            // We only want PRIMARY KEY indexes and UNIQUE constraints here.
+            if !ixlock.is_table_constraint() {
+                continue;
+            }
+
+            // sea-query has no storage clauses, so such constraints are rendered
+            // by us on PostgreSQL, the only backend to support them
+            if backend == crate::backend::BackendKind::Postgres && ixlock.has_storage_clause() {
+                stmt.col(sea_query::ColumnDef::new(ConstraintIden(
+                    ixlock.as_postgres_constraint(py),
+                )));
+            } else if ixlock.options & (crate::index::IndexOptions::Primary as u8) > 0 {
+                stmt.primary_key(&mut ixlock.as_statement(py, backend)?);
+            } else {
+                stmt.index(&mut ixlock.as_statement(py, backend)?);
             }
         }
 
         for fk in self.foreign_keys.iter() {
             let fkbound = unsafe { fk.cast_bound_unchecked::<crate::foreign_key::PyForeignKey>(py) };
-
             let fklock = fkbound.get().inner.lock();
-            stmt.foreign_key(&mut fklock.as_statement(py));
+
+            // sea-query has no MATCH, so such foreign keys are rendered by us on
+            // PostgreSQL, the only backend to support it
+            if backend == crate::backend::BackendKind::Postgres && fklock.r#match.is_some() {
+                let constraint = fklock.as_postgres_constraint(py, sea_query::Mode::Creation);
+                stmt.col(sea_query::ColumnDef::new(ConstraintIden(constraint)));
+            } else {
+                stmt.foreign_key(&mut fklock.as_statement(py));
+            }
         }
 
         for check in self.checks.iter() {
             let check_expr = unsafe { check.cast_bound_unchecked::<crate::expression::PyExpr>(py) };
             let check_expr = check_expr.get();
 
-            stmt.check(check_expr.inner.clone());
+            stmt.check(crate::backend::render_schema_expr(&check_expr.inner, backend)?);
         }
 
         if self.if_not_exists {
@@ -87,30 +207,161 @@ impl TableInner {
         if let Some(x) = &self.character_set {
             stmt.character_set(x);
         }
-        if let Some(x) = &self.extra {
-            stmt.extra(x);
+        // Storage parameters are PostgreSQL specific, and WITHOUT ROWID is SQLite
+        // specific; both are ignored on other backends. SQLite separates its
+        // table options with commas.
+        let (options, separator) = match backend {
+            crate::backend::BackendKind::Postgres => (self.storage_params_clause(py), " "),
+            crate::backend::BackendKind::Sqlite if self.without_rowid => {
+                (Some("WITHOUT ROWID".to_owned()), ", ")
+            }
+            _ => (None, " "),
+        };
+
+        match (options, &self.extra) {
+            (Some(options), Some(x)) => {
+                stmt.extra(format!("{options}{separator}{x}"));
+            }
+            (Some(options), None) => {
+                stmt.extra(options);
+            }
+            (None, Some(x)) => {
+                stmt.extra(x);
+            }
+            (None, None) => (),
+        }
+
+        Ok(stmt)
+    }
+
+    /// Renders the `COMMENT ON` statements of this table.
+    ///
+    /// MySQL gets the comments inline in `CREATE TABLE`, but PostgreSQL needs
+    /// separate statements. Other backends have no comments, so nothing is
+    /// rendered for them.
+    pub(crate) fn as_comment_statements(
+        &self,
+        py: pyo3::Python,
+        backend: crate::backend::BackendKind,
+    ) -> Vec<String> {
+        use sea_query::{Iden, IntoTableRef, QueryBuilder, QuotedBuilder};
+
+        if backend != crate::backend::BackendKind::Postgres {
+            return Vec::new();
+        }
+
+        let builder = sea_query::PostgresQueryBuilder;
+
+        let mut table = String::new();
+        let name = unsafe { self.name.cast_bound_unchecked::<crate::common::PyTableName>(py) };
+        builder.prepare_table_ref(&name.get().clone().into_table_ref(), &mut table);
+
+        let mut statements = Vec::new();
+
+        if let Some(comment) = &self.comment {
+            let mut sql = format!("COMMENT ON TABLE {table} IS ");
+            builder.write_string_quoted(comment, &mut sql);
+
+            statements.push(sql);
         }
 
-        stmt
+        for (_, col) in self.columns.iter() {
+            let colbound = unsafe { col.cast_bound_unchecked::<crate::column::PyColumn>(py) };
+            let collock = colbound.get().inner.lock();
+
+            if let Some(comment) = &collock.comment {
+                let mut sql = format!("COMMENT ON COLUMN {table}.");
+                sea_query::Alias::new(&collock.name).prepare(&mut sql, builder.quote());
+                sql.push_str(" IS ");
+                builder.write_string_quoted(comment, &mut sql);
+
+                statements.push(sql);
+            }
+        }
+
+        statements
+    }
+
+    /// Whether a column or an index of this table is the primary key.
+    fn has_primary_key(&self, py: pyo3::Python) -> bool {
+        let column = self.columns.iter().any(|(_, col)| {
+            let colbound = unsafe { col.cast_bound_unchecked::<crate::column::PyColumn>(py) };
+            colbound.get().inner.lock().is_primary_key()
+        });
+
+        column
+            || self.indexes.iter().any(|ix| {
+                let ixbound = unsafe { ix.cast_bound_unchecked::<crate::index::PyIndex>(py) };
+                ixbound.get().inner.lock().options & (crate::index::IndexOptions::Primary as u8) > 0
+            })
     }
 
+    /// Checks that this table, and every index of it, can be rendered for
+    /// `backend`.
+    pub(crate) fn check_backend(
+        &self,
+        py: pyo3::Python,
+        backend: crate::backend::BackendKind,
+    ) -> pyo3::PyResult<()> {
+        // SQLite refuses WITHOUT ROWID tables without a primary key
+        if self.without_rowid && backend == crate::backend::BackendKind::Sqlite && !self.has_primary_key(py) {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "a WITHOUT ROWID table must have a primary key",
+            ));
+        }
+
+        for ix in self.indexes.iter() {
+            let ixbound = unsafe { ix.cast_bound_unchecked::<crate::index::PyIndex>(py) };
+            ixbound.get().inner.lock().check_backend(py, backend)?;
+        }
+
+        for fk in self.foreign_keys.iter() {
+            let fkbound = unsafe { fk.cast_bound_unchecked::<crate::foreign_key::PyForeignKey>(py) };
+            fkbound.get().inner.lock().check_backend(backend)?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders the `CREATE INDEX` statements of this table's indexes for
+    /// `backend`.
     #[optimize(speed)]
-    pub fn as_index_create_statements(&self, py: pyo3::Python) -> Vec<sea_query::IndexCreateStatement> {
+    pub(crate) fn as_index_create_sqls(
+        &self,
+        backend: &pyo3::Bound<'_, pyo3::PyAny>,
+    ) -> pyo3::PyResult<Vec<String>> {
         let mut vec = Vec::with_capacity(self.indexes.len());
 
         for ix in self.indexes.iter() {
-            let ixbound = unsafe { ix.cast_bound_unchecked::<crate::index::PyIndex>(py) };
+            let ixbound = unsafe { ix.cast_bound_unchecked::<crate::index::PyIndex>(backend.py()) };
             let ixlock = ixbound.get().inner.lock();
 
-            // We only want PRIMARY KEY indexes here.
-            if ixlock.options & (crate::index::IndexOptions::Primary as u8) > 0 {
+            // PRIMARY KEY indexes and UNIQUE constraints are part of CREATE TABLE.
+            if ixlock.is_table_constraint() {
                 continue;
             }
 
-            vec.push(ixlock.as_statement(py));
+            vec.push(ixlock.to_create_sql(backend)?);
         }
 
-        vec
+        Ok(vec)
+    }
+}
+
+/// A constraint of a PostgreSQL `CREATE TABLE`, rendered by us.
+///
+/// sea-query renders a column without type as nothing but its name, so the
+/// constraint is passed to it as one.
+#[derive(Debug)]
+struct ConstraintIden(String);
+
+impl sea_query::Iden for ConstraintIden {
+    fn prepare(&self, s: &mut dyn std::fmt::Write, _q: sea_query::Quote) {
+        write!(s, "{}", self.0).unwrap();
+    }
+
+    fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+        write!(s, "{}", self.0).unwrap();
     }
 }
 
@@ -211,7 +462,9 @@ impl PyTable {
             engine=None,
             collate=None,
             character_set=None,
-            extra=None
+            extra=None,
+            storage_params=None,
+            without_rowid=false
         )
     )]
     fn new(
@@ -227,6 +480,8 @@ impl PyTable {
         collate: Option<String>,
         character_set: Option<String>,
         extra: Option<String>,
+        storage_params: Option<&pyo3::Bound<'_, pyo3::types::PyDict>>,
+        without_rowid: bool,
     ) -> pyo3::PyResult<pyo3::PyClassInitializer<Self>> {
         let py = name.py();
 
@@ -285,6 +540,11 @@ impl PyTable {
             checks_vec.push(expr);
         }
 
+        let storage_params = match storage_params {
+            Some(x) => storage_params_from_dict(x)?,
+            None => StorageParams::new(),
+        };
+
         let inner = TableInner {
             name,
             columns: cols,
@@ -293,11 +553,13 @@ impl PyTable {
             checks: checks_vec,
             if_not_exists,
             temporary,
+            without_rowid,
             comment,
             engine,
             collate,
             character_set,
             extra,
+            storage_params,
         };
 
         let slf = Self {
@@ -329,6 +591,40 @@ impl PyTable {
         pyo3::Py::new(py, map).map(|x| x.into_any())
     }
 
+    fn column_names(&self) -> Vec<String> {
+        let lock = self.inner.lock();
+        lock.columns.keys().cloned().collect()
+    }
+
+    fn has_column(&self, name: &str) -> bool {
+        let lock = self.inner.lock();
+        lock.columns.contains_key(name)
+    }
+
+    fn rename_column(
+        &self,
+        py: pyo3::Python,
+        from_name: String,
+        to_name: String,
+    ) -> pyo3::PyResult<pyo3::Py<super::PyAlterTable>> {
+        let lock = self.inner.lock();
+
+        if !lock.columns.contains_key(&from_name) {
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyKeyError, _>(from_name));
+        }
+
+        let table = unsafe { lock.name.cast_bound_unchecked::<crate::common::PyTableName>(py) };
+
+        // ALTER TABLE takes the table itself, never its alias
+        let name = crate::common::PyTableName {
+            alias: None,
+            ..table.get().clone()
+        };
+        drop(lock);
+
+        super::PyAlterTable::rename_column(py, pyo3::Py::new(py, name)?.into_any(), from_name, to_name)
+    }
+
     #[getter]
     fn indexes(&self, py: pyo3::Python) -> Vec<pyo3::Py<pyo3::PyAny>> {
         let lock = self.inner.lock();
@@ -433,6 +729,17 @@ impl PyTable {
         lock.temporary = val;
     }
 
+    #[getter]
+    fn without_rowid(slf: pyo3::PyRef<'_, Self>) -> bool {
+        slf.inner.lock().without_rowid
+    }
+
+    #[setter]
+    fn set_without_rowid(slf: pyo3::PyRef<'_, Self>, val: bool) {
+        let mut lock = slf.inner.lock();
+        lock.without_rowid = val;
+    }
+
     #[getter]
     fn comment(&self) -> Option<String> {
         let lock = self.inner.lock();
@@ -503,21 +810,60 @@ impl PyTable {
         Ok(())
     }
 
-    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>) -> pyo3::PyResult<String> {
+    #[getter]
+    fn storage_params<'py>(
+        &self,
+        py: pyo3::Python<'py>,
+    ) -> pyo3::PyResult<pyo3::Bound<'py, pyo3::types::PyDict>> {
+        let lock = self.inner.lock();
+        let dict = pyo3::types::PyDict::new(py);
+
+        for (key, value) in lock.storage_params.iter() {
+            dict.set_item(key, value.bind(py))?;
+        }
+
+        Ok(dict)
+    }
+
+    #[setter]
+    fn set_storage_params(&self, val: Option<&pyo3::Bound<'_, pyo3::types::PyDict>>) -> pyo3::PyResult<()> {
+        let storage_params = match val {
+            Some(x) => storage_params_from_dict(x)?,
+            None => StorageParams::new(),
+        };
+
+        let mut lock = self.inner.lock();
+        lock.storage_params = storage_params;
+
+        Ok(())
+    }
+
+    #[pyo3(signature=(backend, pretty=false))]
+    fn to_sql(&self, backend: &pyo3::Bound<'_, pyo3::PyAny>, pretty: bool) -> pyo3::PyResult<String> {
+        let kind = crate::backend::into_backend_kind(backend)?;
+
         let lock = self.inner.lock();
-        let stmt = lock.as_table_create_statement(backend.py());
-        let ix = lock.as_index_create_statements(backend.py());
+        lock.check_backend(backend.py(), kind)?;
+        let stmt = lock.as_table_create_statement(backend.py(), kind)?;
+        let ix = lock.as_index_create_sqls(backend)?;
+        let comments = lock.as_comment_statements(backend.py(), kind);
         drop(lock);
 
-        let mut sql = build_schema!(backend => build_any(stmt))? + ";\n";
+        let mut sql = build_schema!(backend => build_any(stmt))?;
+        sql.push_str(";\n");
 
         for ix in ix.into_iter() {
-            sql += &build_schema!(backend => build_any(ix))?;
+            sql += &ix;
             sql.push(';');
             sql.push('\n');
         }
 
-        Ok(sql)
+        for comment in comments.into_iter() {
+            sql += &comment;
+            sql.push_str(";\n");
+        }
+
+        crate::format::format_if(sql, backend, pretty)
     }
 
     fn __repr__(&self) -> String {
@@ -567,6 +913,9 @@ impl PyTable {
         if lock.temporary {
             write!(s, " temporary=True").unwrap();
         }
+        if lock.without_rowid {
+            write!(s, " without_rowid=True").unwrap();
+        }
 
         if let Some(x) = &lock.comment {
             write!(s, " comment={x}").unwrap();
@@ -580,6 +929,14 @@ impl PyTable {
         if let Some(x) = &lock.character_set {
             write!(s, " character_set={x}").unwrap();
         }
+        if !lock.storage_params.is_empty() {
+            write!(
+                s,
+                " storage_params={:?}",
+                lock.storage_params.keys().collect::<Vec<_>>()
+            )
+            .unwrap();
+        }
 
         write!(s, " checks=[").unwrap();
 
@@ -597,3 +954,164 @@ impl PyTable {
         unsafe { String::from_utf8_unchecked(s) }
     }
 }
+
+/// Identity of a table name when matching foreign keys, same as
+/// `TableName.__eq__` (alias is ignored).
+fn table_name_key(
+    py: pyo3::Python,
+    name: &pyo3::Py<pyo3::PyAny>,
+) -> (Option<String>, Option<String>, String) {
+    let name = unsafe { name.cast_bound_unchecked::<crate::common::PyTableName>(py) }.get();
+
+    (
+        name.database.as_ref().map(|x| x.to_string()),
+        name.schema.as_ref().map(|x| x.to_string()),
+        name.name.to_string(),
+    )
+}
+
+/// Returns the indexes of `tables` in an order where every table comes after
+/// the tables its foreign keys reference, keeping the given order otherwise.
+///
+/// A cycle of foreign keys is an error, unless `break_cycles` is set; then the
+/// tables of the cycle are created in the reverse of the given order, so that
+/// they're dropped in the given order.
+fn creation_order(
+    py: pyo3::Python,
+    tables: &[pyo3::Bound<'_, PyTable>],
+    break_cycles: bool,
+) -> pyo3::PyResult<Vec<usize>> {
+    let keys: Vec<_> = tables
+        .iter()
+        .map(|x| table_name_key(py, &x.get().inner.lock().name))
+        .collect();
+
+    // Indexes of the tables which each table references
+    let mut dependencies = vec![Vec::new(); tables.len()];
+
+    for (index, table) in tables.iter().enumerate() {
+        let lock = table.get().inner.lock();
+
+        for fk in lock.foreign_keys.iter() {
+            let fkbound = unsafe { fk.cast_bound_unchecked::<crate::foreign_key::PyForeignKey>(py) };
+            let to_table = table_name_key(py, &fkbound.get().inner.lock().to_table);
+
+            // Self references and tables outside of the batch don't affect the order
+            if let Some(dependency) = keys.iter().position(|x| *x == to_table) {
+                if dependency != index && !dependencies[index].contains(&dependency) {
+                    dependencies[index].push(dependency);
+                }
+            }
+        }
+    }
+
+    // Topological sort, which keeps the given order among the tables that are ready
+    // to be created
+    let mut created = vec![false; tables.len()];
+    let mut order = Vec::with_capacity(tables.len());
+
+    while order.len() < tables.len() {
+        let next = (0..tables.len())
+            .find(|&index| !created[index] && dependencies[index].iter().all(|&x| created[x]));
+
+        let next = match next {
+            Some(x) => Some(x),
+            None if break_cycles => (0..tables.len()).rev().find(|&index| !created[index]),
+            None => None,
+        };
+
+        let Some(next) = next else {
+            let remaining: Vec<_> = (0..tables.len())
+                .filter(|&index| !created[index])
+                .map(|index| keys[index].2.as_str())
+                .collect();
+
+            return Err(pyo3::PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "foreign keys of these tables form a cycle: {}",
+                remaining.join(", ")
+            )));
+        };
+
+        created[next] = true;
+        order.push(next);
+    }
+
+    Ok(order)
+}
+
+#[pyo3::pyfunction]
+#[pyo3(signature=(tables, backend, pretty=false))]
+pub fn build_schema_ordered(
+    tables: Vec<pyo3::Bound<'_, PyTable>>,
+    backend: &pyo3::Bound<'_, pyo3::PyAny>,
+    pretty: bool,
+) -> pyo3::PyResult<String> {
+    let py = backend.py();
+    let kind = crate::backend::into_backend_kind(backend)?;
+    let order = creation_order(py, &tables, false)?;
+
+    let mut sql = String::new();
+    let mut indexes = String::new();
+    let mut comments = Vec::new();
+
+    for index in order {
+        let lock = tables[index].get().inner.lock();
+        lock.check_backend(py, kind)?;
+        let stmt = lock.as_table_create_statement(py, kind)?;
+        comments.extend(lock.as_comment_statements(py, kind));
+
+        let table_sql = build_schema!(backend => build_any(stmt))?;
+
+        // Indexes are created after all tables
+        let mut index_sql = String::new();
+        for ix in lock.as_index_create_sqls(backend)? {
+            index_sql += &ix;
+            index_sql.push_str(";\n");
+        }
+        drop(lock);
+
+        sql += &table_sql;
+        sql.push_str(";\n");
+        indexes += &index_sql;
+    }
+
+    sql += &indexes;
+
+    for comment in comments.into_iter() {
+        sql += &comment;
+        sql.push_str(";\n");
+    }
+
+    crate::format::format_if(sql, backend, pretty)
+}
+
+#[pyo3::pyfunction]
+#[pyo3(signature=(tables, backend, cascade=false))]
+pub fn drop_all(
+    tables: Vec<pyo3::Bound<'_, PyTable>>,
+    backend: &pyo3::Bound<'_, pyo3::PyAny>,
+    cascade: bool,
+) -> pyo3::PyResult<String> {
+    let py = backend.py();
+    let order = creation_order(py, &tables, cascade)?;
+
+    let mut sql = String::new();
+
+    // Tables are dropped before the tables they reference
+    for index in order.into_iter().rev() {
+        let mut stmt = sea_query::TableDropStatement::new();
+        {
+            let lock = tables[index].get().inner.lock();
+            let name = unsafe { lock.name.cast_bound_unchecked::<crate::common::PyTableName>(py) };
+            stmt.table(name.get().clone());
+        }
+        if cascade {
+            stmt.cascade();
+        }
+
+        sql += &build_schema!(backend => build_any(stmt))?;
+        sql.push_str(";\n");
+    }
+
+    Ok(sql)
+}
@@ -52,6 +52,7 @@ pub(crate) static mut COLUMN_TYPE: *mut pyo3::ffi::PyTypeObject = std::ptr::null
 pub(crate) static mut INDEX_COLUMN_TYPE: *mut pyo3::ffi::PyTypeObject = std::ptr::null_mut();
 pub(crate) static mut SELECT_STATEMENT_TYPE: *mut pyo3::ffi::PyTypeObject = std::ptr::null_mut();
 pub(crate) static mut CASE_STATEMENT_TYPE: *mut pyo3::ffi::PyTypeObject = std::ptr::null_mut();
+pub(crate) static mut BACKEND_TYPE: *mut pyo3::ffi::PyTypeObject = std::ptr::null_mut();
 
 // Python standard libraries types
 pub(crate) static mut STD_DECIMAL_TYPE: *mut pyo3::ffi::PyTypeObject = std::ptr::null_mut();
@@ -132,6 +133,7 @@ fn _initialize_typeref(py: pyo3::Python) -> bool {
         INDEX_COLUMN_TYPE = get_type_object_for::<crate::common::PyIndexColumn>(py);
         SELECT_STATEMENT_TYPE = get_type_object_for::<crate::query::select::PySelect>(py);
         CASE_STATEMENT_TYPE = get_type_object_for::<crate::query::case::PyCase>(py);
+        BACKEND_TYPE = get_type_object_for::<crate::backend::PyBackend>(py);
 
         STD_DECIMAL_TYPE = look_up_type_object(c"decimal", c"Decimal");
         STD_UUID_TYPE = look_up_type_object(c"uuid", c"UUID");
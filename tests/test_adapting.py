@@ -1,7 +1,10 @@
 from collections import namedtuple
 from datetime import datetime, timezone
+import copy
 import decimal
+import gc
 import pytest
+import sys
 import uuid
 
 import rapidquery as rq
@@ -56,6 +59,13 @@ TEST_CASES = [
     NamedCase(decimal.Decimal("1.2"), "is_decimal", rq.DecimalType(), False),
     NamedCase(decimal.Decimal("1.2"), "is_decimal", rq.FloatType(), True),
     NamedCase(1.2, "is_decimal", rq.DecimalType(), True),
+    NamedCase(decimal.Decimal("1.2"), "is_decimal", rq.MoneyType(), False),
+    NamedCase(1.2, "is_decimal", rq.MoneyType(), False),
+    NamedCase(3, "is_decimal", rq.MoneyType(), False),
+    NamedCase("19.99", "is_decimal", rq.MoneyType(), False),
+    NamedCase("abc", "is_decimal", rq.MoneyType(), True),
+    NamedCase(float("nan"), "is_decimal", rq.MoneyType(), True),
+    NamedCase(b"1.2", "is_decimal", rq.MoneyType(), True),
     NamedCase([1.3, 2.1, 3], "is_vector", rq.VectorType(), False),
     NamedCase([3, "b"], "is_vector", rq.VectorType(), True),
 ]
@@ -74,3 +84,128 @@ def test_adaptedvalue(case: NamedCase):
     assert getattr(val, case.attribute)
 
     rq.Expr(val)  # Force AdaptedValue to adapt
+
+
+@pytest.mark.parametrize("value", [decimal.Decimal("19.99"), 19.99, "19.99"])
+def test_money_literal(value):
+    val = rq.AdaptedValue(value, rq.MoneyType())
+
+    assert val.value == decimal.Decimal("19.99")
+
+    for backend in ("postgresql", "mysql", "sqlite"):
+        assert rq.Expr(val).to_sql(backend) == "19.99"
+
+
+@pytest.mark.parametrize(
+    "backend,placeholder,inlined",
+    [
+        ("postgresql", "$1", "E'O\\'Reilly\\'; DROP TABLE users; --'"),
+        ("mysql", "?", "'O\\'Reilly\\'; DROP TABLE users; --'"),
+        ("sqlite", "?", "'O''Reilly''; DROP TABLE users; --'"),
+    ],
+)
+def test_to_param_sql(backend, placeholder, inlined):
+    val = rq.AdaptedValue("O'Reilly'; DROP TABLE users; --")
+
+    assert val.to_sql(backend) == inlined
+
+    sql, param = val.to_param_sql(backend)
+    assert sql == placeholder
+    assert param == val
+    assert param.value == "O'Reilly'; DROP TABLE users; --"
+
+
+@pytest.mark.parametrize("data", ["a string value" * 4, uuid.uuid4()])
+def test_copy_refcount(data):
+    before = sys.getrefcount(data)
+    val = rq.AdaptedValue(data)
+
+    copies = [f(val) for f in (copy.copy, copy.deepcopy, rq.AdaptedValue.copy) for _ in range(200)]
+    for x in copies:
+        assert x.value == data
+        assert hash(x) == hash(data)
+
+    del copies, x, val
+    gc.collect()
+
+    assert sys.getrefcount(data) == before
+
+
+def test_deepcopy_mutable_payloads():
+    payload = {"tags": ["a", "b"]}
+
+    val = rq.AdaptedValue(payload, rq.JsonType())
+    assert copy.copy(val).value is payload
+    assert copy.deepcopy(val).value == payload
+    assert copy.deepcopy(val).value is not payload
+
+    val = rq.AdaptedValue([payload], rq.ArrayType(rq.JsonType()))
+    assert copy.deepcopy(val).value[0] is not payload
+
+    val = rq.AdaptedValue([1, 2], rq.ArrayType(rq.IntegerType()))
+    assert copy.deepcopy(val).value == [1, 2]
+
+
+@pytest.mark.parametrize(
+    "type,low,high",
+    [
+        (rq.TinyIntegerType(), -128, 127),
+        (rq.SmallIntegerType(), -32768, 32767),
+        (rq.IntegerType(), -(2**31), 2**31 - 1),
+        (rq.BigIntegerType(), -(2**63), 2**63 - 1),
+        (rq.TinyUnsignedType(), 0, 255),
+        (rq.SmallUnsignedType(), 0, 65535),
+        (rq.UnsignedType(), 0, 2**32 - 1),
+        (rq.BigUnsignedType(), 0, 2**64 - 1),
+    ],
+)
+def test_strict_integer_range(type, low, high):
+    for value in (low, high):
+        assert rq.AdaptedValue(value, type, strict=True).value == value
+        assert rq.Column("x", type).adapt(value, strict=True).value == value
+
+    # Without strict, values are only checked to fit in the 64 bits integer they're stored as
+    stored = range(0, 2**64) if low == 0 else range(-(2**63), 2**63)
+
+    for value in (low - 1, high + 1):
+        if value in stored:
+            assert rq.AdaptedValue(value, type).value == value
+
+        with pytest.raises((ValueError, OverflowError)):
+            rq.AdaptedValue(value, type, strict=True)
+
+        with pytest.raises((ValueError, OverflowError)):
+            rq.Column("x", type).adapt(value, strict=True)
+
+
+def test_strict_integer_range_message():
+    with pytest.raises(ValueError, match="300 is out of range for TinyInteger, expected -128 to 127"):
+        rq.AdaptedValue(300, rq.TinyIntegerType(), strict=True)
+
+    with pytest.raises(ValueError, match="out of range"):
+        rq.AdaptedValue([1, 70000], rq.ArrayType(rq.SmallIntegerType()), strict=True)
+
+
+def test_adapt_many():
+    values = [1, "text", 2.5, None, {"key": "value"}, decimal.Decimal("1.5"), uuid.uuid4()]
+    adapted = rq.adapt_many(values)
+
+    assert adapted == [rq.AdaptedValue(x) for x in values]
+    assert [x.value for x in adapted] == values
+
+    adapted = rq.adapt_many((x for x in (1, 2, 3)), rq.SmallIntegerType())
+    assert adapted == [rq.AdaptedValue(x, rq.SmallIntegerType()) for x in (1, 2, 3)]
+
+    # AdaptedValue items are copied, and adapted again to the given type
+    value = rq.AdaptedValue(1)
+    assert rq.adapt_many([value])[0] is not value
+    assert rq.adapt_many([value]) == [value]
+    assert rq.adapt_many([value], rq.SmallIntegerType()) == [rq.AdaptedValue(1, rq.SmallIntegerType())]
+
+    with pytest.raises(TypeError):
+        rq.adapt_many([rq.AdaptedValue("text")], rq.IntegerType())
+    with pytest.raises(ValueError, match="out of range"):
+        rq.adapt_many([rq.AdaptedValue(300)], rq.TinyIntegerType(), strict=True)
+
+    with pytest.raises(ValueError, match="out of range"):
+        rq.adapt_many([1, 300], rq.TinyIntegerType(), strict=True)
@@ -145,3 +145,234 @@ def test_column(val: rq.Column, case: ColumnTestCase):
 
     assert val.extra == "HELLO"
     assert val.comment == "COMMENT"
+
+
+def test_column_check():
+    col = rq.Column("price", rq.IntegerType(), check=rq.Expr.col("price") > 0)
+    assert col.check.to_sql("postgres") == '"price" > 0'
+
+    table = rq.Table("products", [col])
+    assert table.to_sql("postgres").strip() == (
+        'CREATE TABLE "products" ( "price" integer CHECK ("price" > 0) );'
+    )
+    assert table.to_sql("mysql").strip() == "CREATE TABLE `products` ( `price` int CHECK (`price` > 0) );"
+
+    col.check = None
+    assert col.check is None
+    assert table.to_sql("sqlite").strip() == 'CREATE TABLE "products" ( "price" integer );'
+
+
+@pytest.mark.parametrize(
+    "backend,expected",
+    [
+        ("postgres", 'CREATE TABLE "t" ( "id" uuid PRIMARY KEY DEFAULT gen_random_uuid() );'),
+        ("mysql", "CREATE TABLE `t` ( `id` binary(16) PRIMARY KEY DEFAULT (UUID_TO_BIN(UUID())) );"),
+    ],
+)
+def test_column_uuid_default(backend, expected):
+    col = rq.Column("id", rq.UuidType(), primary_key=True).uuid_default()
+
+    assert rq.Table("t", [col]).to_sql(backend).strip() == expected
+
+
+def test_column_uuid_default_function_call():
+    col = rq.Column("id", rq.UuidType(), primary_key=True, default=rq.FunctionCall("gen_random_uuid"))
+
+    assert rq.Table("t", [col]).to_sql("postgres").strip() == (
+        'CREATE TABLE "t" ( "id" uuid PRIMARY KEY DEFAULT gen_random_uuid() );'
+    )
+
+
+def test_column_uuid_default_resolved_per_backend():
+    table = rq.Table("t", [rq.Column("id", rq.UuidType(), primary_key=True).uuid_default()])
+
+    assert "DEFAULT gen_random_uuid()" in table.to_sql("postgres")
+    assert "DEFAULT (UUID_TO_BIN(UUID()))" in table.to_sql("mysql")
+
+    with pytest.raises(ValueError, match="SQLite has no UUID generation function"):
+        table.to_sql("sqlite")
+
+
+def test_column_uuid_default_invalid():
+    with pytest.raises(ValueError):
+        rq.Column("id", rq.TextType()).uuid_default()
+
+
+@pytest.mark.parametrize(
+    "backend,expected",
+    [
+        (
+            "postgres",
+            'CREATE TABLE "t" ( "created" timestamp DEFAULT CURRENT_TIMESTAMP, "day" date DEFAULT CURRENT_DATE );',
+        ),
+        (
+            "mysql",
+            "CREATE TABLE `t` ( `created` timestamp DEFAULT CURRENT_TIMESTAMP, `day` date DEFAULT (CURRENT_DATE) );",
+        ),
+        (
+            "sqlite",
+            'CREATE TABLE "t" ( "created" timestamp_text DEFAULT CURRENT_TIMESTAMP, '
+            '"day" date_text DEFAULT CURRENT_DATE );',
+        ),
+    ],
+)
+def test_column_default_now_today(backend, expected):
+    created = rq.Column("created", rq.TimestampType(), default=rq.Column.default_now())
+    day = rq.Column("day", rq.DateType(), default=rq.Column.default_today())
+
+    assert rq.Table("t", [created, day]).to_sql(backend).strip() == expected
+
+    from_default = rq.Column("created", rq.TimestampType(), default=rq.Expr.current_timestamp())
+    assert from_default.default.to_sql(backend) == created.default.to_sql(backend)
+
+
+@pytest.mark.parametrize(
+    "backend,expected",
+    [
+        ("postgres", 'CREATE TABLE "t" ( "n" integer DEFAULT 1 + 2, "s" text DEFAULT \'x\' );'),
+        ("mysql", "CREATE TABLE `t` ( `n` int DEFAULT (1 + 2), `s` text DEFAULT 'x' );"),
+        ("sqlite", 'CREATE TABLE "t" ( "n" integer DEFAULT (1 + 2), "s" text DEFAULT \'x\' );'),
+    ],
+)
+def test_column_default_expression_parenthesized(backend, expected):
+    n = rq.Column("n", rq.IntegerType(), default=rq.Expr(1) + 2)
+    s = rq.Column("s", rq.TextType(), default="x")
+
+    assert rq.Table("t", [n, s]).to_sql(backend).strip() == expected
+
+
+def test_column_default_type_check():
+    col = rq.Column("count", rq.IntegerType(), default=0)
+    assert col.default.to_sql("postgresql") == "0"
+
+    with pytest.raises(TypeError, match='invalid default for column "count" of type IntegerType'):
+        rq.Column("count", rq.IntegerType(), default="zero")
+
+    with pytest.raises(ValueError, match="out of range"):
+        rq.Column("level", rq.TinyIntegerType(), default=300)
+
+    # Assigning is checked the same way, and expressions aren't checked at all
+    with pytest.raises(TypeError):
+        col.default = "zero"
+    assert col.default.to_sql("postgresql") == "0"
+
+    col.default = rq.Expr.col("other")
+    assert col.default.to_sql("postgresql") == '"other"'
+
+
+def test_array_type_of():
+    assert rq.ArrayType.of(rq.TextType()) == rq.ArrayType(rq.TextType())
+
+    grid = rq.ArrayType.of(rq.IntegerType(), dimensions=2)
+    assert grid == rq.ArrayType(rq.ArrayType(rq.IntegerType()))
+    assert grid.element.element == rq.IntegerType()
+
+    table = rq.Table("grids", [rq.Column("cells", grid)])
+    assert table.to_sql("postgresql") == 'CREATE TABLE "grids" ( "cells" integer[][] );\n'
+
+    with pytest.raises(ValueError):
+        rq.ArrayType.of(rq.IntegerType(), dimensions=0)
+
+    with pytest.raises(TypeError):
+        rq.ArrayType.of(str)
+
+
+@pytest.mark.parametrize(
+    "string,backend,expected,sql",
+    [
+        ("varchar(255)", "postgresql", rq.StringType(255), "varchar(255)"),
+        ("Character Varying(20)", "postgresql", rq.StringType(20), "varchar(20)"),
+        ("numeric(10,2)", "postgresql", rq.DecimalType((10, 2)), "decimal(10, 2)"),
+        ("timestamptz", "postgresql", rq.TimestampWithTimeZoneType(), "timestamp with time zone"),
+        ("int[]", "postgresql", rq.ArrayType(rq.IntegerType()), "integer[]"),
+        ("text[][]", "postgresql", rq.ArrayType.of(rq.TextType(), dimensions=2), "text[][]"),
+        ("double precision", "postgresql", rq.DoubleType(), "double precision"),
+        ("int(11) unsigned", "mysql", rq.UnsignedType(), "int UNSIGNED"),
+        ("datetime", "mysql", rq.DateTimeType(), "datetime"),
+        ("real(10, 2)", "sqlite", rq.DecimalType((10, 2)), "real(10, 2)"),
+        ("blob(16)", "sqlite", rq.BinaryType(16), "blob(16)"),
+        ("decimal(10)", "mysql", rq.DecimalType((10, 0)), "decimal(10, 0)"),
+        (
+            "interval hour to minute",
+            "postgresql",
+            rq.IntervalType(rq.INTERVAL_HOUR_TO_MINUTE),
+            "interval HOUR TO MINUTE",
+        ),
+        (
+            "interval day to second(3)",
+            "postgresql",
+            rq.IntervalType(rq.INTERVAL_DAY_TO_SECOND, 3),
+            "interval DAY TO SECOND(3)",
+        ),
+        ("time(3)", "postgresql", rq.TimeType(), "time"),
+        ("timestamp(6) with time zone", "postgresql", rq.TimestampWithTimeZoneType(), "timestamp with time zone"),
+        ("datetime(3)", "mysql", rq.DateTimeType(), "datetime"),
+    ],
+)
+def test_column_type_from_string(string, backend, expected, sql):
+    parsed = rq.column_type_from_string(string, backend)
+    assert parsed == expected
+
+    table = rq.Table("t", [rq.Column("x", parsed)])
+    quote = "`" if backend == "mysql" else '"'
+    assert table.to_sql(backend).strip() == f"CREATE TABLE {quote}t{quote} ( {quote}x{quote} {sql} );"
+
+    # Parsing the rendered type gives the same type back
+    assert rq.column_type_from_string(sql, backend) == expected
+
+
+@pytest.mark.parametrize(
+    "backend,types",
+    [
+        (
+            "postgresql",
+            [
+                rq.DecimalType((10, 0)),
+                rq.DecimalType((12, 4)),
+                rq.DecimalType(),
+                rq.MoneyType((10, 2)),
+                rq.IntervalType(),
+                rq.IntervalType(None, 3),
+                rq.IntervalType(rq.INTERVAL_YEAR_TO_MONTH),
+                rq.IntervalType(rq.INTERVAL_MINUTE_TO_SECOND, 2),
+                rq.TimeType(),
+                rq.TimestampType(),
+                rq.DateTimeType(),
+                rq.TimestampWithTimeZoneType(),
+                rq.StringType(40),
+                rq.ArrayType(rq.BigIntegerType()),
+            ],
+        ),
+        (
+            "mysql",
+            [rq.DecimalType((10, 0)), rq.TimeType(), rq.TimestampType(), rq.DateTimeType(), rq.UnsignedType()],
+        ),
+        ("sqlite", [rq.DecimalType((10, 0)), rq.MoneyType((8, 2)), rq.TimeType(), rq.DateTimeType(), rq.UuidType()]),
+    ],
+)
+def test_column_type_from_string_round_trip(backend, types):
+    for type in types:
+        sql = rq.Table("t", [rq.Column("x", type)]).to_sql(backend)
+        rendered = sql[sql.index("x") + 2 :].removesuffix(" );\n").lstrip('"`').strip()
+
+        assert rq.column_type_from_string(rendered, backend) == type, rendered
+
+
+def test_column_type_from_string_invalid():
+    with pytest.raises(ValueError, match="unknown column type"):
+        rq.column_type_from_string("foo", "postgresql")
+
+    with pytest.raises(ValueError, match="invalid arguments"):
+        rq.column_type_from_string("text(3)", "postgresql")
+
+    with pytest.raises(ValueError, match="only supported on PostgreSQL"):
+        rq.column_type_from_string("int[]", "sqlite")
+
+
+def test_column_auto_increment_type_postgres():
+    table = rq.Table("t", [rq.Column("x", rq.TinyIntegerType(), primary_key=True, auto_increment=True)])
+
+    with pytest.raises(ValueError, match="auto increment is only supported"):
+        table.to_sql("postgresql")
+
+    assert "AUTO_INCREMENT" in table.to_sql("mysql")
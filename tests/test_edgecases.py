@@ -218,11 +218,28 @@ class TestTableDefinitionEdgeCases:
 
     def test_auto_increment_on_non_integer(self):
         """Auto increment on string column (invalid)."""
-        col = _lib.Column("id", _lib.StringType(), auto_increment=True)
-        table = _lib.Table("test", columns=[col])
-        # Should build; DB will reject at execution
-        sql = table.to_sql("mysql")
-        assert sql  # Just ensure it builds
+        with pytest.raises(ValueError):
+            _lib.Column("id", _lib.StringType(), auto_increment=True)
+
+        col = _lib.Column("id", _lib.TextType())
+        with pytest.raises(ValueError):
+            col.auto_increment = True
+        assert not col.auto_increment
+
+    def test_auto_increment_on_integer(self):
+        """Auto increment on any integer family type."""
+        for type in (_lib.IntegerType(), _lib.BigIntegerType(), _lib.SmallUnsignedType()):
+            col = _lib.Column("id", type, auto_increment=True)
+            assert col.auto_increment
+
+        col = _lib.Column("id", _lib.BigIntegerType())
+        col.auto_increment = True
+        assert col.auto_increment
+
+        # Changing the type of an auto increment column is validated too
+        with pytest.raises(ValueError):
+            col.type = _lib.TextType()
+        assert isinstance(col.type, _lib.BigIntegerType)
 
     def test_index_on_nonexistent_column(self):
         """Index referencing column that doesn't exist."""
@@ -454,6 +471,19 @@ class TestTableNameParsing:
         except (ValueError, Exception):
             pass  # Expected for ambiguous input
 
+    def test_alias_equality_and_hash(self):
+        """Aliases are ignored by == and hash, but not by equals(compare_alias=True)."""
+        a = _lib.TableName("users", schema="public", alias="a")
+        b = _lib.TableName("users", schema="public", alias="b")
+
+        assert a == b
+        assert hash(a) == hash(b)
+        assert len({a, b, _lib.TableName("users", schema="public")}) == 1
+        assert a.equals(b)
+        assert not a.equals(b, compare_alias=True)
+        assert a.equals(a.copy(), compare_alias=True)
+        assert not a.equals(_lib.TableName("users", alias="a"))
+
 
 class TestSQLInjectionPrevention:
     """Test that SQL injection is prevented."""
@@ -1,4 +1,5 @@
 from dataclasses import dataclass
+from datetime import date, datetime, timedelta, timezone
 import pytest
 import typing
 
@@ -26,6 +27,46 @@ sqlcases = [
         'CAST(CAST("name" AS VARCHAR(1000)) AS hierarchy_path)',
         "postgres",
     ),
+    SQLCase(
+        rq.Expr.tuple([rq.Expr.col("starts_at"), rq.Expr.col("ends_at")]).overlaps(
+            (rq.Expr.col("r.starts_at"), rq.Expr.col("r.ends_at"))
+        ),
+        '("starts_at", "ends_at") OVERLAPS ("r"."starts_at", "r"."ends_at")',
+        "postgres",
+    ),
+    SQLCase(
+        rq.Expr.json_agg(rq.Expr.json_build_object(id=rq.Expr.col("id"), name=rq.Expr.col("name"))),
+        'json_agg(json_build_object(\'id\', "id", \'name\', "name"))',
+        "postgres",
+    ),
+    SQLCase(
+        rq.Expr.json_agg(rq.Expr.json_build_object(id=rq.Expr.col("id"))),
+        "JSON_ARRAYAGG(JSON_OBJECT('id', `id`))",
+        "mysql",
+    ),
+    SQLCase(
+        rq.Expr.json_agg(rq.Expr.json_build_object(id=rq.Expr.col("id"))),
+        'json_group_array(json_object(\'id\', "id"))',
+        "sqlite",
+    ),
+    SQLCase(rq.Expr.col("id") == rq.Expr.any_array([1, 2, 3]), '"id" = ANY(ARRAY[1, 2, 3])', "postgres"),
+    SQLCase(
+        rq.Expr.col("score") > rq.Expr.all_array([rq.Expr.col("low"), 20]),
+        '"score" > ALL(ARRAY["low", 20])',
+        "postgres",
+    ),
+    SQLCase(
+        rq.Expr.col("elapsed").cast_as(rq.IntervalType(rq.INTERVAL_HOUR_TO_MINUTE)),
+        'CAST("elapsed" AS interval HOUR TO MINUTE)',
+        "postgres",
+    ),
+    SQLCase(
+        rq.Expr.col("tags").cast_as(rq.ArrayType(rq.TextType())),
+        'CAST("tags" AS text[])',
+        "postgres",
+    ),
+    SQLCase(rq.Expr.col("id").cast_as(rq.IntegerType()), "CAST(`id` AS SIGNED)", "mysql"),
+    SQLCase(rq.Expr.col("id").cast_as(rq.IntegerType()), 'CAST("id" AS integer)', "sqlite"),
     SQLCase(
         (rq.Expr.col("oh.level") + 1).between(24, 26),
         '"oh"."level" + 1 BETWEEN 24 AND 26',
@@ -56,6 +97,146 @@ sqlcases = [
         "NOT COUNT(*) = 1",
         "postgres",
     ),
+    SQLCase(
+        rq.Expr.col("a").is_distinct_from(rq.Expr.col("b")),
+        '"a" IS DISTINCT FROM "b"',
+        "postgres",
+    ),
+    SQLCase(
+        rq.Expr.col("a").is_distinct_from(None),
+        '"a" IS DISTINCT FROM NULL',
+        "sqlite",
+    ),
+    SQLCase(
+        rq.Expr.col("a").is_distinct_from(rq.Expr.col("b")),
+        "NOT (`a` <=> `b`)",
+        "mysql",
+    ),
+    SQLCase(
+        rq.Expr.col("a").is_not_distinct_from(rq.Expr.col("b")),
+        '"a" IS NOT DISTINCT FROM "b"',
+        "postgres",
+    ),
+    SQLCase(
+        rq.Expr.col("a").is_not_distinct_from(1),
+        '"a" IS NOT DISTINCT FROM 1',
+        "sqlite",
+    ),
+    SQLCase(
+        rq.Expr.col("a").is_not_distinct_from(rq.Expr.col("b")),
+        "`a` <=> `b`",
+        "mysql",
+    ),
+    SQLCase(
+        rq.Expr.col("a").eq_null_safe(rq.Expr.col("b")),
+        '"a" IS NOT DISTINCT FROM "b"',
+        "postgres",
+    ),
+    SQLCase(
+        rq.Expr.col("a").eq_null_safe(None),
+        '"a" IS NOT DISTINCT FROM NULL',
+        "sqlite",
+    ),
+    SQLCase(
+        rq.Expr.col("a").eq_null_safe(None),
+        "`a` <=> NULL",
+        "mysql",
+    ),
+    SQLCase(
+        rq.all(rq.Expr.col("a").is_distinct_from(1), rq.Expr.col("b") == 2),
+        "(NOT (`a` <=> 1)) AND `b` = 2",
+        "mysql",
+    ),
+    SQLCase(
+        rq.Expr.col("a").between((1, 10)),
+        '"a" BETWEEN 1 AND 10',
+        "postgres",
+    ),
+    SQLCase(
+        rq.Expr.col("a").not_between((rq.Expr.col("b"), 10)),
+        '"a" NOT BETWEEN "b" AND 10',
+        "postgres",
+    ),
+    SQLCase(
+        rq.Expr.col("a").not_between(1, 10),
+        '"a" NOT BETWEEN 1 AND 10',
+        "postgres",
+    ),
+    SQLCase(
+        rq.Expr.col("status").case_when([(1, "a"), (2, "b")]),
+        "CASE \"status\" WHEN 1 THEN 'a' WHEN 2 THEN 'b' END",
+        "postgres",
+    ),
+    SQLCase(
+        rq.Expr.col("status").case_when([(1, rq.Expr.col("x") + 1)], else_=0),
+        'CASE "status" WHEN 1 THEN "x" + 1 ELSE 0 END',
+        "sqlite",
+    ),
+    SQLCase(
+        rq.Expr.col("status").case_when([(1, "a")], else_="z") == "a",
+        "(CASE `status` WHEN 1 THEN 'a' ELSE 'z' END) = 'a'",
+        "mysql",
+    ),
+    SQLCase(
+        rq.FunctionCall.count(rq.Expr.col("user_id").distinct()).to_expr(),
+        'COUNT(DISTINCT "user_id")',
+        "postgres",
+    ),
+    SQLCase(
+        rq.FunctionCall.sum(rq.Expr.col("amount").distinct()).to_expr() > 10,
+        "SUM(DISTINCT `amount`) > 10",
+        "mysql",
+    ),
+    SQLCase(
+        rq.FunctionCall.avg(rq.Expr.col("a").distinct()).to_expr(),
+        'AVG(DISTINCT "a")',
+        "sqlite",
+    ),
+    SQLCase(
+        rq.Case().when(rq.Expr.col("status") == 1, "a").to_expr(),
+        "(CASE WHEN (\"status\" = 1) THEN 'a' END)",
+        "postgres",
+    ),
+    SQLCase(rq.Expr.col("a") ** 2, 'POWER("a", 2)', "postgres"),
+    SQLCase(rq.Expr.col("a") ** rq.Expr.col("b"), "POWER(`a`, `b`)", "mysql"),
+    SQLCase(rq.Expr.col("a").power(0.5) > 3, 'POWER("a", 0.5) > 3', "sqlite"),
+    SQLCase(rq.Expr.col("col").bit_not(), '~("col")', "postgres"),
+    SQLCase(rq.Expr.col("col").bit_not(), "~(`col`)", "mysql"),
+    SQLCase(rq.Expr.col("flags").bit_not().bit_and(7) == 0, '(~("flags") & 7) = 0', "sqlite"),
+    SQLCase(
+        rq.Expr.tuple([rq.Expr.col("a"), rq.Expr.col("b")]).in_([(1, "x"), (2, "y")]),
+        """("a", "b") IN ((1, 'x'), (2, 'y'))""",
+        "postgres",
+    ),
+    SQLCase(
+        rq.Expr.tuple(((1, 2), (3, 4))),
+        "((1, 2), (3, 4))",
+        "postgres",
+    ),
+    SQLCase(
+        rq.Expr.tuple([[rq.Expr.col("a"), 1], (rq.Expr.col("b"), 2)]),
+        "((`a`, 1), (`b`, 2))",
+        "mysql",
+    ),
+    SQLCase(
+        rq.Expr.func_call("similarity", rq.Expr.col("name"), "alice"),
+        """similarity("name", 'alice')""",
+        "postgres",
+    ),
+    SQLCase(rq.Expr.func_call("IFNULL", rq.Expr.col("a"), 0) > 1, "IFNULL(`a`, 0) > 1", "mysql"),
+    SQLCase(rq.Expr.func_call("random"), "random()", "sqlite"),
+    SQLCase(rq.Expr.col("code").similar_to("%(b|d)%"), """"code" SIMILAR TO '%(b|d)%'""", "postgres"),
+    SQLCase(
+        rq.Expr.col("code").not_similar_to("a!%%", escape="!") & (rq.Expr.col("id") == 1),
+        """("code" NOT SIMILAR TO 'a!%%' ESCAPE '!') AND "id" = 1""",
+        "postgres",
+    ),
+    SQLCase(rq.Expr.time_bucket("1 hour", rq.Expr.col("ts")), """time_bucket('1 hour', "ts")""", "postgres"),
+    SQLCase(
+        rq.Expr.time_bucket("15 minutes", rq.Expr.col("ts"), rq.Expr.col("origin")),
+        """time_bucket('15 minutes', "ts", "origin")""",
+        "postgres",
+    ),
 ]
 
 
@@ -140,3 +321,447 @@ def test_invalid_expr():
         rq.Expr(Unknown())
     except ValueError:
         pass
+
+
+def test_between_invalid_tuple():
+    with pytest.raises(ValueError):
+        rq.Expr.col("a").between((1, 2, 3))
+
+    with pytest.raises(ValueError):
+        rq.Expr.col("a").not_between((1,))
+
+    with pytest.raises(TypeError):
+        rq.Expr.col("a").between(1)
+
+
+def test_case_when_invalid():
+    with pytest.raises(ValueError):
+        rq.Expr.col("a").case_when([])
+
+    with pytest.raises(ValueError):
+        rq.Expr.col("a").case_when([(1, 2, 3)])
+
+    with pytest.raises(TypeError):
+        rq.Expr.col("a").case_when([1])
+
+
+def test_tuple_from_set():
+    assert rq.Expr.tuple({3, 1, 2}).to_sql("postgresql") == "(1, 2, 3)"
+    assert rq.Expr.tuple({"b", "c", "a"}).to_sql("sqlite") == "('a', 'b', 'c')"
+    assert rq.Expr.tuple({"a"}).to_sql("sqlite") == "('a')"
+
+    with pytest.raises(TypeError, match="use a list or tuple"):
+        rq.Expr.tuple({1, "a"})
+
+
+def test_tuple_invalid():
+    with pytest.raises(ValueError):
+        rq.Expr.tuple([])
+
+    with pytest.raises(ValueError):
+        rq.Expr.tuple([1, ()])
+
+    with pytest.raises(TypeError):
+        rq.Expr.tuple(1)
+
+
+def test_pow_with_modulo():
+    with pytest.raises(TypeError):
+        pow(rq.Expr.col("a"), 2, 3)
+
+
+def test_in_adapted_values():
+    values = [rq.AdaptedValue(x, rq.BigIntegerType()) for x in (1, 2, 3)]
+    expected = rq.Expr.col("id").in_([1, 2, 3])
+
+    assert rq.Expr.col("id").in_(values).to_sql("postgresql") == expected.to_sql("postgresql")
+    assert rq.Expr.col("id").not_in(values).to_sql("mysql") == "`id` NOT IN (1, 2, 3)"
+
+
+def test_in_with_type():
+    expr = rq.Expr.col("price").in_([1, 2.5, None], type=rq.DoubleType())
+
+    assert expr.to_sql("postgresql") == '"price" IN (1, 2.5, NULL)'
+
+    with pytest.raises(TypeError):
+        rq.Expr.col("price").in_(["a"], type=rq.DoubleType())
+
+    with pytest.raises(TypeError):
+        rq.Expr.col("price").in_([1], type=int)
+
+    with pytest.raises(ValueError):
+        rq.Expr.col("price").not_in([], type=rq.DoubleType())
+
+
+def test_in_from_iterator():
+    expected = rq.Expr.col("id").in_(list(range(10_000)))
+
+    expr = rq.Expr.col("id").in_(x for x in range(10_000))
+    assert expr.to_sql("postgresql") == expected.to_sql("postgresql")
+
+    expr = rq.Expr.col("id").not_in(iter(range(3)), type=rq.BigIntegerType())
+    assert expr.to_sql("postgresql") == '"id" NOT IN (0, 1, 2)'
+
+    with pytest.raises(ValueError):
+        rq.Expr.col("id").in_(x for x in ())
+
+    with pytest.raises(TypeError):
+        rq.Expr.col("id").in_("abc")
+
+    with pytest.raises(TypeError):
+        rq.Expr.col("id").in_(1)
+
+
+def test_floor_division():
+    assert (rq.Expr.col("minutes") / 60).to_sql("postgresql") == '"minutes" / 60'
+    assert (rq.Expr.col("minutes") // 60).to_sql("postgresql") == 'FLOOR(CAST("minutes" AS numeric) / 60)'
+    assert (rq.Expr.col("minutes") // 60).to_sql("sqlite") == 'FLOOR(CAST("minutes" AS real) / 60)'
+    assert (rq.Expr.col("minutes") / 60).to_sql("mysql") == "`minutes` / 60"
+    assert (rq.Expr.col("minutes") // 60).to_sql("mysql") == "FLOOR(`minutes` / 60)"
+
+    expr = (rq.Expr.col("a") + 1) // (rq.Expr.col("b") * 2)
+    assert expr.to_sql("postgresql") == 'FLOOR(CAST("a" + 1 AS numeric) / ("b" * 2))'
+    assert expr.to_sql("mysql") == "FLOOR((`a` + 1) / (`b` * 2))"
+
+
+def test_floor_division_negative():
+    import sqlite3
+
+    connection = sqlite3.connect(":memory:")
+    if sqlite3.sqlite_version_info < (3, 35):
+        pytest.skip("SQLite has no FLOOR()")
+
+    for a, b in [(-7, 2), (7, -2), (7, 2), (-8, 2)]:
+        sql = rq.Select(rq.Expr(a) // b).to_sql("sqlite")
+        assert connection.execute(sql).fetchone()[0] == a // b, sql
+
+
+def test_in_optimize_single():
+    assert rq.Expr.col("id").in_([5]).to_sql("postgresql") == '"id" IN (5)'
+    assert rq.Expr.col("id").in_([5], optimize_single=True).to_sql("postgresql") == '"id" = 5'
+    assert rq.Expr.col("id").not_in([5]).to_sql("mysql") == "`id` NOT IN (5)"
+    assert rq.Expr.col("id").not_in(iter([5]), optimize_single=True).to_sql("mysql") == "`id` <> 5"
+
+    # More than one item always renders IN
+    assert rq.Expr.col("id").in_([5, 6], optimize_single=True).to_sql("sqlite") == '"id" IN (5, 6)'
+
+
+def test_time_bucket_invalid_width():
+    for width in ("", "hour", "0 hours", "1 fortnight", "1 hour'; DROP TABLE t; --"):
+        with pytest.raises(ValueError):
+            rq.Expr.time_bucket(width, rq.Expr.col("ts"))
+
+
+def test_distinct_outside_aggregate():
+    assert rq.Expr.func_call("string_agg", rq.Expr.col("a").distinct(), ",").to_sql("postgres") == (
+        """string_agg(DISTINCT "a", ',')"""
+    )
+
+    invalid = (
+        rq.Expr.col("a").distinct() + 1,
+        rq.Select(rq.Expr.col("a").distinct()).from_table("t"),
+        rq.FunctionCall.lower(rq.Expr.col("a").distinct()).to_expr(),
+        rq.Expr.func_call("f", 1, rq.Expr.col("a").distinct()),
+        rq.Expr.json_build_object(a=rq.Expr.col("a").distinct()),
+    )
+    for query in invalid:
+        for backend in ("postgresql", "mysql", "sqlite"):
+            with pytest.raises(ValueError, match="DISTINCT is only allowed as the first argument of an aggregate"):
+                query.to_sql(backend)
+
+
+def test_func_call_name():
+    assert rq.Expr.func_call("pg_catalog.now").to_sql("postgres") == "pg_catalog.now()"
+
+    # Internal markers, like the UUID default or ARRAY[...], can't be called by name
+    assert rq.Expr.func_call("UUID_GENERATE").to_sql("postgres") == "UUID_GENERATE()"
+    assert rq.Expr.func_call("ARRAY", 1, 2).to_sql("postgres") == "ARRAY(1, 2)"
+
+    for name in ("", "1abc", "now()", "a.b.c", "x; DROP TABLE t; --", "@ARRAY", "a b"):
+        with pytest.raises(ValueError, match="invalid function name"):
+            rq.Expr.func_call(name)
+
+
+def test_between_open_bounds():
+    price = rq.Expr.col("price")
+
+    assert price.between(10, 20, open_bounds=True).to_sql("postgres") == '"price" BETWEEN 10 AND 20'
+    assert price.between(10, None, open_bounds=True).to_sql("postgres") == '"price" >= 10'
+    assert price.between((None, 20), open_bounds=True).to_sql("postgres") == '"price" <= 20'
+    assert price.between(None, 20).to_sql("postgres") == '"price" BETWEEN NULL AND 20'
+
+    with pytest.raises(ValueError):
+        price.between(None, None, open_bounds=True)
+
+
+def test_overlaps():
+    period = rq.Expr.tuple([rq.Expr.col("starts_at"), rq.Expr.col("ends_at")])
+    conflict = (rq.Expr.col("room_id") == 7) & period.overlaps("2024-05-01 10:00", "2024-05-01 12:00")
+
+    query = rq.Select(rq.Expr.col("id")).from_table("reservations").where(conflict)
+    sql, params = query.build("postgresql")
+
+    assert sql == (
+        'SELECT "id" FROM "reservations" WHERE "room_id" = $1 '
+        'AND (("starts_at", "ends_at") OVERLAPS ($2, $3))'
+    )
+    assert len(params) == 3
+
+    with pytest.raises(ValueError):
+        rq.Expr.col("starts_at").overlaps("2024-05-01", "2024-05-02")
+
+    with pytest.raises(ValueError):
+        rq.Expr.tuple([1, 2, 3]).overlaps("2024-05-01", "2024-05-02")
+
+    with pytest.raises(ValueError):
+        period.overlaps(("2024-05-01",))
+
+    for backend in ("mysql", "sqlite"):
+        with pytest.raises(ValueError, match="OVERLAPS is only supported by PostgreSQL"):
+            period.overlaps("2024-05-01", "2024-05-02").to_sql(backend)
+
+
+def test_array_comparison():
+    with pytest.raises(ValueError):
+        rq.Expr.any_array([])
+
+    for backend in ("mysql", "sqlite"):
+        with pytest.raises(ValueError, match="ARRAY is only supported by PostgreSQL"):
+            (rq.Expr.col("id") == rq.Expr.any_array([1, 2])).to_sql(backend)
+
+
+def test_json_build_object():
+    obj = rq.Expr.json_build_object(**{"user id": rq.Expr.col("id"), "it's": 1})
+    query = rq.Select(obj).from_table("users")
+
+    # Keys are inlined, values are parameters
+    assert query.build("postgresql")[0] == (
+        'SELECT json_build_object(\'user id\', "id", E\'it\\\'s\', $1) FROM "users"'
+    )
+    assert rq.Expr.json_build_object().to_sql("postgresql") == "json_build_object()"
+    assert rq.Expr.json_agg(rq.Expr.col("id").distinct()).to_sql("postgresql") == 'json_agg(DISTINCT "id")'
+
+
+def test_array_column_comparison():
+    tags = rq.Column("tags", rq.ArrayType(rq.TextType()))
+    query = rq.Select(rq.Expr.asterisk()).from_table("posts").where(rq.Expr.col("x") == rq.Expr.any_of(tags))
+    assert query.to_sql("postgresql") == 'SELECT * FROM "posts" WHERE "x" = ANY("tags")'
+
+    expr = rq.Expr.col("x") == rq.Expr.all_of(rq.Expr.col("tags"), rq.TextType())
+    assert expr.to_sql("postgresql") == '"x" = ALL(CAST("tags" AS text[]))'
+
+    expr = rq.Expr.col("x") == rq.Expr.any_of(rq.Expr.col("ids").cast_array(rq.IntegerType()))
+    assert expr.to_sql("postgresql") == '"x" = ANY(CAST("ids" AS integer[]))'
+
+    # Neither is known to be an array without a hint
+    with pytest.raises(ValueError):
+        rq.Expr.any_of(rq.Expr.col("tags"))
+
+    with pytest.raises(ValueError):
+        rq.Expr.any_of(rq.Column("name", rq.TextType()))
+
+    with pytest.raises(TypeError):
+        rq.Expr.any_of(rq.Expr.col("tags"), "text")
+
+
+def test_cast_as_unavailable_type():
+    for backend in ("mysql", "sqlite"):
+        with pytest.raises(ValueError, match="not available in"):
+            rq.Expr.col("elapsed").cast_as(rq.IntervalType()).to_sql(backend)
+
+        with pytest.raises(ValueError, match="not available in"):
+            rq.Expr.col("tags").cast_as(rq.ArrayType(rq.TextType())).to_sql(backend)
+
+    with pytest.raises(ValueError, match="not available in MySQL"):
+        rq.Expr.col("flag").cast_as(rq.BooleanType()).to_sql("mysql")
+
+    with pytest.raises(TypeError):
+        rq.Expr.col("id").cast_as(1)
+
+
+def test_cast_as_mysql_and_sqlite_targets():
+    mood = rq.EnumType("mood", ["happy", "sad"])
+    cases = [
+        (mood, "mood", "CHAR", "text"),
+        (rq.StringType(20), "varchar(20)", "CHAR(20)", "text"),
+        (rq.BigUnsignedType(), "bigint", "UNSIGNED", "integer"),
+        (rq.DecimalType((20, 2)), "decimal(20, 2)", "DECIMAL(20, 2)", "numeric"),
+        (rq.TimestampType(), "timestamp", "DATETIME", "text"),
+        (rq.JsonType(), "json", "JSON", "text"),
+        (rq.BlobType(), "bytea", "BINARY", "blob"),
+    ]
+
+    for type, postgres, mysql, sqlite in cases:
+        expr = rq.Expr.col("x").cast_as(type)
+        assert expr.to_sql("postgresql") == f'CAST("x" AS {postgres})'
+        assert expr.to_sql("mysql") == f"CAST(`x` AS {mysql})"
+        assert expr.to_sql("sqlite") == f'CAST("x" AS {sqlite})'
+
+
+def test_cast_as_invalid_name():
+    for name in ("", "   ", "int); DROP TABLE users; --", "int(", "text'"):
+        with pytest.raises(ValueError):
+            rq.Expr.col("id").cast_as(name)
+
+        with pytest.raises(ValueError):
+            rq.Expr.col("id").pg_cast(name)
+
+    for name in ("double precision", "numeric(10, 2)", "public.mood", "int[]"):
+        assert rq.Expr.col("id").cast_as(name).to_sql("postgresql") == f'CAST("id" AS {name})'
+
+
+def test_similar_to_postgres_only():
+    for backend in ("mysql", "sqlite"):
+        with pytest.raises(ValueError, match="SIMILAR TO is only supported by PostgreSQL"):
+            rq.Expr.col("code").similar_to("%(b|d)%").to_sql(backend)
+
+
+def test_compare_with_datetime():
+    naive = datetime(2024, 1, 2, 3, 4, 5)
+    aware = datetime(2024, 1, 2, 3, 4, 5, tzinfo=timezone(timedelta(hours=2)))
+
+    assert (rq.Expr.col("ts") > naive).to_sql("postgresql") == "\"ts\" > '2024-01-02 03:04:05.000000'"
+    assert (rq.Expr.col("ts") > aware).to_sql("postgresql") == "\"ts\" > '2024-01-02 03:04:05.000000 +02:00'"
+    assert (rq.Expr.col("ts") <= aware).to_sql("mysql") == "`ts` <= '2024-01-02 03:04:05.000000 +02:00'"
+
+    expr = rq.Expr.col("day").between(date(2024, 1, 1), date(2024, 2, 1))
+    assert expr.to_sql("sqlite") == "\"day\" BETWEEN '2024-01-01' AND '2024-02-01'"
+
+    # The bound parameters keep the original values, timezone included
+    _, params = rq.Select(rq.Expr.col("id")).from_table("events").where(rq.Expr.col("ts") > aware).build("postgresql")
+    assert params[0].is_datetime
+    assert params[0].value == aware
+    assert params[0].value.utcoffset() == timedelta(hours=2)
+
+    _, params = rq.Select(rq.Expr.col("id")).from_table("events").where(rq.Expr.col("ts") > naive).build("postgresql")
+    assert params[0].value == naive
+    assert params[0].value.tzinfo is None
+
+
+def test_pg_cast():
+    assert rq.Expr.col("col").pg_cast("text").to_sql("postgresql") == '"col"::text'
+    assert rq.Expr.col("col").pg_cast(rq.TextType()).to_sql("postgresql") == '"col"::text'
+    assert rq.Expr.col("ids").pg_cast(rq.ArrayType(rq.IntegerType())).to_sql("postgresql") == '"ids"::integer[]'
+
+    # The operand is parenthesized unless it's atomic, and the cast itself never is
+    expr = (rq.Expr.col("a") + 1).pg_cast(rq.StringType(10)) == "1"
+    assert expr.to_sql("postgresql") == """("a" + 1)::varchar(10) = '1'"""
+
+    for backend in ("mysql", "sqlite"):
+        with pytest.raises(ValueError, match="cast is only supported by PostgreSQL"):
+            rq.Expr.col("col").pg_cast("text").to_sql(backend)
+
+    with pytest.raises(ValueError):
+        rq.Expr.col("col").pg_cast(rq.YearType())
+
+    with pytest.raises(TypeError):
+        rq.Expr.col("col").pg_cast(1)
+
+
+@pytest.mark.parametrize(
+    "backend,expected",
+    [
+        ("postgresql", 'CAST("flags" AS boolean)'),
+        ("mysql", "`flags` <> 0"),
+        ("sqlite", '"flags" <> 0'),
+    ],
+)
+def test_to_bool(backend, expected):
+    assert rq.Expr.col("flags").to_bool().to_sql(backend) == expected
+
+    query = rq.Select(rq.Expr.col("id")).from_table("users").where(rq.Expr.col("flags").to_bool())
+    assert query.to_sql(backend).endswith(f"WHERE {expected}")
+
+
+def test_to_bool_precedence():
+    expr = (rq.Expr.col("a") + 1).to_bool() & rq.Expr.col("b").to_bool()
+    assert expr.to_sql("postgresql") == 'CAST("a" + 1 AS boolean) AND CAST("b" AS boolean)'
+    assert expr.to_sql("sqlite") == '"a" + 1 <> 0 AND "b" <> 0'
+
+    # Compared to each other, the `<> 0` comparisons are parenthesized
+    expr = rq.Expr.col("a").to_bool() == rq.Expr.col("b").to_bool()
+    assert expr.to_sql("mysql") == "(`a` <> 0) = (`b` <> 0)"
+
+
+def test_like_escape_str():
+    assert rq.Expr.col("a").like("50!%", escape="!").to_sql("sqlite") == """"a" LIKE '50!%' ESCAPE '!'"""
+    assert rq.Expr.col("a").not_like("50\\%", escape="\\").to_sql("mysql") == (
+        "`a` NOT LIKE '50\\\\%' ESCAPE '\\\\'"
+    )
+
+    for method in ("like", "not_like", "similar_to", "not_similar_to", "pg_ilike", "pg_not_ilike"):
+        with pytest.raises(ValueError, match="single character"):
+            getattr(rq.Expr.col("a"), method)("x", escape="!!")
+
+        with pytest.raises(ValueError, match="single character"):
+            getattr(rq.Expr.col("a"), method)("x", escape="")
+
+
+def test_shift_operands():
+    assert (rq.Expr.col("flags") << 2).to_sql("postgresql") == '"flags" << 2'
+    assert (rq.Expr.col("flags") >> rq.Expr.col("n")).to_sql("postgresql") == '"flags" >> "n"'
+
+    # The operators are lenient; strict shifts check the literals on PostgreSQL
+    assert (rq.Expr.col("flags") << "2").to_sql("postgresql") == """"flags" << '2'"""
+    assert rq.Expr.col("flags").lshift(2, strict=True).to_sql("postgresql") == '"flags" << 2'
+
+    strict = (
+        rq.Expr.col("flags").lshift("2", strict=True),
+        rq.Expr("8").rshift(1, strict=True),
+        rq.Expr.col("flags").lshift(1.5, strict=True),
+    )
+    for query in strict:
+        with pytest.raises(ValueError, match="must be integers"):
+            query.to_sql("postgresql")
+
+    # MySQL and SQLite convert the operands themselves
+    assert strict[0].to_sql("mysql") == "`flags` << '2'"
+    assert strict[0].to_sql("sqlite") == "\"flags\" << '2'"
+
+
+def test_extended_exprs_in_order_by_and_check():
+    a, b = rq.Expr.col("a"), rq.Expr.col("b")
+    cases = [
+        (a // b, 'FLOOR(CAST("a" AS numeric) / "b")', "FLOOR(`a` / `b`)"),
+        (a.case_when([(1, "x")]), """CASE "a" WHEN 1 THEN 'x' END""", "CASE `a` WHEN 1 THEN 'x' END"),
+        (a.is_distinct_from(b), '"a" IS DISTINCT FROM "b"', "NOT (`a` <=> `b`)"),
+        (a.is_not_distinct_from(b), '"a" IS NOT DISTINCT FROM "b"', "`a` <=> `b`"),
+        (a.to_bool(), 'CAST("a" AS boolean)', "`a` <> 0"),
+    ]
+
+    for expr, postgres, mysql in cases:
+        query = rq.Select(rq.Expr.col("x")).from_table("t").order_by(expr, "asc", "last")
+        assert query.to_sql("postgresql") == f'SELECT "x" FROM "t" ORDER BY {postgres} ASC NULLS LAST'
+        # MySQL emulates NULLS LAST with an extra sort key
+        assert query.to_sql("mysql") == f"SELECT `x` FROM `t` ORDER BY ({mysql}) IS NULL ASC, {mysql} ASC"
+
+        table = rq.Table("t", [rq.Column("a", rq.IntegerType())], checks=[expr])
+        assert table.to_sql("postgresql") == f'CREATE TABLE "t" ( "a" integer, CHECK ({postgres}) );\n'
+        assert table.to_sql("mysql") == f"CREATE TABLE `t` ( `a` int, CHECK ({mysql}) );\n"
+
+    json_agg = rq.Select(rq.Expr.json_agg(a)).from_table("t").order_by(rq.Expr.json_agg(a), "desc", "first")
+    assert json_agg.to_sql("sqlite") == (
+        'SELECT json_group_array("a") FROM "t" ORDER BY json_group_array("a") DESC NULLS FIRST'
+    )
+    assert json_agg.to_sql("mysql") == (
+        "SELECT JSON_ARRAYAGG(`a`) FROM `t` ORDER BY JSON_ARRAYAGG(`a`) IS NULL DESC, JSON_ARRAYAGG(`a`) DESC"
+    )
+
+    any_array = a == rq.Expr.any_array([1, 2])
+    assert rq.Select(rq.Expr.col("x")).from_table("t").order_by(any_array, "desc").to_sql("postgresql") == (
+        'SELECT "x" FROM "t" ORDER BY "a" = ANY(ARRAY[1, 2]) DESC'
+    )
+    assert rq.Index(["a"], name="ix", table="t", where=a.similar_to("x%")).to_sql("postgresql") == (
+        """CREATE INDEX "ix" ON "t" ("a") WHERE "a" SIMILAR TO 'x%'"""
+    )
+    assert rq.Table("t", [rq.Column("a", rq.IntegerType(), default=a // b)]).to_sql("sqlite") == (
+        'CREATE TABLE "t" ( "a" integer DEFAULT (FLOOR(CAST("a" AS real) / "b")) );\n'
+    )
+
+    # PostgreSQL only expressions are rejected instead of being rendered as is
+    for expr in (any_array, a.pg_cast("text"), a.similar_to("x%")):
+        with pytest.raises(ValueError, match="only supported by PostgreSQL"):
+            rq.Select(rq.Expr.col("x")).from_table("t").order_by(expr, "asc").to_sql("mysql")
+
+        with pytest.raises(ValueError, match="only supported by PostgreSQL"):
+            rq.Table("t", [rq.Column("a", rq.IntegerType())], checks=[expr]).to_sql("sqlite")
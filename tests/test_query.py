@@ -0,0 +1,1184 @@
+import pytest
+
+from rapidquery import _lib
+
+
+class TestInsert:
+    def test_values_many_matches_values(self):
+        rows = [(i, f"name-{i}", i % 2 == 0) for i in range(1000)]
+
+        bulk = _lib.Insert().into("users").columns("id", "name", "active").values_many(rows)
+        repeated = _lib.Insert().into("users").columns("id", "name", "active")
+        for row in rows:
+            repeated.values(*row)
+
+        for backend in ("postgresql", "mysql", "sqlite"):
+            assert bulk.to_sql(backend) == repeated.to_sql(backend)
+
+        sql, params = bulk.build("postgresql")
+        assert sql == repeated.build("postgresql")[0]
+        assert len(params) == 3000
+
+    def test_values_many_dicts(self):
+        query = (
+            _lib.Insert()
+            .into("t")
+            .values(a=0, b="z")
+            .values_many([{"a": 1, "b": "x"}, {"b": "y", "a": 2}])
+        )
+
+        assert query.to_sql("sqlite") == (
+            'INSERT INTO "t" ("a", "b") VALUES (0, \'z\'), (1, \'x\'), (2, \'y\')'
+        )
+
+    def test_values_many_invalid(self):
+        query = _lib.Insert().into("t").columns("a", "b")
+
+        with pytest.raises(ValueError):
+            query.values_many([(1, 2), (3,)])
+
+        with pytest.raises(ValueError):
+            query.values_many([{"a": 1, "c": 2}])
+
+        with pytest.raises(TypeError):
+            query.values_many([[1, 2]])
+
+        assert query.values_many([]).to_sql("postgresql") == 'INSERT INTO "t" ("a", "b")'
+
+    def test_values_dict_then_tuple(self):
+        query = _lib.Insert().into("t").values(a=0, b="z").values(1, "x").values(b="y", a=2)
+
+        # Later dicts are matched by key, not by position
+        assert query.to_sql("sqlite") == (
+            'INSERT INTO "t" ("a", "b") VALUES (0, \'z\'), (1, \'x\'), (2, \'y\')'
+        )
+
+        with pytest.raises(ValueError, match="expected 2 values"):
+            query.values(1, "x", True)
+
+        with pytest.raises(ValueError, match="expected 2 values"):
+            query.values(3)
+
+        with pytest.raises(ValueError, match="expected 2 values"):
+            query.values(a=3, b="w", c=True)
+
+        with pytest.raises(ValueError, match='no value for column "b"'):
+            query.values(a=3, c="w")
+
+        # Failed calls don't add rows
+        assert query.to_sql("sqlite") == (
+            'INSERT INTO "t" ("a", "b") VALUES (0, \'z\'), (1, \'x\'), (2, \'y\')'
+        )
+
+    def test_replace(self):
+        query = _lib.Insert().replace().into("t").columns("id", "name").values(1, "a")
+
+        assert query.to_sql("mysql") == "REPLACE INTO `t` (`id`, `name`) VALUES (1, 'a')"
+        assert query.to_sql("sqlite") == 'REPLACE INTO "t" ("id", "name") VALUES (1, \'a\')'
+
+        with pytest.raises(ValueError):
+            query.to_sql("postgresql")
+
+        with pytest.raises(ValueError):
+            query.build("postgresql")
+
+    def test_returning_column_ref(self):
+        table = _lib.Table("users", [_lib.Column("id", _lib.IntegerType()), _lib.Column("name", _lib.TextType())])
+        query = _lib.Insert().into(table).columns("name").values("a")
+
+        query.returning(table.c.id.to_column_ref(), _lib.ColumnRef("name"))
+        assert query.to_sql("postgresql") == 'INSERT INTO "users" ("name") VALUES (\'a\') RETURNING "id", "name"'
+
+        with pytest.raises(ValueError):
+            query.returning(_lib.ColumnRef("id", table="accounts"))
+
+        with pytest.raises(ValueError):
+            query.returning(_lib.ColumnRef.parse("*"))
+
+
+    @pytest.mark.parametrize(
+        "backend,one,three",
+        [
+            ("postgresql", 'INSERT INTO "t" DEFAULT VALUES', 'INSERT INTO "t" VALUES (DEFAULT), (DEFAULT), (DEFAULT)'),
+            ("mysql", "INSERT INTO `t` VALUES ()", "INSERT INTO `t` VALUES (), (), ()"),
+            ("sqlite", 'INSERT INTO "t" DEFAULT VALUES', None),
+        ],
+    )
+    def test_default_values(self, backend, one, three):
+        assert _lib.Insert().into("t").or_default_values().to_sql(backend) == one
+        assert _lib.Insert().into("t").or_default_values(1).to_sql(backend) == one
+
+        query = _lib.Insert().into("t").or_default_values(3)
+        if three is None:
+            with pytest.raises(ValueError, match="3 rows of default values"):
+                query.to_sql(backend)
+        else:
+            assert query.to_sql(backend) == three
+
+        # Defaults are only used when no values are given
+        query = _lib.Insert().into("t").columns("a").values(1).or_default_values(3)
+        assert "DEFAULT" not in query.to_sql(backend)
+
+        with pytest.raises(ValueError):
+            _lib.Insert().into("t").or_default_values(0)
+
+
+class TestUpdate:
+    def test_set_expr(self):
+        query = (
+            _lib.Update()
+            .table("t")
+            .set_expr("n", _lib.Expr.col("n") + 1)
+            .where(_lib.Expr.col("id") == 5)
+        )
+
+        assert query.to_sql("postgresql") == 'UPDATE "t" SET "n" = "n" + 1 WHERE "id" = 5'
+
+    def test_set_expr_with_values(self):
+        query = (
+            _lib.Update()
+            .table("t")
+            .values(name="x")
+            .set_expr(_lib.Column("n", _lib.IntegerType()), _lib.Expr.col("n") * 2)
+            .set_expr("name", _lib.Expr.col("other"))
+        )
+
+        assert query.to_sql("sqlite") == 'UPDATE "t" SET "name" = "other", "n" = "n" * 2'
+
+    def test_values_after_set_expr(self):
+        query = _lib.Update().table("t").set_expr("n", _lib.Expr.col("n") + 1).values(name="x")
+
+        # Later values() calls replace the earlier ones, but keep set_expr assignments
+        assert query.values(title="y").to_sql("sqlite") == 'UPDATE "t" SET "title" = \'y\', "n" = "n" + 1'
+        assert query.values(n=0).to_sql("sqlite") == 'UPDATE "t" SET "n" = 0'
+
+    def test_set_expr_invalid_column(self):
+        with pytest.raises(TypeError):
+            _lib.Update().table("t").set_expr(1, 2)
+
+    def test_values_from_dict(self):
+        query = _lib.Update().table("t").values({"c": 3, "a": _lib.Expr.col("b"), "b": "x"})
+
+        assert query.to_sql("postgresql") == 'UPDATE "t" SET "c" = 3, "a" = "b", "b" = \'x\''
+
+    def test_values_from_dict_and_kwargs(self):
+        query = _lib.Update().table("t").values({"a": 1, "b": 2}, c=3, a=4)
+
+        assert query.to_sql("mysql") == "UPDATE `t` SET `a` = 4, `b` = 2, `c` = 3"
+
+    def test_values_non_str_key(self):
+        with pytest.raises(TypeError):
+            _lib.Update().table("t").values({1: 2})
+
+    def test_order_by_null_order_mysql(self):
+        query = _lib.Update().table("t").values(a=1).order_by(_lib.Expr.col("a"), "desc", "last").limit(1)
+
+        assert query.to_sql("mysql") == "UPDATE `t` SET `a` = 1 ORDER BY `a` IS NULL ASC, `a` DESC LIMIT 1"
+
+    def test_table_from_table_object(self):
+        users = _lib.Table(_lib.TableName("users", schema="app"), [_lib.Column("id", _lib.IntegerType())])
+        orders = _lib.Table("orders", [_lib.Column("user_id", _lib.IntegerType())])
+
+        query = (
+            _lib.Update()
+            .table(users)
+            .from_table(orders)
+            .values(active=True)
+            .where(_lib.Expr.col("orders.user_id") == _lib.Expr.col("users.id"))
+        )
+
+        assert query.to_sql("postgresql") == (
+            'UPDATE "app"."users" SET "active" = TRUE FROM "orders" WHERE "orders"."user_id" = "users"."id"'
+        )
+
+
+class TestDelete:
+    def test_using(self):
+        query = (
+            _lib.Delete()
+            .from_table("orders")
+            .using("customers")
+            .where(_lib.Expr.col("orders.customer_id") == _lib.Expr.col("customers.id"))
+            .returning_all()
+        )
+
+        assert query.to_sql("postgresql") == (
+            'DELETE FROM "orders" USING "customers" '
+            'WHERE "orders"."customer_id" = "customers"."id" RETURNING *'
+        )
+
+    def test_using_many_tables(self):
+        query = _lib.Delete().from_table("a").using("b").using(_lib.TableName("c", schema="s"))
+
+        sql, params = query.build("postgresql")
+        assert sql == 'DELETE FROM "a" USING "b", "s"."c"'
+        assert params == ()
+
+    def test_using_unsupported_backend(self):
+        query = _lib.Delete().from_table("a").using("b")
+
+        with pytest.raises(ValueError):
+            query.to_sql("mysql")
+
+        with pytest.raises(ValueError):
+            query.build("sqlite")
+
+    def test_from_table_object(self):
+        users = _lib.Table(_lib.TableName("users", schema="app"), [_lib.Column("id", _lib.IntegerType())])
+        banned = _lib.Table("banned", [_lib.Column("user_id", _lib.IntegerType())])
+
+        query = (
+            _lib.Delete()
+            .from_table(users)
+            .using(banned)
+            .where(_lib.Expr.col("banned.user_id") == _lib.Expr.col("users.id"))
+        )
+
+        assert query.to_sql("postgresql") == (
+            'DELETE FROM "app"."users" USING "banned" WHERE "banned"."user_id" = "users"."id"'
+        )
+
+
+class TestSelect:
+    def test_is_distinct_from_in_subquery_mysql(self):
+        sub = _lib.Select(_lib.Expr.col("id")).from_table("b").where(
+            _lib.Expr.col("x").is_distinct_from(_lib.Expr.col("y"))
+        )
+        query = _lib.Select(_lib.ASTERISK).from_table("a").where(_lib.Expr.col("id").in_subquery(sub))
+
+        sql, params = query.build("mysql")
+        assert sql == (
+            "SELECT * FROM `a` WHERE `id` IN "
+            "(SELECT `id` FROM `b` WHERE NOT (`x` <=> `y`))"
+        )
+
+    @pytest.mark.parametrize(
+        "backend,expected",
+        [
+            ("postgresql", 'SELECT "id" FROM "t" OFFSET 5'),
+            ("sqlite", 'SELECT "id" FROM "t" LIMIT 9223372036854775807 OFFSET 5'),
+            ("mysql", "SELECT `id` FROM `t` LIMIT 18446744073709551615 OFFSET 5"),
+        ],
+    )
+    def test_offset_without_limit(self, backend, expected):
+        query = _lib.Select(_lib.Expr.col("id")).from_table("t").offset(5)
+
+        assert query.to_sql(backend) == expected
+
+    def test_offset_with_limit(self):
+        query = _lib.Select(_lib.Expr.col("id")).from_table("t").limit(10).offset(5)
+
+        assert query.to_sql("mysql") == "SELECT `id` FROM `t` LIMIT 10 OFFSET 5"
+        assert query.to_sql("sqlite") == 'SELECT "id" FROM "t" LIMIT 10 OFFSET 5'
+
+    def test_offset_without_limit_in_subquery(self):
+        sub = _lib.Select(_lib.Expr.col("id")).from_table("b").offset(5)
+        query = _lib.Select(_lib.ASTERISK).from_table("a").where(_lib.Expr.col("id").in_subquery(sub))
+
+        assert query.to_sql("mysql") == (
+            "SELECT * FROM `a` WHERE `id` IN (SELECT `id` FROM `b` LIMIT 18446744073709551615 OFFSET 5)"
+        )
+        assert query.to_sql("postgresql") == 'SELECT * FROM "a" WHERE "id" IN (SELECT "id" FROM "b" OFFSET 5)'
+
+    def test_case_when_in_subquery(self):
+        sub = _lib.Select(_lib.Expr.col("status").case_when([(1, "a")], else_="b")).from_table("b")
+        query = _lib.Select(_lib.ASTERISK).from_table("a").where(_lib.Expr.col("x").in_subquery(sub))
+
+        sql, params = query.build("postgresql")
+        assert sql == (
+            'SELECT * FROM "a" WHERE "x" IN '
+            '(SELECT CASE "status" WHEN $1 THEN $2 ELSE $3 END FROM "b")'
+        )
+        assert len(params) == 3
+
+    def test_repeated_build_is_stable(self):
+        query = (
+            _lib.Select(_lib.Expr.col("u.id"), _lib.Expr.col("o.total"))
+            .distinct(_lib.ColumnRef("id", table="u"))
+            .from_table(_lib.TableName("users", schema="app", alias="u"))
+            .join(_lib.TableName("orders", alias="o"), _lib.Expr.col("o.user_id") == _lib.Expr.col("u.id"))
+            .lock("exclusive", "nowait", tables=[_lib.TableName("users", schema="app")])
+        )
+        expected = (
+            'SELECT DISTINCT ON ("u"."id") "u"."id", "o"."total" FROM "app"."users" AS "u" '
+            'JOIN "orders" AS "o" ON "o"."user_id" = "u"."id" FOR UPDATE OF "u" NOWAIT'
+        )
+
+        for _ in range(1000):
+            assert query.to_sql("postgresql") == expected
+
+    def test_repeated_build(self):
+        def make_query():
+            return (
+                _lib.Select(_lib.ColumnRef("id", table="u"), _lib.ColumnRef("total", table="o"))
+                .from_table(_lib.TableName("users", schema="app", alias="u"))
+                .join(_lib.TableName("orders", alias="o"), _lib.Expr.col("o.user_id") == _lib.Expr.col("u.id"))
+                .lock("exclusive", tables=[_lib.TableName("users", schema="app")])
+            )
+
+        # The same query reuses the table and column references converted by its
+        # first build, which must render the same as fresh objects
+        fresh = [make_query().to_sql("postgresql") for _ in range(3)]
+
+        query = make_query()
+        reused = [query.to_sql("postgresql") for _ in range(3)]
+
+        assert reused == fresh
+        assert set(reused) == {
+            'SELECT "u"."id", "o"."total" FROM "app"."users" AS "u" '
+            'JOIN "orders" AS "o" ON "o"."user_id" = "u"."id" FOR UPDATE OF "u"'
+        }
+
+    def test_subquery_columns(self):
+        sub = (
+            _lib.Select(
+                _lib.Expr.col("orders.user_id"),
+                _lib.SelectCol(_lib.FunctionCall.sum(_lib.Expr.col("amount")), "total"),
+                _lib.Expr.col("a") + 1,
+            )
+            .from_table("orders")
+            .group_by(_lib.Expr.col("orders.user_id"))
+            .subquery("t")
+        )
+
+        assert sub.alias == "t"
+        assert len(sub.c) == 2
+        assert [c.name for c in sub.c.to_list()] == ["user_id", "total"]
+        assert sub.c.total.table == "t"
+
+        with pytest.raises(KeyError):
+            sub.c.get("a")
+
+    def test_subquery_from_and_where(self):
+        sub = (
+            _lib.Select(
+                _lib.Expr.col("user_id"),
+                _lib.SelectCol(_lib.FunctionCall.sum(_lib.Expr.col("amount")), "total"),
+            )
+            .from_table("orders")
+            .group_by(_lib.Expr.col("user_id"))
+            .subquery("t")
+        )
+        query = _lib.Select(sub.c.user_id).from_table(sub).where(_lib.Expr(sub.c.total) > 100)
+
+        assert query.to_sql("postgresql") == (
+            'SELECT "t"."user_id" FROM (SELECT "user_id", SUM("amount") AS "total" '
+            'FROM "orders" GROUP BY "user_id") AS "t" WHERE "t"."total" > 100'
+        )
+
+    def test_subquery_join(self):
+        sub = _lib.Select(_lib.Expr.col("id")).from_table("admins").subquery("adm")
+        query = _lib.Select(_lib.Expr.col("u.name")).from_table(_lib.TableName("users", alias="u")).join(
+            sub, _lib.Expr(sub.c.id) == _lib.Expr.col("u.id"), "left"
+        )
+
+        assert query.to_sql("mysql") == (
+            "SELECT `u`.`name` FROM `users` AS `u` "
+            "LEFT JOIN (SELECT `id` FROM `admins`) AS `adm` ON `adm`.`id` = `u`.`id`"
+        )
+
+        with pytest.raises(ValueError):
+            sub.select.join(sub, _lib.Expr(1) == 1)
+
+    def test_with_recursive_numbers(self):
+        base = _lib.Select(_lib.SelectCol(1, "n"))
+        step = _lib.Select(_lib.Expr.col("n") + 1).from_table("t").where(_lib.Expr.col("n") < 10)
+        query = _lib.Select(_lib.Expr.col("n")).with_recursive("t", base, step).from_table("t")
+
+        assert query.to_sql("postgresql") == (
+            'WITH RECURSIVE "t" AS (SELECT 1 AS "n" UNION ALL '
+            '(SELECT "n" + 1 FROM "t" WHERE "n" < 10)) SELECT "n" FROM "t"'
+        )
+        assert query.to_sql("sqlite") == (
+            'WITH RECURSIVE "t" AS (SELECT 1 AS "n" UNION ALL '
+            'SELECT "n" + 1 FROM "t" WHERE "n" < 10) SELECT "n" FROM "t"'
+        )
+
+    def test_with_recursive_tree_walk(self):
+        base = _lib.Select(_lib.Expr.col("id"), _lib.Expr.col("parent_id")).from_table("nodes").where(
+            _lib.Expr.col("parent_id").is_null()
+        )
+        step = (
+            _lib.Select(_lib.Expr.col("n.id"), _lib.Expr.col("n.parent_id"))
+            .from_table(_lib.TableName("nodes", alias="n"))
+            .join(_lib.TableName("tree", alias="t"), _lib.Expr.col("n.parent_id") == _lib.Expr.col("t.id"))
+        )
+        query = (
+            _lib.Select(_lib.Expr.col("id"))
+            .with_recursive("tree", base, step, union_all=False, columns=["id", "parent_id"])
+            .from_table("tree")
+        )
+
+        sql, params = query.build("postgresql")
+        assert sql == (
+            'WITH RECURSIVE "tree" ("id", "parent_id") AS ('
+            'SELECT "id", "parent_id" FROM "nodes" WHERE "parent_id" IS NULL UNION '
+            '(SELECT "n"."id", "n"."parent_id" FROM "nodes" AS "n" '
+            'JOIN "tree" AS "t" ON "n"."parent_id" = "t"."id")) '
+            'SELECT "id" FROM "tree"'
+        )
+        assert params == ()
+
+    def test_with_recursive_invalid(self):
+        query = _lib.Select(_lib.Expr.col("n"))
+
+        with pytest.raises(TypeError):
+            query.with_recursive("t", _lib.Select(_lib.Expr.col("n")), "SELECT 1")
+
+        with pytest.raises(ValueError):
+            query.with_recursive("t", query, _lib.Select(_lib.Expr.col("n")))
+
+    @pytest.mark.parametrize(
+        "backend,expected",
+        [
+            ("postgresql", 'SELECT "id" FROM "t" FOR SHARE SKIP LOCKED'),
+            ("mysql", "SELECT `id` FROM `t` FOR SHARE SKIP LOCKED"),
+        ],
+    )
+    def test_lock_shared_skip(self, backend, expected):
+        query = _lib.Select(_lib.Expr.col("id")).from_table("t").lock("shared", "skip")
+
+        assert query.to_sql(backend) == expected
+
+    def test_lock_sqlite_omitted(self):
+        query = _lib.Select(_lib.Expr.col("id")).from_table("t").lock("shared", "nowait")
+
+        assert "FOR" not in query.to_sql("sqlite")
+
+    def test_lock_exclusive_nowait(self):
+        query = _lib.Select(_lib.Expr.col("id")).from_table("t").lock("exclusive", "nowait", tables=["t"])
+
+        assert query.to_sql("postgresql") == 'SELECT "id" FROM "t" FOR UPDATE OF "t" NOWAIT'
+        assert query.to_sql("mysql") == "SELECT `id` FROM `t` FOR UPDATE OF `t` NOWAIT"
+
+    def test_lock_of(self):
+        accounts = _lib.TableName("accounts", alias="a")
+        query = (
+            _lib.Select(_lib.Expr.asterisk())
+            .from_table(accounts)
+            .join(_lib.TableName("orders", alias="o"), _lib.Expr.col("a.id") == _lib.Expr.col("o.account_id"))
+            .lock()
+            .of(accounts)
+        )
+
+        # Aliased tables are referred to by their alias
+        assert query.to_sql("postgresql") == (
+            'SELECT * FROM "accounts" AS "a" JOIN "orders" AS "o" ON "a"."id" = "o"."account_id" FOR UPDATE OF "a"'
+        )
+
+        query.of("a", "o")
+        assert query.to_sql("postgresql").endswith('FOR UPDATE OF "a", "o"')
+
+        with pytest.raises(ValueError):
+            _lib.Select(_lib.Expr.asterisk()).from_table("t").of("t")
+
+    def test_lock_of_resolves_aliases(self):
+        query = _lib.Select(_lib.Expr.asterisk()).from_table(_lib.TableName("a", alias="x")).lock().of("a")
+        assert query.to_sql("postgresql") == 'SELECT * FROM "a" AS "x" FOR UPDATE OF "x"'
+        assert query.to_sql("mysql") == "SELECT * FROM `a` AS `x` FOR UPDATE OF `x`"
+
+        query.of(_lib.TableName("a", schema="app"))
+        with pytest.raises(ValueError, match="isn't a table of FROM"):
+            query.to_sql("postgresql")
+
+        query.of("b")
+        with pytest.raises(ValueError, match="isn't a table of FROM"):
+            query.to_sql("mysql")
+
+        # Tables are referred to unqualified
+        query = _lib.Select(_lib.Expr.asterisk()).from_table(_lib.TableName("a", schema="app")).lock().of("app.a")
+        assert query.to_sql("postgresql") == 'SELECT * FROM "app"."a" FOR UPDATE OF "a"'
+
+        query = (
+            _lib.Select(_lib.Expr.asterisk())
+            .from_table(_lib.TableName("a", alias="x"))
+            .join(_lib.TableName("a", alias="y"), _lib.Expr.col("x.id") == _lib.Expr.col("y.parent_id"))
+            .lock()
+            .of("a")
+        )
+        with pytest.raises(ValueError, match="ambiguous"):
+            query.to_sql("postgresql")
+
+        query.of("y")
+        assert query.to_sql("postgresql").endswith('FOR UPDATE OF "y"')
+
+    def test_lock_invalid(self):
+        query = _lib.Select(_lib.Expr.col("id")).from_table("t")
+
+        with pytest.raises(ValueError):
+            query.lock("update")
+
+        with pytest.raises(ValueError):
+            query.lock("shared", "wait")
+
+    def test_distinct_on_expression(self):
+        query = (
+            _lib.Select(_lib.SelectCol(_lib.Expr.col("name"), "n"), _lib.Expr.col("id"))
+            .from_table("t")
+            .where(_lib.Expr.col("id") > 1)
+            .distinct(_lib.FunctionCall.lower(_lib.Expr.col("name")), "id")
+        )
+
+        sql, params = query.build("postgresql")
+        assert sql == 'SELECT DISTINCT ON (LOWER("name"), "id") "name" AS "n", "id" FROM "t" WHERE "id" > $1'
+        assert len(params) == 1
+
+        # Like column targets, DISTINCT ON is dropped on other backends
+        assert query.to_sql("sqlite") == 'SELECT "name" AS "n", "id" FROM "t" WHERE "id" > 1'
+
+    def test_distinct_on_expression_params(self):
+        query = (
+            _lib.Select(_lib.Expr.col("id"))
+            .from_table("t")
+            .where(_lib.Expr.col("a") > 1)
+            .distinct(_lib.Expr.col("a") > 5)
+        )
+
+        sql, params = query.build("postgresql")
+        assert sql == 'SELECT DISTINCT ON ("a" > $1) "id" FROM "t" WHERE "a" > $2'
+        assert len(params) == 2
+
+    def test_distinct_invalid(self):
+        with pytest.raises(TypeError):
+            _lib.Select(_lib.Expr.col("id")).distinct(1)
+
+    def test_time_bucket_group_by(self):
+        bucket = _lib.Expr.time_bucket("5 minutes", _lib.Expr.col("ts"))
+        query = (
+            _lib.Select(_lib.SelectCol(bucket, "bucket"), _lib.FunctionCall.avg(_lib.Expr.col("value")))
+            .from_table("metrics")
+            .group_by(bucket)
+        )
+
+        assert query.to_sql("postgresql") == (
+            """SELECT time_bucket('5 minutes', "ts") AS "bucket", AVG("value") FROM "metrics" """
+            """GROUP BY time_bucket('5 minutes', "ts")"""
+        )
+
+    def test_order_by_columns(self):
+        query = (
+            _lib.Select(_lib.ASTERISK)
+            .from_table("users")
+            .order_by(_lib.Expr.col("id"), "asc")
+            .order_by_columns(
+                (_lib.Expr.col("last_name"), "asc"),
+                (_lib.Expr.col("first_name"), "DESC", None),
+                (_lib.Expr.col("created_at"), "desc", "last"),
+            )
+        )
+
+        assert query.to_sql("postgresql") == (
+            'SELECT * FROM "users" ORDER BY "id" ASC, "last_name" ASC, "first_name" DESC, '
+            '"created_at" DESC NULLS LAST'
+        )
+
+    def test_order_by_columns_invalid(self):
+        query = _lib.Select(_lib.ASTERISK).from_table("users")
+
+        with pytest.raises(TypeError):
+            query.order_by_columns([_lib.Expr.col("id"), "asc"])
+
+        with pytest.raises(ValueError):
+            query.order_by_columns((_lib.Expr.col("id"),))
+
+        with pytest.raises(ValueError):
+            query.order_by_columns((_lib.Expr.col("id"), "asc"), (_lib.Expr.col("name"), "up"))
+
+        # Nothing is appended when a spec is rejected
+        assert query.to_sql("postgresql") == 'SELECT * FROM "users"'
+
+    @pytest.mark.parametrize(
+        "backend,expected",
+        [
+            ("postgresql", 'SELECT "a" FROM "t" ORDER BY "a" ASC NULLS FIRST, "b" DESC NULLS LAST'),
+            ("sqlite", 'SELECT "a" FROM "t" ORDER BY "a" ASC NULLS FIRST, "b" DESC NULLS LAST'),
+            ("mysql", "SELECT `a` FROM `t` ORDER BY `a` IS NULL DESC, `a` ASC, `b` IS NULL ASC, `b` DESC"),
+        ],
+    )
+    def test_order_by_null_order(self, backend, expected):
+        query = (
+            _lib.Select(_lib.Expr.col("a"))
+            .from_table("t")
+            .order_by(_lib.Expr.col("a"), "asc", "first")
+            .order_by(_lib.Expr.col("b"), "desc", "last")
+        )
+
+        assert query.to_sql(backend) == expected
+
+    def test_from_lateral(self):
+        latest = (
+            _lib.Select(_lib.Expr.col("total"))
+            .from_table("orders")
+            .where(_lib.Expr.col("orders.user_id") == _lib.Expr.col("t.id"))
+            .limit(1)
+        )
+        query = (
+            _lib.Select(_lib.Expr.col("t.id"), _lib.Expr.col("s.total"))
+            .from_table("t")
+            .from_lateral(latest, "s", _lib.Expr.col("s.total") > 10)
+        )
+
+        sql, params = query.build("postgresql")
+        assert sql == (
+            'SELECT "t"."id", "s"."total" FROM "t", LATERAL (SELECT "total" FROM "orders" '
+            'WHERE "orders"."user_id" = "t"."id" LIMIT $1) AS "s" WHERE "s"."total" > $2'
+        )
+        assert len(params) == 2
+
+    def test_from_lateral_invalid(self):
+        query = _lib.Select(_lib.ASTERISK).from_table("t")
+
+        with pytest.raises(TypeError):
+            query.from_lateral("orders", "s")
+
+        with pytest.raises(ValueError):
+            query.from_lateral(query, "s")
+
+    def test_and_having(self):
+        query = _lib.Select(_lib.Expr.col("region")).from_table("sales").group_by(_lib.Expr.col("region"))
+        assert query.current_having is None
+
+        query.and_having(_lib.Expr.col("total") > 5).and_having(_lib.Expr.col("total") < 100)
+        assert query.current_having.to_sql("postgresql") == '"total" > 5 AND "total" < 100'
+
+        query.or_having(_lib.Expr.col("region") == "eu")
+        assert query.current_having.to_sql("postgresql") == (
+            '("total" > 5 AND "total" < 100) OR "region" = \'eu\''
+        )
+        assert query.to_sql("postgresql").endswith(
+            'HAVING ("total" > 5 AND "total" < 100) OR "region" = \'eu\''
+        )
+
+        query.having(_lib.Expr.col("total") > 1)
+        assert query.current_having.to_sql("postgresql") == '"total" > 1'
+
+    def test_join_subquery(self):
+        totals = (
+            _lib.Select(
+                _lib.Expr.col("user_id"),
+                _lib.SelectCol(_lib.FunctionCall.sum(_lib.Expr.col("amount")), "total"),
+            )
+            .from_table("orders")
+            .group_by(_lib.Expr.col("user_id"))
+        )
+        query = (
+            _lib.Select(_lib.Expr.col("u.name"), _lib.Expr.col("t.total"))
+            .from_table(_lib.TableName("users", alias="u"))
+            .join_subquery(totals, "t", _lib.Expr.col("t.user_id") == _lib.Expr.col("u.id"), "inner")
+        )
+
+        assert query.to_sql("postgresql") == (
+            'SELECT "u"."name", "t"."total" FROM "users" AS "u" INNER JOIN '
+            '(SELECT "user_id", SUM("amount") AS "total" FROM "orders" GROUP BY "user_id") AS "t" '
+            'ON "t"."user_id" = "u"."id"'
+        )
+        assert query.to_sql("mysql") == (
+            "SELECT `u`.`name`, `t`.`total` FROM `users` AS `u` INNER JOIN "
+            "(SELECT `user_id`, SUM(`amount`) AS `total` FROM `orders` GROUP BY `user_id`) AS `t` "
+            "ON `t`.`user_id` = `u`.`id`"
+        )
+        assert query.to_sql("sqlite") == query.to_sql("postgresql")
+
+        with pytest.raises(TypeError):
+            query.join_subquery("orders", "o", _lib.Expr.col("o.id") == 1)
+
+        with pytest.raises(ValueError):
+            query.join_subquery(query, "q", _lib.Expr.col("q.id") == 1)
+
+    def test_count_distinct(self):
+        query = _lib.Select(_lib.Expr.col("id")).from_table("t").count_distinct(_lib.Expr.col("col"))
+
+        assert query.to_sql("postgresql") == 'SELECT COUNT(DISTINCT "col") FROM "t"'
+        assert query.to_sql("mysql") == "SELECT COUNT(DISTINCT `col`) FROM `t`"
+
+        query.count_distinct(_lib.Expr.col("col"), "n")
+        assert query.to_sql("sqlite") == 'SELECT COUNT(DISTINCT "col") AS "n" FROM "t"'
+
+    def test_clear_where(self):
+        def search(name=None, min_age=None):
+            query = _lib.Select(_lib.Expr.asterisk()).from_table("users")
+
+            if name is not None:
+                query.where(_lib.Expr.col("name") == name)
+            if min_age is not None:
+                query.where(_lib.Expr.col("age") >= min_age)
+
+            return query
+
+        query = search()
+        assert not query.has_where()
+        assert query.to_sql("postgresql") == 'SELECT * FROM "users"'
+
+        query = search(name="alice", min_age=18)
+        assert query.has_where()
+        assert query.to_sql("postgresql") == (
+            'SELECT * FROM "users" WHERE "name" = \'alice\' AND "age" >= 18'
+        )
+
+        assert query.clear_where() is query
+        assert not query.has_where()
+        assert query.to_sql("postgresql") == 'SELECT * FROM "users"'
+
+    def test_to_sql_pretty(self):
+        query = (
+            _lib.Select(_lib.Expr.col("region"), _lib.FunctionCall.count(_lib.Expr.col("id")))
+            .from_table("orders")
+            .join("users", _lib.Expr.col("users.id") == _lib.Expr.col("orders.user_id"), "left")
+            .where(_lib.Expr.col("note") == "picked up FROM store")
+            .where(_lib.Expr.col("user_id").in_subquery(_lib.Select(_lib.Expr.col("id")).from_table("vip")))
+            .group_by(_lib.Expr.col("region"))
+            .order_by(_lib.Expr.col("region"), "asc")
+            .limit(10)
+        )
+
+        assert query.to_sql("postgresql", pretty=True) == (
+            'SELECT "region", COUNT("id")\n'
+            'FROM "orders"\n'
+            'LEFT JOIN "users" ON "users"."id" = "orders"."user_id"\n'
+            'WHERE "note" = \'picked up FROM store\' AND "user_id" IN (SELECT "id" FROM "vip")\n'
+            'GROUP BY "region"\n'
+            'ORDER BY "region" ASC\n'
+            "LIMIT 10"
+        )
+        assert "\n" not in query.to_sql("postgresql")
+
+        update = _lib.Update().table("t").values(a=1).where(_lib.Expr.col("id") == 1)
+        assert update.to_sql("mysql", pretty=True) == "UPDATE `t`\nSET `a` = 1\nWHERE `id` = 1"
+
+        # SQLite doesn't escape with backslashes, so the literal ends after one
+        query = _lib.Select(_lib.Expr.col("a")).from_table("t").where(_lib.Expr.col("a") == "x\\").limit(1)
+        assert query.to_sql("sqlite", pretty=True) == 'SELECT "a"\nFROM "t"\nWHERE "a" = \'x\\\'\nLIMIT 1'
+        assert query.to_sql("mysql", pretty=True) == "SELECT `a`\nFROM `t`\nWHERE `a` = 'x\\\\'\nLIMIT 1"
+
+    def test_top_n_per_group(self):
+        query = (
+            _lib.Select(_lib.Expr.col("name"), _lib.Expr.col("dept"))
+            .from_table("employees")
+            .where(_lib.Expr.col("active") == True)  # noqa: E712
+            .top_n_per_group([_lib.Expr.col("dept")], [(_lib.Expr.col("salary"), "desc")], 3)
+        )
+
+        assert query.to_sql("postgresql") == (
+            'SELECT "name", "dept" FROM (SELECT "name", "dept", ROW_NUMBER() OVER ( PARTITION BY "dept" '
+            'ORDER BY "salary" DESC ) AS "rn" FROM "employees" WHERE "active" = TRUE) AS "ranked" WHERE "rn" <= 3'
+        )
+
+        query = (
+            _lib.Select(_lib.Expr.col("e.id"), _lib.SelectCol(_lib.FunctionCall.lower(_lib.Expr.col("name")), "n"))
+            .from_table(_lib.TableName("employees", alias="e"))
+            .top_n_per_group([_lib.Expr.col("dept")], [(_lib.Expr.col("hired_at"), "asc", "last")], 1, rank_alias="r")
+            .order_by(_lib.Expr.col("id"), "asc")
+        )
+        assert query.to_sql("postgresql") == (
+            'SELECT "id", "n" FROM (SELECT "e"."id", LOWER("name") AS "n", ROW_NUMBER() OVER ( PARTITION BY "dept" '
+            'ORDER BY "hired_at" ASC NULLS LAST ) AS "r" FROM "employees" AS "e") AS "ranked" WHERE "r" <= 1 '
+            'ORDER BY "id" ASC'
+        )
+
+        # The default rank alias doesn't collide with the selected columns
+        query = (
+            _lib.Select(_lib.Expr.col("rn"), _lib.Expr.col("rn_1"))
+            .from_table("t")
+            .top_n_per_group([_lib.Expr.col("g")], [(_lib.Expr.col("rn"), "asc")], 2)
+        )
+        assert query.to_sql("postgresql") == (
+            'SELECT "rn", "rn_1" FROM (SELECT "rn", "rn_1", ROW_NUMBER() OVER ( PARTITION BY "g" ORDER BY "rn" ASC ) '
+            'AS "rn_2" FROM "t") AS "ranked" WHERE "rn_2" <= 2'
+        )
+
+        # ORDER BY, LIMIT, OFFSET and the lock given earlier apply to the outer query
+        query = (
+            _lib.Select(_lib.Expr.col("name"), _lib.Expr.col("dept"))
+            .from_table("ranked")
+            .order_by(_lib.Expr.col("name"), "asc")
+            .limit(10)
+            .offset(5)
+            .lock("shared")
+            .top_n_per_group([_lib.Expr.col("dept")], [(_lib.Expr.col("salary"), "desc")], 1)
+        )
+        assert query.to_sql("postgresql") == (
+            'SELECT "name", "dept" FROM (SELECT "name", "dept", ROW_NUMBER() OVER ( PARTITION BY "dept" '
+            'ORDER BY "salary" DESC ) AS "rn" FROM "ranked") AS "ranked_1" WHERE "rn" <= 1 '
+            'ORDER BY "name" ASC LIMIT 10 OFFSET 5 FOR SHARE'
+        )
+
+        query = _lib.Select(_lib.Expr.col("a")).from_table("t")
+        query.top_n_per_group([_lib.Expr.col("g")], [], 1, alias="top")
+        assert query.to_sql("postgresql") == (
+            'SELECT "a" FROM (SELECT "a", ROW_NUMBER() OVER ( PARTITION BY "g" ) AS "rn" FROM "t") AS "top" '
+            'WHERE "rn" <= 1'
+        )
+
+        with pytest.raises(ValueError, match="alias must not be empty"):
+            _lib.Select(_lib.Expr.col("a")).from_table("t").top_n_per_group([], [], 1, alias="")
+        with pytest.raises(ValueError, match="already a selected column"):
+            _lib.Select(_lib.Expr.col("rn")).from_table("t").top_n_per_group([], [], 1, rank_alias="rn")
+        with pytest.raises(ValueError, match="requires named columns"):
+            _lib.Select().from_table("employees").top_n_per_group([_lib.Expr.col("dept")], [], 1)
+        with pytest.raises(ValueError, match="requires named columns"):
+            _lib.Select(_lib.Expr.col("a") + 1).from_table("t").top_n_per_group([], [], 1)
+        with pytest.raises(ValueError, match="selected twice"):
+            _lib.Select(_lib.Expr.col("a.id"), _lib.Expr.col("b.id")).from_table("a").top_n_per_group([], [], 1)
+        with pytest.raises(ValueError):
+            _lib.Select(_lib.Expr.col("a")).from_table("employees").top_n_per_group([_lib.Expr.col("dept")], [], 0)
+
+    def test_to_sql_quote_identifiers(self):
+        query = (
+            _lib.Select(_lib.Expr.col("id"), _lib.Expr.col("userName"), _lib.Expr.col("order"))
+            .from_table(_lib.TableName("users", schema="app"))
+            .where(_lib.Expr.col("name") == "it's \"me\"")
+            .order_by(_lib.Expr.col("id"), "asc")
+        )
+
+        assert query.to_sql("sqlite") == (
+            'SELECT "id", "userName", "order" FROM "app"."users" WHERE "name" = \'it\'\'s "me"\' ORDER BY "id" ASC'
+        )
+        assert query.to_sql("sqlite", quote_identifiers=False) == (
+            'SELECT id, "userName", "order" FROM app.users WHERE name = \'it\'\'s "me"\' ORDER BY id ASC'
+        )
+        assert query.to_sql("mysql", quote_identifiers=False) == (
+            "SELECT id, `userName`, `order` FROM app.users WHERE name = 'it\\'s \\\"me\\\"' ORDER BY id ASC"
+        )
+
+        update = _lib.Update().table("t").values(a=1).where(_lib.Expr.col("Id") == 1)
+        assert update.to_sql("postgresql", quote_identifiers=False) == 'UPDATE t SET a = 1 WHERE "Id" = 1'
+
+        # Each backend quotes its own reserved keywords
+        query = _lib.Select(*(_lib.Expr.col(x) for x in ("rank", "only", "rows", "key", "pragma"))).from_table("t")
+        assert query.to_sql("postgresql", quote_identifiers=False) == 'SELECT rank, "only", rows, key, pragma FROM t'
+        assert query.to_sql("mysql", quote_identifiers=False) == "SELECT `rank`, only, `rows`, `key`, pragma FROM t"
+        assert query.to_sql("sqlite", quote_identifiers=False) == 'SELECT rank, only, "rows", "key", "pragma" FROM t'
+
+    def test_add_column(self):
+        query = _lib.Select(_lib.Expr.col("id")).from_table("orders")
+
+        assert query.add_column(_lib.Expr.col("price") * _lib.Expr.col("quantity"), "total") is query
+        query.add_column(_lib.SelectCol(_lib.FunctionCall.upper(_lib.Expr.col("status")), "a"), alias="status")
+
+        assert query.to_sql("postgresql") == (
+            'SELECT "id", "price" * "quantity" AS "total", UPPER("status") AS "status" FROM "orders"'
+        )
+
+        query = _lib.Select().from_table("orders").add_column(_lib.Expr.col("id"))
+        assert query.to_sql("postgresql") == 'SELECT "id" FROM "orders"'
+
+    def test_distinct_order_by_postgres(self):
+        query = (
+            _lib.Select(_lib.Expr.col("name"))
+            .distinct()
+            .from_table("users")
+            .order_by(_lib.Expr.col("created_at"), "desc")
+        )
+
+        with pytest.raises(ValueError, match="select list"):
+            query.to_sql("postgresql")
+        with pytest.raises(ValueError, match="select list"):
+            query.build("postgresql")
+
+        # Other backends don't require it
+        assert query.to_sql("mysql") == "SELECT DISTINCT `name` FROM `users` ORDER BY `created_at` DESC"
+
+        query = (
+            _lib.Select(_lib.Expr.col("name"), _lib.SelectCol(_lib.FunctionCall.lower(_lib.Expr.col("email")), "em"))
+            .distinct()
+            .from_table("users")
+            .order_by(_lib.Expr.col("name"), "asc")
+            .order_by(_lib.Expr.col("em"), "desc")
+        )
+        assert query.to_sql("postgresql") == (
+            'SELECT DISTINCT "name", LOWER("email") AS "em" FROM "users" ORDER BY "name" ASC, "em" DESC'
+        )
+
+        query = _lib.Select(_lib.Expr.asterisk()).distinct().from_table("users").order_by(_lib.Expr.col("id"), "asc")
+        assert query.to_sql("postgresql") == 'SELECT DISTINCT * FROM "users" ORDER BY "id" ASC'
+
+        # Qualifiers may be aliases, so they're ignored when comparing
+        query = _lib.Select(_lib.Expr.col("u.a")).distinct().from_table("users").order_by(_lib.Expr.col("a"), "asc")
+        assert query.to_sql("postgresql") == 'SELECT DISTINCT "u"."a" FROM "users" ORDER BY "a" ASC'
+
+        query = (
+            _lib.Select(_lib.Expr.col("a") + 1)
+            .distinct()
+            .from_table("users")
+            .order_by(_lib.Expr.col("users.a") + 1, "asc")
+        )
+        assert query.to_sql("postgresql") == 'SELECT DISTINCT "a" + 1 FROM "users" ORDER BY "users"."a" + 1 ASC'
+
+        query = _lib.Select(_lib.Expr.col("a")).distinct().from_table("users").order_by(_lib.Expr.col("u.b"), "asc")
+        with pytest.raises(ValueError, match='got "u"."b"'):
+            query.to_sql("postgresql")
+
+    def test_from_table_only(self):
+        query = _lib.Select(_lib.Expr.asterisk()).from_table("parent", only=True)
+        assert query.to_sql("postgresql") == 'SELECT * FROM ONLY "parent"'
+
+        query = (
+            _lib.Select(_lib.Expr.col("id"))
+            .from_table(_lib.TableName("parent", schema="public", alias="p"), only=True)
+            .where(_lib.Expr.col("id") > 1)
+        )
+        assert query.to_sql("postgresql") == 'SELECT "id" FROM ONLY "public"."parent" AS "p" WHERE "id" > 1'
+
+        with pytest.raises(ValueError, match="PostgreSQL"):
+            _lib.Select(_lib.Expr.asterisk()).from_table("parent", only=True).to_sql("sqlite")
+
+        subquery = _lib.Select(_lib.Expr.asterisk()).from_table("parent").subquery("s")
+        with pytest.raises(ValueError):
+            _lib.Select(_lib.Expr.asterisk()).from_table(subquery, only=True)
+
+    def test_add_group_by(self):
+        query = (
+            _lib.Select(_lib.Expr.col("country"), _lib.Expr.col("city"))
+            .from_table("users")
+            .add_group_by(_lib.Expr.col("country"))
+            .add_group_by(_lib.Expr.col("city"))
+        )
+        assert query.to_sql("postgresql") == (
+            'SELECT "country", "city" FROM "users" GROUP BY "country", "city"'
+        )
+
+        # group_by still replaces the whole list
+        query.group_by(_lib.Expr.col("city"))
+        assert query.to_sql("postgresql") == 'SELECT "country", "city" FROM "users" GROUP BY "city"'
+
+    def test_explain(self):
+        query = _lib.Select(_lib.Expr.col("id")).from_table("users").where(_lib.Expr.col("active"))
+        sql = query.to_sql("postgresql")
+
+        assert query.explain("postgresql") == f"EXPLAIN {sql}"
+        assert query.explain("postgresql", analyze=True) == f"EXPLAIN ANALYZE {sql}"
+        assert query.explain("postgresql", format="json") == f"EXPLAIN (FORMAT JSON) {sql}"
+        assert query.explain("postgresql", analyze=True, format="JSON") == f"EXPLAIN (ANALYZE, FORMAT JSON) {sql}"
+
+        assert query.explain("mysql", format="json") == f"EXPLAIN FORMAT=JSON {query.to_sql('mysql')}"
+        assert query.explain("mysql", analyze=True) == f"EXPLAIN ANALYZE {query.to_sql('mysql')}"
+        assert query.explain("sqlite") == f"EXPLAIN QUERY PLAN {query.to_sql('sqlite')}"
+
+        with pytest.raises(ValueError, match="not supported by mysql"):
+            query.explain("mysql", analyze=True, format="json")
+        with pytest.raises(ValueError, match="not supported by postgresql"):
+            query.explain("postgresql", format="tree")
+        with pytest.raises(ValueError):
+            query.explain("sqlite", analyze=True)
+        with pytest.raises(ValueError):
+            query.explain("sqlite", format="json")
+
+    def test_cte_by_name(self):
+        base = _lib.Select(_lib.Expr.col("id")).from_table("categories").where(_lib.Expr.col("id") == 1)
+        step = (
+            _lib.Select(_lib.Expr.col("c.id"))
+            .from_table(_lib.TableName("categories", alias="c"))
+            .join(_lib.TableName("tree"), _lib.Expr.col("tree.id") == _lib.Expr.col("c.parent_id"))
+        )
+
+        query = (
+            _lib.Select(_lib.Expr.col("p.name"))
+            .with_recursive("tree", base, step)
+            .from_table(_lib.TableName("products", alias="p"))
+            .join_cte("tree", _lib.Expr.col("tree.id") == _lib.Expr.col("p.category_id"), "left")
+        )
+        assert query.to_sql("postgresql").endswith(
+            'SELECT "p"."name" FROM "products" AS "p" LEFT JOIN "tree" ON "tree"."id" = "p"."category_id"'
+        )
+
+        query = _lib.Select(_lib.Expr.col("id")).with_recursive("tree", base, step).add_cte_from("tree")
+        assert query.to_sql("sqlite").endswith('SELECT "id" FROM "tree"')
+
+        with pytest.raises(ValueError, match='no CTE named "trees"'):
+            _lib.Select(_lib.Expr.col("id")).with_recursive("tree", base, step).add_cte_from("trees")
+        with pytest.raises(ValueError, match='no CTE named "tree"'):
+            _lib.Select(_lib.Expr.col("id")).from_table("t").join_cte("tree", _lib.Expr.col("tree.id") == 1)
+
+    def test_join_on_column_pairs(self):
+        query = (
+            _lib.Select(_lib.Expr.col("o.id"))
+            .from_table(_lib.TableName("orders", alias="o"))
+            .join(
+                _lib.TableName("lines", alias="l"),
+                [("o.id", "l.order_id"), (_lib.ColumnRef("tenant_id", table="o"), "l.tenant_id")],
+                "left",
+            )
+        )
+        assert query.to_sql("postgresql") == (
+            'SELECT "o"."id" FROM "orders" AS "o" LEFT JOIN "lines" AS "l" '
+            'ON "o"."id" = "l"."order_id" AND "o"."tenant_id" = "l"."tenant_id"'
+        )
+
+        with pytest.raises(ValueError, match="at least one column pair"):
+            _lib.Select(_lib.Expr.asterisk()).from_table("a").join("b", [])
+        with pytest.raises(TypeError):
+            _lib.Select(_lib.Expr.asterisk()).from_table("a").join("b", [("a.id",)])
+        with pytest.raises(TypeError):
+            _lib.Select(_lib.Expr.asterisk()).from_table("a").join("b", [("a.id", "b.id"), "b.x"])
+        with pytest.raises(TypeError):
+            _lib.Select(_lib.Expr.asterisk()).from_table("a").join("b", [("a.id", 1)])
+
+    def test_correlated_exists(self):
+        users = _lib.Table(
+            _lib.TableName("users", schema="app"),
+            [_lib.Column("id", _lib.IntegerType()), _lib.Column("manager_id", _lib.IntegerType())],
+        )
+        orders = _lib.Table("orders", [_lib.Column("user_id", _lib.IntegerType())])
+
+        # The outer table keeps its schema qualifier inside the subquery
+        inner = _lib.Select(_lib.Expr(1)).from_table(orders).where(orders.c.user_id.to_expr() == users.c.id.to_expr())
+        query = _lib.Select(users.c.id).from_table(users).where(_lib.Expr.exists(inner))
+        assert query.to_sql("postgresql") == (
+            'SELECT "app"."users"."id" FROM "app"."users" '
+            'WHERE EXISTS(SELECT 1 FROM "orders" WHERE "orders"."user_id" = "app"."users"."id")'
+        )
+
+        # Correlating a table with itself, the inner one is referenced by its alias
+        managers = _lib.AliasedTable(users, "m")
+        inner = (
+            _lib.Select(_lib.Expr(1))
+            .from_table(managers)
+            .where(_lib.Expr(managers.c.id) == users.c.manager_id.to_expr())
+        )
+        query = _lib.Select(users.c.id).from_table(users).where(_lib.not_(_lib.Expr.exists(inner)))
+        assert query.to_sql("mysql") == (
+            "SELECT `app`.`users`.`id` FROM `app`.`users` WHERE NOT EXISTS(SELECT 1 FROM `app`.`users` AS `m` "
+            "WHERE `m`.`id` = `app`.`users`.`manager_id`)"
+        )
+
+    @pytest.mark.parametrize(
+        "backend,expected",
+        [
+            ("postgresql", 'SELECT 1 + 1 AS "x"'),
+            ("sqlite", 'SELECT 1 + 1 AS "x"'),
+            ("mysql", "SELECT 1 + 1 AS `x`"),
+        ],
+    )
+    def test_select_without_from(self, backend, expected):
+        query = _lib.Select(_lib.SelectCol(_lib.Expr(1) + 1, "x"))
+        assert query.to_sql(backend) == expected
+
+        sql, params = query.build(backend)
+        assert "FROM" not in sql
+        assert len(params) == 2
+
+    def test_selected_columns(self):
+        query = _lib.Select(
+            _lib.Expr.col("id"),
+            _lib.SelectCol(_lib.Expr.col("price") * _lib.Expr.col("quantity"), "total"),
+            _lib.Expr.asterisk(),
+        ).from_table("orders")
+
+        columns = query.selected_columns
+        assert all(isinstance(expr, _lib.Expr) for expr, _ in columns)
+        assert [(expr.to_sql("postgresql"), alias) for expr, alias in columns] == [
+            ('"id"', None),
+            ('"price" * "quantity"', "total"),
+            ("*", None),
+        ]
+
+        query.add_column(_lib.Expr(1), "one")
+        expr, alias = query.selected_columns[-1]
+        assert (expr.to_sql("postgresql"), alias) == ("1", "one")
+        assert _lib.Select().selected_columns == []
+
+    def test_union_nesting(self):
+        def select(table):
+            return _lib.Select(_lib.Expr.col("id")).from_table(table)
+
+        # Set operations apply from left to right: (a UNION b) UNION ALL c
+        query = select("a").union(select("b")).union(select("c"), "all")
+        assert query.to_sql("postgresql") == (
+            'SELECT "id" FROM "a" UNION (SELECT "id" FROM "b") UNION ALL (SELECT "id" FROM "c")'
+        )
+        assert query.to_sql("sqlite") == (
+            'SELECT "id" FROM "a" UNION SELECT "id" FROM "b" UNION ALL SELECT "id" FROM "c"'
+        )
+
+        # A compound operand groups the other way: c UNION ALL (a UNION b)
+        query = select("c").union(select("a").union(select("b")), "all")
+        assert query.to_sql("postgresql") == (
+            'SELECT "id" FROM "c" UNION ALL (SELECT "id" FROM "a" UNION (SELECT "id" FROM "b"))'
+        )
+        assert query.to_sql("mysql") == (
+            "SELECT `id` FROM `c` UNION ALL (SELECT `id` FROM `a` UNION (SELECT `id` FROM `b`))"
+        )
+        assert query.to_sql("sqlite") == (
+            'SELECT "id" FROM "c" UNION ALL '
+            'SELECT * FROM (SELECT "id" FROM "a" UNION SELECT "id" FROM "b") AS "compound"'
+        )
+
+        # SQLite has no LIMIT on a member either
+        query = select("c").union(select("a").limit(1), "all")
+        assert query.to_sql("sqlite") == (
+            'SELECT "id" FROM "c" UNION ALL SELECT * FROM (SELECT "id" FROM "a" LIMIT 1) AS "compound"'
+        )
+
+        a, b = select("a"), select("b")
+        with pytest.raises(ValueError):
+            a.union(a)
+
+        b.union(select("c").union(a))
+        with pytest.raises(ValueError):
+            a.union(b)
+
+    def test_cross_join(self):
+        query = _lib.Select(_lib.Expr.asterisk()).from_table("sizes").join("colors", type="cross")
+
+        assert query.to_sql("postgresql") == 'SELECT * FROM "sizes" CROSS JOIN "colors"'
+        assert query.to_sql("mysql") == "SELECT * FROM `sizes` CROSS JOIN `colors`"
+        assert query.to_sql("sqlite") == 'SELECT * FROM "sizes" CROSS JOIN "colors"'
+
+        with pytest.raises(ValueError):
+            _lib.Select(_lib.Expr.asterisk()).from_table("a").join("b", _lib.Expr.col("a.id") == 1, "cross")
+
+        with pytest.raises(ValueError):
+            _lib.Select(_lib.Expr.asterisk()).from_table("a").join("b", type="left")
+
+    def test_cross_join_cte_and_subquery(self):
+        base = _lib.Select(_lib.SelectCol(_lib.Expr(1), "n"))
+        query = (
+            _lib.Select(_lib.Expr.asterisk())
+            .with_recursive("nums", base, base)
+            .from_table("t")
+            .join_cte("nums", type="cross")
+        )
+        assert query.to_sql("postgresql").endswith('SELECT * FROM "t" CROSS JOIN "nums"')
+
+        sub = _lib.Select(_lib.Expr.col("id")).from_table("b")
+        query = _lib.Select(_lib.Expr.asterisk()).from_table("a").join_lateral(sub, "s", type="cross")
+        assert query.to_sql("postgresql") == 'SELECT * FROM "a" CROSS JOIN LATERAL (SELECT "id" FROM "b") AS "s"'
+
+        query = _lib.Select(_lib.Expr.asterisk()).from_table("a").join_subquery(sub, "s", type="cross")
+        assert query.to_sql("mysql") == "SELECT * FROM `a` CROSS JOIN (SELECT `id` FROM `b`) AS `s`"
+
+        with pytest.raises(ValueError, match="condition is required"):
+            _lib.Select(_lib.Expr.asterisk()).from_table("a").join_subquery(sub, "s")
+        with pytest.raises(ValueError, match="cross join has no condition"):
+            _lib.Select(_lib.Expr.asterisk()).from_table("t").with_recursive("nums", base, base).join_cte(
+                "nums", _lib.Expr.col("nums.n") == 1, "cross"
+            )
+
+
+class TestBackend:
+    def test_backend_object(self):
+        query = _lib.Select(_lib.Expr.col("id")).from_table("users").where(_lib.Expr.col("id") == 1)
+
+        for backend in (_lib.Backend.POSTGRES, _lib.Backend.MYSQL, _lib.Backend.SQLITE):
+            assert query.to_sql(backend) == query.to_sql(backend.name)
+            assert query.build(backend)[0] == query.build(str(backend))[0]
+
+        table = _lib.Table("t", [_lib.Column("id", _lib.IntegerType())])
+        assert table.to_sql(_lib.Backend.MYSQL) == table.to_sql("mysql")
+
+        assert _lib.Backend("postgres") == _lib.Backend.POSTGRES
+        assert _lib.Backend(_lib.Backend.SQLITE) == _lib.Backend.SQLITE
+        assert _lib.Backend.MYSQL != _lib.Backend.SQLITE
+        assert len({_lib.Backend("mysql"), _lib.Backend.MYSQL}) == 1
+        assert _lib.Backend.POSTGRES.name == "postgresql"
+        assert repr(_lib.Backend.MYSQL) == '<Backend "mysql">'
+
+    def test_invalid_backend(self):
+        with pytest.raises(ValueError, match="expected 'postgresql', 'mysql' or 'sqlite'"):
+            _lib.Backend("postgre")
+
+        with pytest.raises(TypeError):
+            _lib.Backend(1)
+
+        with pytest.raises(ValueError):
+            _lib.Select(_lib.Expr(1)).to_sql("postgre")
@@ -15,6 +15,13 @@ from rapidquery._lib import (
     StringType,
     ForeignKey,
     Index,
+    IndexColumn,
+    AliasedTable,
+    ColumnRef,
+    Expr,
+    Select,
+    build_schema_ordered,
+    drop_all,
 )
 
 
@@ -84,6 +91,26 @@ class TestTable:
         assert column.name == "email"
         assert isinstance(column.type, StringType)
 
+    def test_table_column_names(self):
+        """Test column_names keeps definition order and has_column checks by name"""
+        table = Table(
+            "users",
+            [
+                Column("id", IntegerType(), primary_key=True),
+                Column("name", StringType(255)),
+                Column("email", StringType(255)),
+                Column("age", IntegerType()),
+            ],
+        )
+
+        assert table.column_names() == ["id", "name", "email", "age"]
+        assert table.has_column("email")
+        assert not table.has_column("password")
+
+        table.columns.remove("name")
+        assert table.column_names() == ["id", "email", "age"]
+        assert not table.has_column("name")
+
     def test_table_get_column_nonexistent(self):
         """Test retrieving non-existent column raises error"""
         columns = [Column("id", IntegerType())]
@@ -104,6 +131,68 @@ class TestTable:
         assert "CREATE TABLE" in sql.upper()
         assert "test_table" in sql
 
+    def test_table_storage_params(self):
+        """Test that storage parameters render as WITH clause on PostgreSQL only"""
+        table = Table(
+            "items",
+            [Column("id", IntegerType())],
+            storage_params={"fillfactor": 70, "autovacuum_enabled": False},
+        )
+
+        assert table.storage_params == {"fillfactor": 70, "autovacuum_enabled": False}
+        assert table.to_sql("postgresql") == (
+            'CREATE TABLE "items" ( "id" integer ) WITH (fillfactor=70, autovacuum_enabled=false);\n'
+        )
+        assert table.to_sql("sqlite") == 'CREATE TABLE "items" ( "id" integer );\n'
+
+        table.extra = "TABLESPACE fast"
+        assert table.to_sql("postgresql") == (
+            'CREATE TABLE "items" ( "id" integer ) '
+            "WITH (fillfactor=70, autovacuum_enabled=false) TABLESPACE fast;\n"
+        )
+
+        table.storage_params = None
+        assert table.storage_params == {}
+
+        with pytest.raises(TypeError):
+            table.storage_params = {"fillfactor": [70]}
+
+    def test_table_storage_params_validated(self):
+        """Test that storage parameter names are checked and string values are quoted"""
+        table = Table(
+            "items",
+            [Column("id", IntegerType())],
+            storage_params={"toast.autovacuum_enabled": True, "check_option": "it's"},
+        )
+        assert table.to_sql("postgresql") == (
+            'CREATE TABLE "items" ( "id" integer ) '
+            "WITH (toast.autovacuum_enabled=true, check_option='it''s');\n"
+        )
+
+        for name in ["fillfactor=1) --", "", "a.b.c", "1abc", "a b"]:
+            with pytest.raises(ValueError, match="invalid storage parameter name"):
+                table.storage_params = {name: 1}
+        with pytest.raises(ValueError, match="finite"):
+            table.storage_params = {"fillfactor": float("nan")}
+        assert table.storage_params == {"toast.autovacuum_enabled": True, "check_option": "it's"}
+
+    @pytest.mark.parametrize(
+        "backend,expected",
+        [
+            ("postgresql", 'CREATE TABLE "t" ( "a" varchar, "b" varchar, "c" varchar(20) );\n'),
+            ("mysql", "CREATE TABLE `t` ( `a` varchar(255), `b` varchar(255), `c` varchar(20) );\n"),
+            ("sqlite", 'CREATE TABLE "t" ( "a" varchar, "b" varchar, "c" varchar(20) );\n'),
+        ],
+    )
+    def test_table_string_without_length(self, backend, expected):
+        """Test that a length-less string column never renders as VARCHAR(0)"""
+        table = Table(
+            "t",
+            [Column("a", StringType()), Column("b", StringType(0)), Column("c", StringType(20))],
+        )
+
+        assert table.to_sql(backend) == expected
+
     def test_table_repr(self):
         """Test string representation"""
         columns = [Column("id", IntegerType())]
@@ -115,6 +204,211 @@ class TestTable:
         assert "Table" in repr_str
         assert "test" in repr_str
 
+    def test_foreign_key_match(self):
+        """Test MATCH FULL of a composite foreign key, which only PostgreSQL supports"""
+        foreign_key = ForeignKey(
+            from_columns=["order_id", "line_no"],
+            to_columns=["order_id", "line_no"],
+            to_table="order_lines",
+            name="fk_line",
+            on_delete="CASCADE",
+            match="full",
+        )
+        table = Table(
+            "shipments",
+            [Column("order_id", IntegerType()), Column("line_no", IntegerType())],
+            foreign_keys=[foreign_key],
+        )
+
+        assert foreign_key.match == "FULL"
+        assert table.to_sql("postgresql") == (
+            'CREATE TABLE "shipments" ( "order_id" integer, "line_no" integer, '
+            'CONSTRAINT "fk_line" FOREIGN KEY ("order_id", "line_no") '
+            'REFERENCES "order_lines" ("order_id", "line_no") MATCH FULL ON DELETE CASCADE );\n'
+        )
+        with pytest.raises(ValueError, match="MATCH type, which MySQL doesn't support"):
+            table.to_sql("mysql")
+
+        alter = AlterTable("shipments", [AlterTableAddForeignKeyOption(foreign_key)])
+        assert alter.to_sql("postgresql") == (
+            'ALTER TABLE "shipments" ADD CONSTRAINT "fk_line" FOREIGN KEY ("order_id", "line_no") '
+            'REFERENCES "order_lines" ("order_id", "line_no") MATCH FULL ON DELETE CASCADE'
+        )
+        with pytest.raises(ValueError, match="MATCH type, which SQLite doesn't support"):
+            alter.to_sql("sqlite")
+
+        # The clause goes into the constraint, even if its text appears earlier
+        text = "REFERENCES order_lines (order_id, line_no)"
+        table.columns.append(Column("note", StringType(), default=text))
+        sql = table.to_sql("postgresql")
+        assert f"DEFAULT '{text}', " in sql
+        assert sql.endswith('REFERENCES "order_lines" ("order_id", "line_no") MATCH FULL ON DELETE CASCADE );\n')
+
+        foreign_key.match = None
+        assert "MATCH" not in table.to_sql("postgresql")
+
+        with pytest.raises(ValueError):
+            ForeignKey(["a"], ["b"], "t", match="fuzzy")
+
+    def test_foreign_key_set_columns(self):
+        """Test replacing both columns of a composite foreign key together"""
+        foreign_key = ForeignKey(["user_id"], ["id"], "users")
+
+        with pytest.raises(ValueError):
+            foreign_key.from_columns = ["tenant_id", "user_id"]
+
+        foreign_key.set_columns(["tenant_id", "user_id"], ["tenant_id", "id"])
+        assert foreign_key.from_columns == ["tenant_id", "user_id"]
+        assert foreign_key.to_columns == ["tenant_id", "id"]
+
+        copied = foreign_key.copy()
+        assert copied.from_columns == ["tenant_id", "user_id"]
+        assert copied.to_columns == ["tenant_id", "id"]
+
+        with pytest.raises(ValueError, match=r"same length \(1 != 2\)"):
+            foreign_key.set_columns(["user_id"], ["tenant_id", "id"])
+        with pytest.raises(ValueError, match="from_columns is empty"):
+            foreign_key.set_columns([], [])
+        assert foreign_key.from_columns == ["tenant_id", "user_id"]
+        assert foreign_key.to_columns == ["tenant_id", "id"]
+
+    def test_foreign_key_from_column_objects(self):
+        """Test giving foreign key columns as Column and ColumnRef objects"""
+        users = Table("users", [Column("id", IntegerType(), primary_key=True)])
+        posts = Table("posts", [Column("id", IntegerType()), Column("user_id", IntegerType())])
+
+        foreign_key = ForeignKey([posts.c.user_id], [users.c.id], "users")
+        assert foreign_key.from_columns == ["user_id"]
+        assert foreign_key.to_columns == ["id"]
+        assert foreign_key.name == "fk__user_id_users_id"
+
+        foreign_key.to_columns = [ColumnRef("id", table="users")]
+        foreign_key.set_columns([Column("author_id", IntegerType()), "tenant_id"], ["id", "tenant_id"])
+        assert foreign_key.from_columns == ["author_id", "tenant_id"]
+        assert foreign_key.to_columns == ["id", "tenant_id"]
+
+        with pytest.raises(TypeError):
+            ForeignKey([1], ["id"], "users")
+        with pytest.raises(TypeError):
+            ForeignKey("user_id", "id", "users")
+        with pytest.raises(ValueError):
+            ForeignKey([ColumnRef.parse("*")], ["id"], "users")
+
+    def test_foreign_key_column_qualifier(self):
+        """Test that qualified ColumnRef objects must belong to the table of their columns"""
+        foreign_key = ForeignKey([ColumnRef("user_id", table="posts")], [ColumnRef("id", table="users")], "users")
+        assert foreign_key.from_columns == ["user_id"]
+
+        with pytest.raises(ValueError, match="qualified by posts"):
+            ForeignKey(["user_id"], [ColumnRef("id", table="posts")], "users")
+        with pytest.raises(ValueError, match="qualified by users"):
+            ForeignKey([ColumnRef("user_id", table="users")], ["id"], "users", from_table="posts")
+        with pytest.raises(ValueError, match="qualified by users"):
+            ForeignKey(["user_id"], [ColumnRef("id", table="users", schema="other")], TableName.parse("app.users"))
+
+        foreign_key = ForeignKey(["user_id"], ["id"], "users", from_table="posts")
+        with pytest.raises(ValueError, match="qualified by comments"):
+            foreign_key.from_columns = [ColumnRef("user_id", table="comments")]
+        with pytest.raises(ValueError, match="qualified by comments"):
+            foreign_key.set_columns(["user_id"], [ColumnRef("id", table="comments")])
+        assert foreign_key.to_columns == ["id"]
+
+    def test_without_rowid(self):
+        """Test SQLite WITHOUT ROWID tables, which need a primary key"""
+        table = Table(
+            "kv",
+            [Column("key", StringType(100), primary_key=True), Column("value", StringType(100))],
+            without_rowid=True,
+        )
+
+        assert table.without_rowid is True
+        assert table.to_sql("sqlite") == (
+            'CREATE TABLE "kv" ( "key" varchar(100) PRIMARY KEY, "value" varchar(100) ) WITHOUT ROWID;\n'
+        )
+        assert "ROWID" not in table.to_sql("postgresql")
+        assert "ROWID" not in table.to_sql("mysql")
+
+        table.extra = "STRICT"
+        assert table.to_sql("sqlite").endswith(") WITHOUT ROWID, STRICT;\n")
+
+        table = Table(
+            "kv",
+            [Column("key", StringType(100)), Column("value", StringType(100))],
+            indexes=[Index(["key"], primary=True)],
+            without_rowid=True,
+        )
+        assert table.to_sql("sqlite").endswith('PRIMARY KEY ("key") ) WITHOUT ROWID;\n')
+
+        table = Table("kv", [Column("key", StringType(100))], without_rowid=True)
+        with pytest.raises(ValueError, match="primary key"):
+            table.to_sql("sqlite")
+        with pytest.raises(ValueError, match="primary key"):
+            build_schema_ordered([table], "sqlite")
+
+        table.without_rowid = False
+        assert table.to_sql("sqlite") == 'CREATE TABLE "kv" ( "key" varchar(100) );\n'
+
+    def test_table_to_sql_pretty(self):
+        """Test that pretty output puts each definition on its own line"""
+        table = Table(
+            "child",
+            [Column("id", IntegerType(), primary_key=True), Column("parent_id", IntegerType())],
+            foreign_keys=[
+                ForeignKey(["parent_id"], ["id"], "parent", name="fk_parent", on_delete="SET NULL")
+            ],
+        )
+
+        assert table.to_sql("postgresql", pretty=True) == (
+            'CREATE TABLE "child" (\n'
+            '    "id" integer PRIMARY KEY,\n'
+            '    "parent_id" integer,\n'
+            '    CONSTRAINT "fk_parent" FOREIGN KEY ("parent_id") REFERENCES "parent" ("id") ON DELETE SET NULL\n'
+            ");\n"
+        )
+        assert build_schema_ordered([table], "postgresql", pretty=True) == table.to_sql("postgresql", pretty=True)
+
+    def test_column_comments_postgres(self):
+        """Test that column comments are separate statements on PostgreSQL"""
+        table = Table(
+            TableName("users", schema="app"),
+            [
+                Column("id", IntegerType(), primary_key=True, comment="Identifier"),
+                Column("name", StringType(50), comment="User's name"),
+                Column("email", StringType(50)),
+            ],
+        )
+
+        assert table.to_sql("postgresql") == (
+            'CREATE TABLE "app"."users" ( "id" integer PRIMARY KEY, "name" varchar(50), "email" varchar(50) );\n'
+            'COMMENT ON COLUMN "app"."users"."id" IS \'Identifier\';\n'
+            'COMMENT ON COLUMN "app"."users"."name" IS E\'User\\\'s name\';\n'
+        )
+        assert build_schema_ordered([table], "postgresql") == table.to_sql("postgresql")
+
+        # Inline on MySQL, and not supported by SQLite
+        assert "COMMENT ON" not in table.to_sql("mysql")
+        assert "COMMENT 'Identifier'" in table.to_sql("mysql")
+        assert "COMMENT" not in table.to_sql("sqlite")
+
+    def test_table_comment_postgres(self):
+        """Test that the table comment is a separate statement on PostgreSQL"""
+        table = Table(
+            "users",
+            [Column("id", IntegerType(), primary_key=True, comment="Identifier")],
+            comment="Registered users",
+        )
+
+        assert table.to_sql("postgresql") == (
+            'CREATE TABLE "users" ( "id" integer PRIMARY KEY );\n'
+            'COMMENT ON TABLE "users" IS \'Registered users\';\n'
+            'COMMENT ON COLUMN "users"."id" IS \'Identifier\';\n'
+        )
+
+        # MySQL keeps it inline
+        assert table.to_sql("mysql").startswith(
+            "CREATE TABLE `users` ( `id` int PRIMARY KEY COMMENT 'Identifier' ) COMMENT 'Registered users';"
+        )
+
 
 class TestDropTable:
     """Test cases for DropTable class"""
@@ -266,6 +560,21 @@ class TestAlterTable:
         assert isinstance(repr_str, str)
         assert "AlterTable" in repr_str
 
+    def test_table_rename_column(self):
+        """Test building the ALTER TABLE which renames a column of a table"""
+        table = Table(TableName("users", schema="app", alias="u"), [Column("name", StringType(100))])
+
+        alter = table.rename_column("name", "full_name")
+        assert isinstance(alter, AlterTable)
+        assert alter.to_sql("postgresql") == 'ALTER TABLE "app"."users" RENAME COLUMN "name" TO "full_name"'
+        assert alter.to_sql("mysql") == "ALTER TABLE `app`.`users` RENAME COLUMN `name` TO `full_name`"
+
+        # The table itself is unchanged
+        assert table.column_names() == ["name"]
+
+        with pytest.raises(KeyError):
+            table.rename_column("email", "mail")
+
 
 class TestAlterTableOptions:
     """Test cases for individual AlterTable option classes"""
@@ -290,6 +599,28 @@ class TestAlterTableOptions:
         repr_str = repr(option)
         assert "AlterTableDropColumnOption" in repr_str
 
+    def test_alter_table_drop_column_if_exists_cascade(self):
+        """Test DropColumn option with IF EXISTS and CASCADE"""
+        option = AlterTableDropColumnOption("obsolete_column", if_exists=True, cascade=True)
+
+        assert option.if_exists is True
+        assert option.cascade is True
+        assert "if_exists=True cascade=True" in repr(option)
+
+        alter_table = AlterTable("users", [option, AlterTableDropColumnOption("other")])
+        assert alter_table.to_sql("postgresql") == (
+            'ALTER TABLE "users" DROP COLUMN IF EXISTS "obsolete_column" CASCADE, DROP COLUMN "other"'
+        )
+
+        for backend in ("mysql", "sqlite"):
+            with pytest.raises(ValueError):
+                alter_table.to_sql(backend)
+
+        plain = AlterTableDropColumnOption("other")
+        assert plain.if_exists is False
+        assert plain.cascade is False
+        assert AlterTable("users", [plain]).to_sql("mysql") == "ALTER TABLE `users` DROP COLUMN `other`"
+
     def test_alter_table_modify_column_option(self):
         """Test ModifyColumn option"""
         column = Column("description", StringType(500))
@@ -332,6 +663,98 @@ class TestAlterTableOptions:
         assert "AlterTableDropForeignKeyOption" in repr_str
 
 
+class TestIndex:
+    """Test cases for Index class"""
+
+    def test_partial_index_predicate(self):
+        """Test that a partial index exposes and renders its predicate"""
+        predicate = Expr.col("deleted_at").is_null()
+        index = Index(["email"], name="ix_email", table="users", unique=True, where=predicate)
+
+        assert index.is_partial()
+        assert index.predicate is predicate
+        assert index.to_sql("postgresql") == (
+            'CREATE UNIQUE INDEX "ix_email" ON "users" ("email") WHERE "deleted_at" IS NULL'
+        )
+        assert index.to_sql("mysql") == "CREATE UNIQUE INDEX `ix_email` ON `users` (`email`)"
+
+    def test_index_without_predicate(self):
+        """Test that an index without where is not partial"""
+        index = Index(["email"], name="ix_email", table="users")
+
+        assert not index.is_partial()
+        assert index.predicate is None
+
+
+    def test_unique_constraint_vs_unique_index(self):
+        """Test that a unique constraint goes into CREATE TABLE, unlike a unique index"""
+        columns = [Column("id", IntegerType(), primary_key=True), Column("email", StringType(100))]
+
+        index = Table("users", columns, indexes=[Index(["email"], unique=True)])
+        assert index.to_sql("postgresql") == (
+            'CREATE TABLE "users" ( "id" integer PRIMARY KEY, "email" varchar(100) );\n'
+            'CREATE UNIQUE INDEX "ix_users_email" ON "users" ("email");\n'
+        )
+
+        constraint = Index(["email"], unique_constraint=True)
+        assert constraint.unique_constraint is True
+
+        table = Table("users", columns, indexes=[constraint])
+        assert table.to_sql("postgresql") == (
+            'CREATE TABLE "users" ( "id" integer PRIMARY KEY, "email" varchar(100), '
+            'CONSTRAINT "ix_users_email" UNIQUE ("email") );\n'
+        )
+        assert "UNIQUE KEY `ix_users_email` (`email`)" in table.to_sql("mysql")
+
+    def test_index_column_prefix_and_order(self):
+        """Test that a prefix length renders on MySQL, and fails on PostgreSQL"""
+        index = Index([IndexColumn("name", prefix=10, order="desc"), "id"], name="ix_name", table="users")
+
+        assert index.to_sql("mysql") == "CREATE INDEX `ix_name` ON `users` (`name` (10) DESC, `id`)"
+        assert index.to_sql("sqlite") == 'CREATE INDEX "ix_name" ON "users" ("name" DESC, "id")'
+
+        with pytest.raises(ValueError, match='prefix length on column "name"'):
+            index.to_sql("postgresql")
+
+        table = Table("users", [Column("id", IntegerType()), Column("name", StringType(100))], indexes=[index])
+        with pytest.raises(ValueError, match="PostgreSQL"):
+            table.to_sql("postgresql")
+        with pytest.raises(ValueError, match="PostgreSQL"):
+            build_schema_ordered([table], "postgresql")
+
+        index = Index([IndexColumn("name", order="desc")], name="ix_name", table="users")
+        assert index.to_sql("postgresql") == 'CREATE INDEX "ix_name" ON "users" ("name" DESC)'
+
+    def test_index_tablespace_and_fillfactor(self):
+        """Test that tablespace and fillfactor render on PostgreSQL, and are rejected elsewhere"""
+        predicate = Expr.col("deleted_at").is_null()
+        index = Index(["email"], name="ix_email", table="users", tablespace="fast", fillfactor=70, where=predicate)
+
+        assert index.tablespace == "fast"
+        assert index.fillfactor == 70
+        assert index.to_sql("postgresql") == (
+            'CREATE INDEX "ix_email" ON "users" ("email") WITH (fillfactor=70) TABLESPACE "fast" '
+            'WHERE "deleted_at" IS NULL'
+        )
+        with pytest.raises(ValueError, match="tablespace or fillfactor, which MySQL doesn't support"):
+            index.to_sql("mysql")
+
+        constraint = Index(["email"], unique_constraint=True, tablespace="fast", fillfactor=90)
+        table = Table("users", [Column("id", IntegerType()), Column("email", StringType(100))], indexes=[constraint])
+        assert table.to_sql("postgresql") == (
+            'CREATE TABLE "users" ( "id" integer, "email" varchar(100), '
+            'CONSTRAINT "ix_users_email" UNIQUE ("email") WITH (fillfactor=90) USING INDEX TABLESPACE "fast" );\n'
+        )
+        assert build_schema_ordered([table], "postgresql") == table.to_sql("postgresql")
+        with pytest.raises(ValueError, match="tablespace or fillfactor, which SQLite doesn't support"):
+            table.to_sql("sqlite")
+
+        with pytest.raises(ValueError, match="fillfactor"):
+            Index(["email"], fillfactor=5)
+        with pytest.raises(ValueError, match="fillfactor"):
+            constraint.fillfactor = 101
+
+
 class TestIntegration:
     """Integration tests for table operations"""
 
@@ -376,3 +799,156 @@ class TestIntegration:
         assert len(table.foreign_keys) == 1
         assert table.indexes[0].name == "ix_posts_title"
         assert table.foreign_keys[0].to_table.name == "users"
+
+
+class TestBuildSchemaOrdered:
+    """Test cases for build_schema_ordered function"""
+
+    def _users(self):
+        return Table(
+            "users",
+            [Column("id", IntegerType(), primary_key=True), Column("email", StringType(100))],
+            indexes=[Index(["email"], name="ix_users_email")],
+        )
+
+    def _posts(self, to_table="users"):
+        return Table(
+            "posts",
+            [Column("id", IntegerType(), primary_key=True), Column("user_id", IntegerType())],
+            foreign_keys=[ForeignKey(from_columns=["user_id"], to_columns=["id"], to_table=to_table)],
+        )
+
+    def test_referenced_table_created_first(self):
+        users, posts = self._users(), self._posts()
+
+        sql = build_schema_ordered([posts, users], "postgresql")
+
+        assert sql.index('CREATE TABLE "users"') < sql.index('CREATE TABLE "posts"')
+        assert sql.startswith('CREATE TABLE "users"')
+        assert sql.endswith('CREATE INDEX "ix_users_email" ON "users" ("email");\n')
+        assert sql == build_schema_ordered([users, posts], "postgresql")
+
+    def test_self_and_outer_references(self):
+        tree = Table(
+            "tree",
+            [Column("id", IntegerType(), primary_key=True), Column("parent_id", IntegerType())],
+            foreign_keys=[ForeignKey(from_columns=["parent_id"], to_columns=["id"], to_table="tree")],
+        )
+
+        sql = build_schema_ordered([tree, self._posts(to_table="accounts")], "sqlite")
+
+        assert sql.index('CREATE TABLE "tree"') < sql.index('CREATE TABLE "posts"')
+
+    def test_cycle(self):
+        users = self._users()
+        users.foreign_keys = [ForeignKey(from_columns=["id"], to_columns=["user_id"], to_table="posts")]
+
+        with pytest.raises(ValueError, match="cycle"):
+            build_schema_ordered([users, self._posts()], "postgresql")
+
+    def test_drop_all_children_first(self):
+        users, posts = self._users(), self._posts()
+
+        assert drop_all([users, posts], "postgresql") == 'DROP TABLE "posts";\nDROP TABLE "users";\n'
+        assert drop_all([posts, users], "postgresql") == drop_all([users, posts], "postgresql")
+        assert drop_all([users, posts], "postgresql", cascade=True) == (
+            'DROP TABLE "posts" CASCADE;\nDROP TABLE "users" CASCADE;\n'
+        )
+
+    def test_drop_all_cycle(self):
+        users, posts = self._users(), self._posts()
+        users.foreign_keys = [ForeignKey(from_columns=["id"], to_columns=["user_id"], to_table="posts")]
+
+        with pytest.raises(ValueError, match="cycle"):
+            drop_all([users, posts], "postgresql")
+
+        # CASCADE drops the constraints of a cycle, so the given order is kept
+        assert drop_all([users, posts], "postgresql", cascade=True) == (
+            'DROP TABLE "users" CASCADE;\nDROP TABLE "posts" CASCADE;\n'
+        )
+        assert drop_all([posts, users], "postgresql", cascade=True) == (
+            'DROP TABLE "posts" CASCADE;\nDROP TABLE "users" CASCADE;\n'
+        )
+
+
+class TestAliasedTable:
+    """Test cases for AliasedTable class"""
+
+    def _table(self):
+        return Table("employees", [Column("id", IntegerType()), Column("manager_id", IntegerType())])
+
+    def test_aliased_columns_qualified_by_alias(self):
+        aliased = AliasedTable(self._table(), "e")
+
+        assert aliased.c.id == ColumnRef("id", table="e")
+        assert aliased.columns.get("manager_id") == ColumnRef("manager_id", table="e")
+        assert aliased.c.to_list() == [ColumnRef("id", table="e"), ColumnRef("manager_id", table="e")]
+        assert len(aliased.c) == 2
+
+        with pytest.raises(KeyError):
+            aliased.c.unknown
+
+    def test_join_table_to_its_alias(self):
+        table = self._table()
+        manager = AliasedTable(table, "m")
+
+        query = (
+            Select(Expr(table.c.id), Expr(manager.c.id))
+            .from_table(table)
+            .join(manager, Expr(table.c.manager_id) == Expr(manager.c.id), "left")
+        )
+
+        assert query.to_sql("postgresql") == (
+            'SELECT "employees"."id", "m"."id" FROM "employees" '
+            'LEFT JOIN "employees" AS "m" ON "employees"."manager_id" = "m"."id"'
+        )
+
+    def test_from_aliased_table_keeps_alias(self):
+        e = AliasedTable(self._table(), "e")
+
+        assert e.name == TableName("employees")
+        assert e.name.alias == "e"
+
+        query = Select(Expr(e.c.id)).from_table(e).where(Expr(e.c.manager_id) == 1)
+
+        sql, params = query.build("sqlite")
+        assert sql == 'SELECT "e"."id" FROM "employees" AS "e" WHERE "e"."manager_id" = ?'
+        assert len(params) == 1
+
+    def test_self_join_with_two_aliases(self):
+        table = self._table()
+        e = AliasedTable(table, "e")
+        m = AliasedTable(table, "m")
+
+        query = (
+            Select(Expr(e.c.id), Expr(m.c.id))
+            .from_table(e)
+            .join(m, Expr(e.c.manager_id) == Expr(m.c.id))
+            .where(Expr(m.c.manager_id).is_null())
+        )
+
+        assert query.to_sql("postgresql") == (
+            'SELECT "e"."id", "m"."id" FROM "employees" AS "e" '
+            'JOIN "employees" AS "m" ON "e"."manager_id" = "m"."id" '
+            'WHERE "m"."manager_id" IS NULL'
+        )
+        assert query.to_sql("mysql") == (
+            "SELECT `e`.`id`, `m`.`id` FROM `employees` AS `e` "
+            "JOIN `employees` AS `m` ON `e`.`manager_id` = `m`.`id` "
+            "WHERE `m`.`manager_id` IS NULL"
+        )
+
+    def test_table_name_alias_qualifies_columns(self):
+        table = Table(
+            TableName("employees", schema="hr", alias="e"),
+            [Column("id", IntegerType()), Column("manager_id", IntegerType())],
+        )
+        m = AliasedTable(table, "m")
+
+        assert table.c.id.to_column_ref() == ColumnRef("id", table="e")
+
+        query = Select(Expr(table.c.id)).from_table(table).join(m, Expr(table.c.manager_id) == Expr(m.c.id))
+        assert query.to_sql("postgresql") == (
+            'SELECT "e"."id" FROM "hr"."employees" AS "e" '
+            'JOIN "hr"."employees" AS "m" ON "e"."manager_id" = "m"."id"'
+        )